@@ -7,7 +7,7 @@ fn main() {
     theme.apply();
     let mut win = window::Window::default().with_size(800, 800);
     let mut choice = menu::Choice::new(300, 10, 200, 30, None);
-    choice.add_choice("Classic|Aero|Metro|AquaClassic|Greybird|Blue|HighContrast|Dark");
+    choice.add_choice("Classic|Aero|Metro|AquaClassic|Greybird|Blue|HighContrast|Dark|Win11|Aqua|Adwaita|AdwaitaDark|Breeze|BreezeDark|Yaru|YaruDark|Motif|Haiku|Luna|HighContrastDark|Elementary|FluentDark");
     choice.set_value(6);
     choice.set_frame(OS_PANEL_THIN_UP_BOX);
     let mut vgrid = group::VGrid::new(50, 100, 700, 700, None);
@@ -94,8 +94,12 @@ fn main() {
     let mut frame = frame::Frame::default().with_label(&"BG_BOX".to_lowercase());
     frame.set_frame(OS_BG_BOX);
     frame.set_label_size(10);
-    frame::Frame::default();
-    frame::Frame::default();
+    let mut frame = frame::Frame::default().with_label(&"SCROLLBAR_THUMB_BOX".to_lowercase());
+    frame.set_frame(OS_SCROLLBAR_THUMB_BOX);
+    frame.set_label_size(10);
+    let mut frame = frame::Frame::default().with_label(&"MENU_BAR_BOX".to_lowercase());
+    frame.set_frame(OS_MENU_BAR_BOX);
+    frame.set_label_size(10);
     frame::Frame::default();
     vgrid.end();
     win.end();
@@ -110,6 +114,20 @@ fn main() {
             5 => WidgetTheme::new(ThemeType::Blue),
             6 => WidgetTheme::new(ThemeType::HighContrast),
             7 => WidgetTheme::new(ThemeType::Dark),
+            8 => WidgetTheme::new(ThemeType::Win11),
+            9 => WidgetTheme::new(ThemeType::Aqua),
+            10 => WidgetTheme::new(ThemeType::Adwaita),
+            11 => WidgetTheme::new(ThemeType::AdwaitaDark),
+            12 => WidgetTheme::new(ThemeType::Breeze),
+            13 => WidgetTheme::new(ThemeType::BreezeDark),
+            14 => WidgetTheme::new(ThemeType::Yaru),
+            15 => WidgetTheme::new(ThemeType::YaruDark),
+            16 => WidgetTheme::new(ThemeType::Motif),
+            17 => WidgetTheme::new(ThemeType::Haiku),
+            18 => WidgetTheme::new(ThemeType::Luna),
+            19 => WidgetTheme::new(ThemeType::HighContrastDark),
+            20 => WidgetTheme::new(ThemeType::Elementary),
+            21 => WidgetTheme::new(ThemeType::FluentDark),
             _ => unimplemented!(),
         };
         theme.apply();
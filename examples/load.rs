@@ -13,11 +13,12 @@ fn load_colormap(file: &str) -> ColorTheme {
                 r: map[2].parse().expect("Parse Error!"),
                 g: map[3].parse().expect("Parse Error!"),
                 b: map[4].parse().expect("Parse Error!"),
+                a: 255,
             };
             vec.push(cmap);
         }
     }
-    ColorTheme(vec)
+    ColorTheme::from(vec)
 }
 
 fn main() {
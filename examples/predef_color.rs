@@ -1,15 +1,58 @@
 use fltk::{prelude::*, *};
-use fltk_theme::{color_themes, ColorTheme};
+use fltk_theme::{color_themes, ColorMap, ColorTheme};
+
+const THEMES: &[(&str, &[ColorMap])] = &[
+    ("Black", color_themes::BLACK_THEME),
+    ("Dark", color_themes::DARK_THEME),
+    ("Gray", color_themes::GRAY_THEME),
+    ("Shake", color_themes::SHAKE_THEME),
+    ("Tan", color_themes::TAN_THEME),
+    ("Nord", color_themes::NORD_THEME),
+    ("Nord Light", color_themes::NORD_LIGHT_THEME),
+    ("Solarized Dark", color_themes::SOLARIZED_DARK_THEME),
+    ("Solarized Light", color_themes::SOLARIZED_LIGHT_THEME),
+    ("Dracula", color_themes::DRACULA_THEME),
+    ("Gruvbox Dark", color_themes::GRUVBOX_DARK_THEME),
+    ("Gruvbox Light", color_themes::GRUVBOX_LIGHT_THEME),
+    ("Catppuccin Latte", color_themes::CATPPUCCIN_LATTE),
+    ("Catppuccin Frappe", color_themes::CATPPUCCIN_FRAPPE),
+    ("Catppuccin Macchiato", color_themes::CATPPUCCIN_MACCHIATO),
+    ("Catppuccin Mocha", color_themes::CATPPUCCIN_MOCHA),
+    ("Monokai", color_themes::MONOKAI_THEME),
+    ("One Dark", color_themes::ONE_DARK_THEME),
+    ("One Light", color_themes::ONE_LIGHT_THEME),
+    ("Tokyo Night", color_themes::TOKYO_NIGHT_THEME),
+    ("Tokyo Night Storm", color_themes::TOKYO_NIGHT_STORM_THEME),
+    ("Tokyo Night Light", color_themes::TOKYO_NIGHT_LIGHT_THEME),
+    ("Everforest Dark", color_themes::EVERFOREST_DARK_THEME),
+    ("Everforest Light", color_themes::EVERFOREST_LIGHT_THEME),
+    ("Rose Pine", color_themes::ROSE_PINE),
+    ("Rose Pine Moon", color_themes::ROSE_PINE_MOON),
+    ("Rose Pine Dawn", color_themes::ROSE_PINE_DAWN),
+    ("Zenburn", color_themes::ZENBURN_THEME),
+    ("Sepia", color_themes::SEPIA_THEME),
+    ("AMOLED", color_themes::AMOLED_THEME),
+    ("Material Light", color_themes::MATERIAL_LIGHT_THEME),
+    ("Material Dark", color_themes::MATERIAL_DARK_THEME),
+    ("High Contrast", color_themes::HIGH_CONTRAST_THEME),
+    (
+        "High Contrast Yellow",
+        color_themes::HIGH_CONTRAST_YELLOW_THEME,
+    ),
+    ("Classic 95", color_themes::CLASSIC95_THEME),
+];
 
 fn main() {
     let a = app::App::default().with_scheme(app::Scheme::Gtk);
     app::set_visible_focus(false);
 
-    let color_theme = ColorTheme::new(color_themes::BLACK_THEME);
+    let color_theme = ColorTheme::new(THEMES[0].1);
     color_theme.apply();
     let mut win = window::Window::default().with_size(400, 300);
     let mut choice = menu::Choice::new(100, 100, 200, 30, None);
-    choice.add_choice("Black|Dark|Gray|Shake|Tan");
+    for (name, _) in THEMES {
+        choice.add_choice(name);
+    }
     choice.set_value(0);
     let mut check = button::CheckButton::new(160, 150, 80, 30, "  Check");
     check.set_value(true);
@@ -21,14 +64,8 @@ fn main() {
     win.end();
     win.show();
     choice.set_callback(|c| {
-        let theme = match c.value() {
-            0 => ColorTheme::new(color_themes::BLACK_THEME),
-            1 => ColorTheme::new(color_themes::DARK_THEME),
-            2 => ColorTheme::new(color_themes::GRAY_THEME),
-            3 => ColorTheme::new(color_themes::SHAKE_THEME),
-            4 => ColorTheme::new(color_themes::TAN_THEME),
-            _ => ColorTheme::new(color_themes::BLACK_THEME),
-        };
+        let idx = c.value().max(0) as usize;
+        let theme = ColorTheme::new(THEMES.get(idx).unwrap_or(&THEMES[0]).1);
         theme.apply();
     });
 
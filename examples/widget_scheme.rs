@@ -7,7 +7,7 @@ fn main() {
     scheme.apply();
     let mut win = window::Window::default().with_size(400, 300);
     let mut choice = menu::Choice::new(100, 100, 200, 30, None);
-    choice.add_choice("Clean|Crystal|Gleam");
+    choice.add_choice("Clean|Crystal|Gleam|Material|Neumorphic|Flat|Ios|Classic95|Elevated");
     choice.set_value(3);
     let mut check = button::CheckButton::new(160, 150, 80, 30, "Check");
     check.set_value(true);
@@ -21,6 +21,12 @@ fn main() {
             0 => WidgetScheme::new(SchemeType::Clean),
             1 => WidgetScheme::new(SchemeType::Crystal),
             2 => WidgetScheme::new(SchemeType::Gleam),
+            3 => WidgetScheme::new(SchemeType::Material),
+            4 => WidgetScheme::new(SchemeType::Neumorphic),
+            5 => WidgetScheme::new(SchemeType::Flat),
+            6 => WidgetScheme::new(SchemeType::Ios),
+            7 => WidgetScheme::new(SchemeType::Classic95),
+            8 => WidgetScheme::new(SchemeType::Elevated),
             _ => unimplemented!(),
         };
         scheme.apply();
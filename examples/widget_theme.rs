@@ -7,7 +7,7 @@ fn main() {
     theme.apply();
     let mut win = window::Window::default().with_size(400, 300);
     let mut choice = menu::Choice::new(100, 100, 200, 30, None);
-    choice.add_choice("Classic|Aero|Metro|AquaClassic|Greybird|Blue|HighContrast|Dark");
+    choice.add_choice("Classic|Aero|Metro|AquaClassic|Greybird|Blue|HighContrast|Dark|Win11|Aqua|Adwaita|AdwaitaDark|Breeze|BreezeDark|Yaru|YaruDark|Motif|Haiku|Luna|HighContrastDark|Elementary|FluentDark");
     choice.set_value(3);
     choice.set_frame(widget_themes::OS_PANEL_THIN_UP_BOX);
     let mut check = button::CheckButton::new(160, 150, 80, 30, "  Check");
@@ -30,6 +30,20 @@ fn main() {
             5 => WidgetTheme::new(ThemeType::Blue),
             6 => WidgetTheme::new(ThemeType::HighContrast),
             7 => WidgetTheme::new(ThemeType::Dark),
+            8 => WidgetTheme::new(ThemeType::Win11),
+            9 => WidgetTheme::new(ThemeType::Aqua),
+            10 => WidgetTheme::new(ThemeType::Adwaita),
+            11 => WidgetTheme::new(ThemeType::AdwaitaDark),
+            12 => WidgetTheme::new(ThemeType::Breeze),
+            13 => WidgetTheme::new(ThemeType::BreezeDark),
+            14 => WidgetTheme::new(ThemeType::Yaru),
+            15 => WidgetTheme::new(ThemeType::YaruDark),
+            16 => WidgetTheme::new(ThemeType::Motif),
+            17 => WidgetTheme::new(ThemeType::Haiku),
+            18 => WidgetTheme::new(ThemeType::Luna),
+            19 => WidgetTheme::new(ThemeType::HighContrastDark),
+            20 => WidgetTheme::new(ThemeType::Elementary),
+            21 => WidgetTheme::new(ThemeType::FluentDark),
             _ => WidgetTheme::new(ThemeType::Classic),
         };
         theme.apply();
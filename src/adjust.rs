@@ -0,0 +1,238 @@
+use crate::colors::{contrast_ratio, relative_luminance};
+use crate::ramp::{GRAY_RAMP_END, GRAY_RAMP_START};
+use crate::roles::{selection_text, PAIRINGS};
+use crate::seeds::{from_oklab_in_gamut, to_oklab};
+use crate::{ColorMap, ColorTheme};
+
+/// The smallest OKLab lightness step kept between neighbors of the gray ramp, about 4/255 of
+/// sRGB gray, so that bevels and borders stay visible however far the theme is pushed
+const MIN_RAMP_STEP: f32 = 0.015;
+
+impl ColorTheme {
+    /// Maps the OKLab lightness of every color but FL_BLACK (56) and FL_WHITE (255), keeping the
+    /// neighbors of the gray ramp at least `MIN_RAMP_STEP` apart where they were before
+    fn adjust_lightness(&self, f: impl Fn(f32) -> f32) -> ColorTheme {
+        let labs: Vec<[f32; 3]> = self
+            .colors
+            .iter()
+            .map(|c| to_oklab((c.r, c.g, c.b)))
+            .collect();
+        let mut target: Vec<f32> = labs.iter().map(|lab| f(lab[0]).clamp(0.0, 1.0)).collect();
+        // the last colormap of an index is the one apply() leaves in place
+        let ramp: Vec<usize> = (GRAY_RAMP_START..=GRAY_RAMP_END)
+            .filter_map(|index| self.colors.iter().rposition(|c| c.index == index))
+            .collect();
+        if ramp.len() > 1 {
+            // work from the dark end of the ramp, whichever index that is
+            let up = labs[ramp[ramp.len() - 1]][0] >= labs[ramp[0]][0];
+            let flip = |l: f32| if up { l } else { 1.0 - l };
+            let steps: Vec<f32> = ramp
+                .windows(2)
+                .map(|w| (flip(labs[w[1]][0]) - flip(labs[w[0]][0])).clamp(0.0, MIN_RAMP_STEP))
+                .collect();
+            let mut l: Vec<f32> = ramp.iter().map(|&pos| flip(target[pos])).collect();
+            for i in 1..l.len() {
+                l[i] = l[i].max(l[i - 1] + steps[i - 1]);
+            }
+            let last = l.len() - 1;
+            l[last] = l[last].min(1.0);
+            for i in (0..last).rev() {
+                l[i] = l[i].min(l[i + 1] - steps[i]);
+            }
+            for (&pos, l) in ramp.iter().zip(l) {
+                target[pos] = flip(l);
+            }
+        }
+        let mut theme = self.clone();
+        theme.colors = self
+            .colors
+            .iter()
+            .zip(labs)
+            .zip(target)
+            .map(|((c, [_, a, b]), l)| {
+                if c.index == 56 || c.index == 255 {
+                    return c.clone();
+                }
+                let (r, g, b) = from_oklab_in_gamut([l, a, b]);
+                ColorMap {
+                    index: c.index,
+                    r,
+                    g,
+                    b,
+                    a: c.a,
+                }
+            })
+            .collect();
+        theme
+    }
+
+    /**
+    Returns the theme with the OKLab lightness of every color shifted by delta (from -1 to 1),
+    keeping hue and chroma, e.g. for a "UI brightness" slider:
+    ```rust,no_run
+    use fltk_theme::{color_themes, ColorTheme};
+    ColorTheme::new(color_themes::DARK_THEME).adjusted_brightness(0.05).apply();
+    ```
+    Lightness is clamped to black and white, but the neighbors of the gray ramp (32 to 55) are
+    kept a few levels apart rather than crushed together, so bevels survive large shifts.
+    FL_BLACK (56) and FL_WHITE (255) are left as they are
+    */
+    pub fn adjusted_brightness(&self, delta: f32) -> ColorTheme {
+        self.adjust_lightness(|l| l + delta)
+    }
+
+    /// Returns the theme with the OKLab lightness of every color raised to `1 / gamma`: a gamma
+    /// above 1 lifts the midtones, one below 1 dims them, black and white staying in place.
+    /// The gray ramp is kept apart as in `adjusted_brightness`. A gamma which isn't a positive
+    /// number returns a copy of the theme
+    pub fn adjusted_gamma(&self, gamma: f32) -> ColorTheme {
+        if gamma <= 0.0 || !gamma.is_finite() {
+            return self.clone();
+        }
+        self.adjust_lightness(|l| l.max(0.0).powf(1.0 / gamma))
+    }
+
+    /**
+    Returns the theme with the OKLab chroma of every color scaled by factor: 0 gives a grayscale
+    theme, e.g. for a "focus mode", above 1 boosts the colors, clamped to sRGB. With
+    keep_selection, the selection (15), which the builder derives from the accent, keeps its
+    color so that a gray UI still shows what is selected:
+    ```rust,no_run
+    use fltk_theme::{color_themes, ColorTheme};
+    ColorTheme::new(color_themes::NORD_THEME)
+        .adjusted_saturation(0.0, true)
+        .apply();
+    ```
+    Each color keeps its WCAG luminance rather than its lightness, so contrasts, and therefore
+    what `validate()` reports, stay the same up to rounding
+    */
+    pub fn adjusted_saturation(&self, factor: f32, keep_selection: bool) -> ColorTheme {
+        let factor = factor.max(0.0);
+        let mut theme = self.clone();
+        theme.colors = self
+            .colors
+            .iter()
+            .map(|c| {
+                if keep_selection && c.index == 15 {
+                    return c.clone();
+                }
+                let (r, g, b) = with_chroma((c.r, c.g, c.b), factor);
+                ColorMap {
+                    index: c.index,
+                    r,
+                    g,
+                    b,
+                    a: c.a,
+                }
+            })
+            .collect();
+        theme
+    }
+
+    /**
+    Returns the theme with each color FLTK draws on another pushed away from it in OKLab
+    lightness, keeping its hue, until their WCAG contrast reaches ratio (from 1 to 21), e.g. for
+    an "Increase contrast" setting working with any theme:
+    ```rust,no_run
+    use fltk_theme::{color_themes, ColorTheme};
+    ColorTheme::new(color_themes::NORD_THEME)
+        .with_min_contrast(7.0)
+        .apply();
+    ```
+    The pairs are the foreground (0) on the background (49) and background2 (7), inactive (8) on
+    the background, and the borders of boxes (32 and FL_DARK3, 39) on the background. The text of
+    selections, which FLTK picks itself, is made to contrast by moving the selection (15) instead.
+    A color which can't reach the ratio either way gets as close as it can, and indices the theme
+    doesn't set are left unset
+    */
+    pub fn with_min_contrast(&self, ratio: f32) -> ColorTheme {
+        let ratio = ratio.clamp(1.0, 21.0);
+        let mut theme = self.clone();
+        for &(text, background) in PAIRINGS {
+            if let (Some(color), Some(background)) = (theme.value(text), theme.value(background)) {
+                theme.set_value(text, push_apart(color, background, ratio));
+            }
+        }
+        if let (Some(fg), Some(selection)) = (theme.value(0), theme.value(15)) {
+            let black = theme.value(56).unwrap_or((0, 0, 0));
+            let white = theme.value(255).unwrap_or((255, 255, 255));
+            let text = selection_text(fg, selection, black, white);
+            theme.set_value(15, push_apart(selection, text, ratio));
+        }
+        theme
+    }
+
+    /// Changes the color of the last colormap of an index, the one apply() leaves in place
+    fn set_value(&mut self, index: u8, (r, g, b): (u8, u8, u8)) {
+        if let Some(c) = self.colors.iter_mut().rev().find(|c| c.index == index) {
+            c.r = r;
+            c.g = g;
+            c.b = b;
+        }
+    }
+}
+
+/// Moves the OKLab lightness of color away from against until their contrast reaches ratio,
+/// trying the other way if the first hits black or white, and otherwise keeping the best of both
+fn push_apart(color: (u8, u8, u8), against: (u8, u8, u8), ratio: f32) -> (u8, u8, u8) {
+    if contrast_ratio(color, against) >= ratio {
+        return color;
+    }
+    let [l, a, b] = to_oklab(color);
+    let towards = |end: f32| {
+        let mut moved = color;
+        for step in 1..=100 {
+            let l = l + (end - l) * step as f32 / 100.0;
+            moved = from_oklab_in_gamut([l, a, b]);
+            if contrast_ratio(moved, against) >= ratio {
+                break;
+            }
+        }
+        moved
+    };
+    let lighter = relative_luminance(color) >= relative_luminance(against);
+    let first = towards(if lighter { 1.0 } else { 0.0 });
+    if contrast_ratio(first, against) >= ratio {
+        return first;
+    }
+    let second = towards(if lighter { 0.0 } else { 1.0 });
+    if contrast_ratio(second, against) > contrast_ratio(first, against) {
+        second
+    } else {
+        first
+    }
+}
+
+/// Scales the OKLab chroma of a color, then finds the lightness giving back its luminance
+fn with_chroma(rgb: (u8, u8, u8), factor: f32) -> (u8, u8, u8) {
+    let target = relative_luminance(rgb);
+    let off = |c: (u8, u8, u8)| (relative_luminance(c) - target).abs();
+    if factor == 1.0 {
+        return rgb;
+    }
+    if factor == 0.0 {
+        // the gray whose luminance is closest, rounding included
+        return (0..=255u8)
+            .map(|v| (v, v, v))
+            .min_by(|x, y| off(*x).total_cmp(&off(*y)))
+            .unwrap();
+    }
+    let [_, a, b] = to_oklab(rgb);
+    let (a, b) = (a * factor, b * factor);
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..20 {
+        let l = (lo + hi) / 2.0;
+        if relative_luminance(from_oklab_in_gamut([l, a, b])) < target {
+            lo = l;
+        } else {
+            hi = l;
+        }
+    }
+    let below = from_oklab_in_gamut([lo, a, b]);
+    let above = from_oklab_in_gamut([hi, a, b]);
+    if off(below) <= off(above) {
+        below
+    } else {
+        above
+    }
+}
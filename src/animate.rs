@@ -0,0 +1,135 @@
+use crate::seeds::mix_oklab;
+use crate::{defaults, ColorMap, ColorTheme};
+use fltk::{app, enums::Color};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// Time between two steps of an animation, in seconds
+const STEP: f64 = 0.016;
+
+thread_local! {
+    /// Bumped by every apply, an animation stopping when it no longer matches its own
+    static GENERATION: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Stops the running animation, if any
+pub(crate) fn cancel() -> u64 {
+    GENERATION.with(|g| {
+        g.set(g.get() + 1);
+        g.get()
+    })
+}
+
+impl ColorTheme {
+    /**
+    Applies the theme gradually, fading each color it sets from its current value to the target
+    over duration, then landing exactly on the values `apply()` would have set, translucent
+    colormaps being blended over the colors the fade started from. Applying another theme, animated
+    or not, stops the fade where it is (the next fade starting from there). A zero duration
+    applies the theme at once. Should be called from the main thread:
+    ```rust,no_run
+    use fltk_theme::{color_themes, ColorTheme};
+    use std::time::Duration;
+    ColorTheme::new(color_themes::DARK_THEME).apply_animated(Duration::from_millis(250));
+    ```
+    */
+    pub fn apply_animated(&self, duration: Duration) {
+        if duration.as_secs_f64() < STEP {
+            self.apply();
+            return;
+        }
+        let generation = cancel();
+        defaults::record_defaults();
+        let from: ColorTheme = self
+            .colors
+            .iter()
+            .map(|elem| {
+                let (r, g, b) = Color::by_index(elem.index).to_rgb();
+                ColorMap { r, g, b, ..*elem }
+            })
+            .collect();
+        // translucent colormaps are blended over the starting colors once, as apply() would,
+        // rather than over each step of the fade
+        let mut current = [(0, 0, 0); 256];
+        for elem in &self.colors {
+            current[elem.index as usize] = app::get_color(Color::by_index(elem.index));
+        }
+        let mut target = self.clone();
+        for elem in &mut target.colors {
+            let (r, g, b) = elem.over(current[elem.index as usize]);
+            current[elem.index as usize] = (r, g, b);
+            *elem = ColorMap {
+                r,
+                g,
+                b,
+                a: 255,
+                ..*elem
+            };
+        }
+        let start = Instant::now();
+        app::add_timeout3(STEP, move |handle| {
+            if GENERATION.with(|g| g.get()) != generation {
+                return;
+            }
+            let t = start.elapsed().as_secs_f32() / duration.as_secs_f32();
+            if t >= 1.0 {
+                target.apply();
+                return;
+            }
+            for elem in ColorTheme::lerp(&from, &target, t).colors {
+                app::set_color(Color::by_index(elem.index), elem.r, elem.g, elem.b);
+            }
+            app::redraw();
+            app::repeat_timeout3(STEP, handle);
+        });
+    }
+
+    /**
+    Interpolates between two themes, from a (t = 0) to b (t = 1), in OKLab so that fades between
+    colors don't go through muddy grays. The theme sets the indices set by either theme, in the
+    order of `ColorTheme::merged(a, b)`, an index set by only one of them keeping its value.
+    E.g. to dim the UI during playback by blending it towards a darkened copy, the ends giving
+    back the inputs exactly:
+    ```rust
+    use fltk_theme::{color_themes::NAMED_THEMES, ColorMap, ColorTheme};
+    let darken = |theme: &ColorTheme| {
+        let half = |c: &ColorMap| ColorMap { r: c.r / 2, g: c.g / 2, b: c.b / 2, ..*c };
+        theme.colors.iter().map(half).collect::<ColorTheme>()
+    };
+    // the indices set twice resolved
+    let dedup = |theme: &ColorTheme| ColorTheme::merged(theme, theme).colors;
+    for (_, map) in NAMED_THEMES {
+        let active = ColorTheme::new(map);
+        let darkened = darken(&active);
+        let _dimmed = ColorTheme::lerp(&active, &darkened, 0.3);
+        assert_eq!(ColorTheme::lerp(&active, &darkened, 0.0).colors, dedup(&active));
+        assert_eq!(ColorTheme::lerp(&active, &darkened, 1.0).colors, dedup(&darkened));
+    }
+    ```
+    */
+    pub fn lerp(a: &ColorTheme, b: &ColorTheme, t: f32) -> ColorTheme {
+        // the last colormap of an index is the one apply() leaves in place
+        let value = |theme: &ColorTheme, index: u8| {
+            theme
+                .colors
+                .iter()
+                .rev()
+                .find(|c| c.index == index)
+                .map(|c| (c.r, c.g, c.b))
+        };
+        let mut theme = ColorTheme::merged(a, b);
+        for elem in &mut theme.colors {
+            if let (Some(from), Some(to)) = (value(a, elem.index), value(b, elem.index)) {
+                let (r, g, b) = if t <= 0.0 {
+                    from
+                } else if t >= 1.0 {
+                    to
+                } else {
+                    mix_oklab(from, to, t)
+                };
+                *elem = ColorMap { r, g, b, ..*elem };
+            }
+        }
+        theme
+    }
+}
@@ -0,0 +1,72 @@
+use crate::ramp::gray_ramp_through;
+use crate::theme_file::{mix, ramp_ends};
+use crate::{ColorMap, ColorTheme};
+
+/// Maps a terminal palette onto FLTK's indices, skipping the slots which aren't defined.
+/// selection is the selection color, the blue slot being used otherwise
+pub(crate) fn ansi_map(
+    bg: (u8, u8, u8),
+    fg: (u8, u8, u8),
+    ansi: &[Option<(u8, u8, u8)>],
+    selection: Option<(u8, u8, u8)>,
+) -> Vec<ColorMap> {
+    let slot = |n: usize| ansi.get(n).copied().flatten();
+    let mut map = vec![];
+    let mut set = |index: u8, col: Option<(u8, u8, u8)>| {
+        if let Some((r, g, b)) = col {
+            map.push(ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            });
+        }
+    };
+    set(0, Some(fg));
+    for n in (1..7).chain(9..15) {
+        set(n as u8, slot(n));
+    }
+    set(7, Some(mix(bg, fg, 0.06)));
+    set(8, slot(8).or_else(|| Some(mix(bg, fg, 0.5))));
+    set(15, selection.or_else(|| slot(4)));
+    for n in 0..16 {
+        set(ColorTheme::ANSI_BASE_INDEX + n as u8, slot(n));
+    }
+    set(56, slot(0));
+    set(63, slot(2));
+    set(88, slot(1));
+    set(95, slot(3));
+    set(216, slot(4));
+    set(223, slot(6));
+    set(248, slot(5));
+    set(255, slot(15).or_else(|| slot(7)));
+    let (dark, light) = ramp_ends(bg, fg);
+    map.extend(gray_ramp_through(dark, bg, light));
+    map
+}
+
+impl ColorTheme {
+    /// The index of ANSI color n (0 to 15) in themes built from terminal palettes is
+    /// `ANSI_BASE_INDEX + n`. These are FLTK's free color indices, so they can be used
+    /// for things like status badges without clashing with the widget colors
+    pub const ANSI_BASE_INDEX: u8 = 16;
+
+    /**
+    Builds a theme from a 16 color terminal palette.
+    - The gray ramp is interpolated between the background and the foreground
+    - The blue slot (4) gives the selection color
+    - Slots 1 to 6 and their bright variants (9 to 14) are also FLTK's first 16 colors,
+      and slot 8 (bright black) the inactive color
+    - The normal colors are also FLTK's named colors, slot 0 being black and slot 15 white
+    - All 16 slots are available at `ANSI_BASE_INDEX + n`, e.g. `Color::by_index(ColorTheme::ANSI_BASE_INDEX + 10)` for bright green
+    */
+    pub fn from_ansi16(
+        colors: &[(u8, u8, u8); 16],
+        background: (u8, u8, u8),
+        foreground: (u8, u8, u8),
+    ) -> ColorTheme {
+        let slots: Vec<_> = colors.iter().copied().map(Some).collect();
+        ColorTheme::from(ansi_map(background, foreground, &slots, None))
+    }
+}
@@ -0,0 +1,195 @@
+use crate::colors::{is_dark, relative_luminance};
+use crate::ramp::gray_ramp_through;
+use crate::theme_file::{mix, parse_hex, strip_comment, unquote};
+use crate::{ColorMap, ColorTheme, ThemeError, ThemeInfo, Variant};
+use std::{fs, path::Path};
+
+impl ColorTheme {
+    /**
+    Parses a [base16](https://github.com/tinted-theming/home) scheme, either in the legacy format
+    (`base00: "282828"` at the top level) or the newer one with the colors nested under `palette:`.
+
+    The colors are mapped as follows:
+    - base00: background (49), with the gray ramp going darker from it and lighter towards base02
+    - base01: background2 (7), the background of inputs
+    - base03: inactive (8)
+    - base05: foreground (0)
+    - base08, base0B, base0A, base0D, base0E, base0C: red, green, yellow, blue, magenta and cyan
+      (both the first 6 indices and the FLTK named colors)
+    - base0D: selection (15)
+    - the darker and lighter of base00 and base07: black (56) and white (255)
+
+    base04, base06, base09 and base0F have no FLTK counterpart and are unused.
+    The `scheme` (or `name`), `author` and `variant` keys give the theme its info (see `info()`).
+    */
+    pub fn from_base16(s: &str) -> Result<ColorTheme, ThemeError> {
+        let mut base = [None; 16];
+        let (mut name, mut author, mut variant) = (None, None, None);
+        for (i, line) in s.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            let (key, value) = match line.split_once(':') {
+                Some(kv) => kv,
+                None => continue,
+            };
+            let key = key.trim();
+            let key = unquote(key).unwrap_or(key);
+            let text = || {
+                let value = value.trim();
+                unquote(value).unwrap_or(value).to_string()
+            };
+            match key {
+                "scheme" | "name" => name = Some(text()),
+                "author" => author = Some(text()),
+                "variant" => variant = Variant::parse(&text()),
+                _ => (),
+            }
+            let n = match key
+                .strip_prefix("base0")
+                .and_then(|n| u8::from_str_radix(n, 16).ok())
+            {
+                Some(n) if key.len() == 6 => n as usize,
+                _ => continue,
+            };
+            let value = value.trim();
+            let col = parse_hex(unquote(value).unwrap_or(value)).ok_or_else(|| {
+                ThemeError::InvalidHex {
+                    line: i + 1,
+                    key: key.to_string(),
+                    value: value.to_string(),
+                }
+            })?;
+            base[n] = Some(col);
+        }
+        let mut colors = [(0, 0, 0); 16];
+        for (n, col) in base.iter().enumerate() {
+            colors[n] = col.ok_or_else(|| ThemeError::MissingColor {
+                key: format!("base{:02X}", n),
+            })?;
+        }
+        let bg = colors[0x00];
+        let (dark, light) = if is_dark(bg) {
+            (mix(bg, (0, 0, 0), 0.4), colors[0x02])
+        } else {
+            (colors[0x03], mix(bg, (255, 255, 255), 0.5))
+        };
+        let (black, white) = if relative_luminance(colors[0x00]) < relative_luminance(colors[0x07])
+        {
+            (colors[0x00], colors[0x07])
+        } else {
+            (colors[0x07], colors[0x00])
+        };
+        let mut map = vec![];
+        let mut set = |index: u8, (r, g, b): (u8, u8, u8)| {
+            map.push(ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            })
+        };
+        set(0, colors[0x05]);
+        set(1, colors[0x08]);
+        set(2, colors[0x0B]);
+        set(3, colors[0x0A]);
+        set(4, colors[0x0D]);
+        set(5, colors[0x0E]);
+        set(6, colors[0x0C]);
+        set(7, colors[0x01]);
+        set(8, colors[0x03]);
+        set(15, colors[0x0D]);
+        set(56, black);
+        set(63, colors[0x0B]);
+        set(88, colors[0x08]);
+        set(95, colors[0x0A]);
+        set(216, colors[0x0D]);
+        set(223, colors[0x0C]);
+        set(248, colors[0x0E]);
+        set(255, white);
+        map.extend(gray_ramp_through(dark, bg, light));
+        let mut theme = ColorTheme::from(map);
+        if let Some(name) = name {
+            let variant = variant.unwrap_or_else(|| Variant::of(&theme));
+            theme = theme.with_info(ThemeInfo {
+                name,
+                author,
+                variant,
+                ..Default::default()
+            });
+        }
+        Ok(theme)
+    }
+
+    /// Loads a base16 scheme file, see `from_base16`
+    pub fn load_base16(path: &Path) -> Result<ColorTheme, ThemeError> {
+        ColorTheme::from_base16(&fs::read_to_string(path)?)
+    }
+
+    /**
+    Exports the theme as a base16 scheme (in the legacy YAML format read by `from_base16`).
+    Colors the theme doesn't set are synthesized, never left black:
+    - base00: background (49), FLTK's default gray if unset
+    - base05: foreground (0), black or white depending on the background if unset
+    - base01, base02: light1 (50) and light2 (52) for a dark background, dark1 (47) and dark2 (45)
+      for a light one, falling back to mixes of the background with the foreground
+    - base03: inactive (8), falling back to a mix of the background with the foreground
+    - base04: halfway between base03 and base05
+    - base06, base07: the foreground going towards white (255) for a dark background, black (56)
+      for a light one
+    - base08, base0A, base0B, base0C, base0D, base0E: red, yellow, green, cyan, blue and magenta,
+      read from the FLTK named colors then from indices 1 to 6, base0D also from selection (15).
+      If unset, the pure color is mixed with the foreground
+    - base09: halfway between base08 and base0A (orange)
+    - base0F: base08 mixed with the background (brown)
+    */
+    pub fn to_base16(&self, name: &str) -> String {
+        // the last colormap of an index is the one apply() leaves in place
+        let get = |index: u8| {
+            self.colors
+                .iter()
+                .rev()
+                .find(|c| c.index == index)
+                .map(|c| (c.r, c.g, c.b))
+        };
+        let bg = get(49).unwrap_or((192, 192, 192));
+        let dark = is_dark(bg);
+        let fg = get(0).unwrap_or(if dark { (255, 255, 255) } else { (0, 0, 0) });
+        let (step1, step2, end) = if dark { (50, 52, 255) } else { (47, 45, 56) };
+        let end = get(end)
+            .filter(|&c| (relative_luminance(c) > relative_luminance(fg)) == dark)
+            .unwrap_or(if dark { (255, 255, 255) } else { (0, 0, 0) });
+        let accent = |named: u8, index: u8, pure: (u8, u8, u8)| {
+            get(named)
+                .or_else(|| get(index))
+                .unwrap_or_else(|| mix(pure, fg, 0.3))
+        };
+        let mut base = [(0, 0, 0); 16];
+        base[0x00] = bg;
+        base[0x01] = get(step1).unwrap_or_else(|| mix(bg, fg, 0.08));
+        base[0x02] = get(step2).unwrap_or_else(|| mix(bg, fg, 0.16));
+        base[0x03] = get(8).unwrap_or_else(|| mix(bg, fg, 0.45));
+        base[0x04] = mix(base[0x03], fg, 0.5);
+        base[0x05] = fg;
+        base[0x06] = mix(fg, end, 0.35);
+        base[0x07] = mix(fg, end, 0.7);
+        base[0x08] = accent(88, 1, (255, 0, 0));
+        base[0x0A] = accent(95, 3, (255, 255, 0));
+        base[0x0B] = accent(63, 2, (0, 255, 0));
+        base[0x0C] = accent(223, 6, (0, 255, 255));
+        base[0x0D] = get(216)
+            .or_else(|| get(4))
+            .or_else(|| get(15))
+            .unwrap_or_else(|| mix((0, 0, 255), fg, 0.3));
+        base[0x0E] = accent(248, 5, (255, 0, 255));
+        base[0x09] = mix(base[0x08], base[0x0A], 0.5);
+        base[0x0F] = mix(base[0x08], bg, 0.4);
+        let mut s = format!(
+            "scheme: \"{}\"\nauthor: \"fltk-theme\"\n",
+            name.replace('\\', "\\\\").replace('"', "\\\"")
+        );
+        for (n, (r, g, b)) in base.iter().enumerate() {
+            s += &format!("base{:02X}: \"{:02x}{:02x}{:02x}\"\n", n, r, g, b);
+        }
+        s
+    }
+}
@@ -0,0 +1,244 @@
+use crate::colors::is_dark;
+use crate::ramp::{gray_ramp_curve, gray_ramp_through};
+use crate::roles::selection_text;
+use crate::system_theme::selection_color_for;
+use crate::theme_file::{mix, parse_hex, ramp_ends};
+use crate::{ColorMap, ColorTheme, ThemeError};
+use fltk::enums::Color;
+
+type Rgb = (u8, u8, u8);
+
+/// A color given to `ColorThemeBuilder`: an rgb tuple, a `#rrggbb` hex string or an FLTK `Color`
+pub trait IntoRgb {
+    /// The rgb value, or the offending string if it isn't a valid hex color
+    fn into_rgb(self) -> Result<(u8, u8, u8), String>;
+}
+
+impl IntoRgb for (u8, u8, u8) {
+    fn into_rgb(self) -> Result<(u8, u8, u8), String> {
+        Ok(self)
+    }
+}
+
+impl IntoRgb for &str {
+    fn into_rgb(self) -> Result<(u8, u8, u8), String> {
+        parse_hex(self.trim()).ok_or_else(|| self.to_string())
+    }
+}
+
+impl IntoRgb for String {
+    fn into_rgb(self) -> Result<(u8, u8, u8), String> {
+        parse_hex(self.trim()).ok_or(self)
+    }
+}
+
+impl IntoRgb for Color {
+    fn into_rgb(self) -> Result<(u8, u8, u8), String> {
+        Ok(self.to_rgb())
+    }
+}
+
+/**
+Builds a `ColorTheme` from the roles of its colors rather than FLTK's color indices:
+```rust,no_run
+use fltk_theme::ColorTheme;
+let theme = ColorTheme::builder()
+    .background("#1e1e2e")
+    .foreground("#cdd6f4")
+    .accent((137, 180, 250))
+    .build()
+    .unwrap();
+theme.apply();
+```
+The roles are mapped as follows, unset ones being derived from the others:
+- background: background (49), FLTK's default gray if unset
+- foreground: foreground (0), black or white depending on the background if unset
+- surface: background2 (7), the background of inputs, the background lightened by 5% if unset
+- selection_background: selection (15), derived from the accent if unset (see
+  `selection_color_for`), FLTK's default selection otherwise
+- inactive: inactive (8), halfway between the foreground and the background if unset
+- the gray ramp (32 to 55) around the background, going towards the foreground on one side and
+  towards black or white on the other, unless `with_gray_ramp` gives its ends (see `gray_ramp`)
+
+The accent is only used for the selection, FLTK having no separate accent index.
+FLTK has no index for the text of selections either: it uses the foreground, or black or white
+when the foreground doesn't contrast with the selection. The selection foreground therefore
+lightens or darkens the selection background until the two contrast, so that FLTK picks readable
+text.
+*/
+#[derive(Debug, Clone, Default)]
+pub struct ColorThemeBuilder {
+    background: Option<Rgb>,
+    foreground: Option<Rgb>,
+    surface: Option<Rgb>,
+    selection_background: Option<Rgb>,
+    selection_foreground: Option<Rgb>,
+    inactive: Option<Rgb>,
+    accent: Option<Rgb>,
+    ramp_dark: Option<Rgb>,
+    ramp_light: Option<Rgb>,
+    ramp_gamma: Option<f32>,
+    /// The first invalid color, as (role, value)
+    invalid: Option<(&'static str, String)>,
+}
+
+impl ColorThemeBuilder {
+    /// Creates a builder with no color set
+    pub fn new() -> ColorThemeBuilder {
+        ColorThemeBuilder::default()
+    }
+
+    fn set<C: IntoRgb>(
+        mut self,
+        role: &'static str,
+        field: fn(&mut ColorThemeBuilder) -> &mut Option<Rgb>,
+        color: C,
+    ) -> ColorThemeBuilder {
+        match color.into_rgb() {
+            Ok(c) => *field(&mut self) = Some(c),
+            Err(value) => {
+                self.invalid.get_or_insert((role, value));
+            }
+        }
+        self
+    }
+
+    /// Sets the background of windows and buttons
+    pub fn background<C: IntoRgb>(self, color: C) -> ColorThemeBuilder {
+        self.set("background", |b| &mut b.background, color)
+    }
+
+    /// Sets the color of text and labels
+    pub fn foreground<C: IntoRgb>(self, color: C) -> ColorThemeBuilder {
+        self.set("foreground", |b| &mut b.foreground, color)
+    }
+
+    /// Sets the background of inputs, browsers and menus
+    pub fn surface<C: IntoRgb>(self, color: C) -> ColorThemeBuilder {
+        self.set("surface", |b| &mut b.surface, color)
+    }
+
+    /// Sets the background of selected text and items
+    pub fn selection_background<C: IntoRgb>(self, color: C) -> ColorThemeBuilder {
+        self.set(
+            "selection_background",
+            |b| &mut b.selection_background,
+            color,
+        )
+    }
+
+    /// Sets the text of selected text and items, see above for how FLTK uses it
+    pub fn selection_foreground<C: IntoRgb>(self, color: C) -> ColorThemeBuilder {
+        self.set(
+            "selection_foreground",
+            |b| &mut b.selection_foreground,
+            color,
+        )
+    }
+
+    /// Sets the color of deactivated widgets
+    pub fn inactive<C: IntoRgb>(self, color: C) -> ColorThemeBuilder {
+        self.set("inactive", |b| &mut b.inactive, color)
+    }
+
+    /// Sets the accent, used for the selection unless `selection_background` is set
+    pub fn accent<C: IntoRgb>(self, color: C) -> ColorThemeBuilder {
+        self.set("accent", |b| &mut b.accent, color)
+    }
+
+    /// Fills the gray ramp from dark to light as `gray_ramp` does, instead of around the
+    /// background. The background still sets index 49 if given
+    pub fn with_gray_ramp<C: IntoRgb, D: IntoRgb>(self, dark: C, light: D) -> ColorThemeBuilder {
+        self.set("ramp_dark", |b| &mut b.ramp_dark, dark).set(
+            "ramp_light",
+            |b| &mut b.ramp_light,
+            light,
+        )
+    }
+
+    /// Sets the curve of the ramp given by `with_gray_ramp`, see `gray_ramp_curve`
+    pub fn ramp_gamma(mut self, gamma: f32) -> ColorThemeBuilder {
+        self.ramp_gamma = Some(gamma);
+        self
+    }
+
+    /// Expands the roles into FLTK's indices. Fails with `ThemeError::InvalidHex` (its line being
+    /// 0) if a string given to a setter wasn't a valid `#rrggbb` color
+    pub fn build(&self) -> Result<ColorTheme, ThemeError> {
+        if let Some((key, value)) = &self.invalid {
+            return Err(ThemeError::InvalidHex {
+                line: 0,
+                key: key.to_string(),
+                value: value.clone(),
+            });
+        }
+        let bg = self.background.unwrap_or((192, 192, 192));
+        let fg = self.foreground.unwrap_or(if is_dark(bg) {
+            (255, 255, 255)
+        } else {
+            (0, 0, 0)
+        });
+        let surface = self
+            .surface
+            .unwrap_or_else(|| mix(bg, (255, 255, 255), 0.05));
+        let inactive = self.inactive.unwrap_or_else(|| mix(fg, bg, 0.5));
+        let mut selection = self
+            .selection_background
+            .or_else(|| self.accent.map(selection_color_for))
+            .unwrap_or((0, 0, 128));
+        if let Some(text) = self.selection_foreground {
+            let (black, white) = ((0, 0, 0), (255, 255, 255));
+            let away = if is_dark(text) { white } else { black };
+            let start = selection;
+            for step in 1..=10 {
+                // FLTK keeps the text when it contrasts with the selection
+                if selection_text(text, selection, black, white) == text {
+                    break;
+                }
+                selection = mix(start, away, step as f32 / 10.0);
+            }
+        }
+        let mut map = vec![];
+        let mut set = |index: u8, (r, g, b): Rgb| {
+            map.push(ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            })
+        };
+        set(0, fg);
+        set(7, surface);
+        set(8, inactive);
+        set(15, selection);
+        match (self.ramp_dark, self.ramp_light) {
+            (Some(dark), Some(light)) => {
+                let gamma = self.ramp_gamma.unwrap_or(1.0);
+                map.extend(gray_ramp_curve(dark, light, gamma));
+                if let Some(bg) = self.background {
+                    map.retain(|c| c.index != 49);
+                    map.push(ColorMap {
+                        index: 49,
+                        r: bg.0,
+                        g: bg.1,
+                        b: bg.2,
+                        a: 255,
+                    });
+                }
+            }
+            _ => {
+                let (dark, light) = ramp_ends(bg, fg);
+                map.extend(gray_ramp_through(dark, bg, light));
+            }
+        }
+        Ok(ColorTheme::from(map))
+    }
+}
+
+impl ColorTheme {
+    /// Starts a `ColorThemeBuilder`
+    pub fn builder() -> ColorThemeBuilder {
+        ColorThemeBuilder::new()
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{cmap, ColorMap};
+
+/// AMOLED, a pure black theme which keeps the border steps of the gray ramp at #3a3a3a
+pub const AMOLED_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 230, 230, 230),
+    cmap!(1, 255, 69, 58),
+    cmap!(2, 50, 215, 75),
+    cmap!(3, 255, 214, 10),
+    cmap!(4, 61, 139, 255),
+    cmap!(5, 191, 90, 242),
+    cmap!(6, 100, 210, 255),
+    cmap!(7, 0, 0, 0),
+    cmap!(8, 142, 142, 142),
+    cmap!(9, 153, 41, 35),
+    cmap!(10, 30, 129, 45),
+    cmap!(11, 153, 128, 6),
+    cmap!(12, 37, 83, 153),
+    cmap!(13, 115, 54, 145),
+    cmap!(14, 60, 126, 153),
+    cmap!(15, 61, 139, 255),
+    cmap!(32, 0, 0, 0),
+    cmap!(33, 0, 0, 0),
+    cmap!(34, 0, 0, 0),
+    cmap!(35, 0, 0, 0),
+    cmap!(36, 0, 0, 0),
+    cmap!(37, 0, 0, 0),
+    cmap!(38, 0, 0, 0),
+    cmap!(39, 58, 58, 58),
+    cmap!(40, 0, 0, 0),
+    cmap!(41, 0, 0, 0),
+    cmap!(42, 0, 0, 0),
+    cmap!(43, 0, 0, 0),
+    cmap!(44, 0, 0, 0),
+    cmap!(45, 58, 58, 58),
+    cmap!(46, 0, 0, 0),
+    cmap!(47, 58, 58, 58),
+    cmap!(48, 0, 0, 0),
+    cmap!(49, 0, 0, 0),
+    cmap!(50, 58, 58, 58),
+    cmap!(51, 64, 64, 64),
+    cmap!(52, 70, 70, 70),
+    cmap!(53, 76, 76, 76),
+    cmap!(54, 82, 82, 82),
+    cmap!(55, 90, 90, 90),
+    cmap!(56, 58, 58, 58),
+    cmap!(60, 30, 129, 45),
+    cmap!(63, 50, 215, 75),
+    cmap!(72, 153, 41, 35),
+    cmap!(76, 153, 128, 6),
+    cmap!(88, 255, 69, 58),
+    cmap!(95, 255, 214, 10),
+    cmap!(136, 37, 83, 153),
+    cmap!(140, 60, 126, 153),
+    cmap!(152, 115, 54, 145),
+    cmap!(216, 61, 139, 255),
+    cmap!(223, 100, 210, 255),
+    cmap!(248, 191, 90, 242),
+    cmap!(255, 255, 255, 255),
+];
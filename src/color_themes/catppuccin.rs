@@ -0,0 +1,237 @@
+use crate::{cmap, ColorMap};
+
+/// Catppuccin Latte, the light flavor
+pub const CATPPUCCIN_LATTE: &[crate::ColorMap] = &[
+    cmap!(0, 76, 79, 105),
+    cmap!(1, 210, 15, 57),
+    cmap!(2, 64, 160, 43),
+    cmap!(3, 223, 142, 29),
+    cmap!(4, 30, 102, 245),
+    cmap!(5, 136, 57, 239),
+    cmap!(6, 23, 146, 153),
+    cmap!(7, 239, 241, 245),
+    cmap!(8, 140, 143, 161),
+    cmap!(9, 218, 102, 130),
+    cmap!(10, 130, 189, 121),
+    cmap!(11, 226, 178, 113),
+    cmap!(12, 110, 154, 243),
+    cmap!(13, 174, 127, 239),
+    cmap!(14, 106, 181, 187),
+    cmap!(15, 172, 176, 190),
+    cmap!(32, 156, 160, 176),
+    cmap!(33, 158, 162, 178),
+    cmap!(34, 161, 165, 180),
+    cmap!(35, 163, 167, 182),
+    cmap!(36, 165, 169, 184),
+    cmap!(37, 167, 171, 186),
+    cmap!(38, 170, 174, 188),
+    cmap!(39, 172, 176, 190),
+    cmap!(40, 175, 179, 192),
+    cmap!(41, 177, 181, 195),
+    cmap!(42, 180, 184, 197),
+    cmap!(43, 183, 187, 199),
+    cmap!(44, 185, 189, 202),
+    cmap!(45, 188, 192, 204),
+    cmap!(46, 196, 200, 211),
+    cmap!(47, 204, 208, 218),
+    cmap!(48, 220, 224, 232),
+    cmap!(49, 230, 233, 239),
+    cmap!(50, 234, 237, 242),
+    cmap!(51, 238, 240, 244),
+    cmap!(52, 242, 244, 247),
+    cmap!(53, 247, 248, 250),
+    cmap!(54, 251, 251, 252),
+    cmap!(55, 255, 255, 255),
+    cmap!(56, 76, 79, 105),
+    cmap!(60, 130, 189, 121),
+    cmap!(63, 64, 160, 43),
+    cmap!(72, 218, 102, 130),
+    cmap!(76, 226, 178, 113),
+    cmap!(88, 210, 15, 57),
+    cmap!(90, 254, 100, 11),
+    cmap!(95, 223, 142, 29),
+    cmap!(136, 110, 154, 243),
+    cmap!(140, 106, 181, 187),
+    cmap!(152, 174, 127, 239),
+    cmap!(216, 30, 102, 245),
+    cmap!(223, 23, 146, 153),
+    cmap!(248, 136, 57, 239),
+    cmap!(255, 255, 255, 255),
+];
+
+/// Catppuccin Frappé
+pub const CATPPUCCIN_FRAPPE: &[crate::ColorMap] = &[
+    cmap!(0, 198, 208, 245),
+    cmap!(1, 231, 130, 132),
+    cmap!(2, 166, 209, 137),
+    cmap!(3, 229, 200, 144),
+    cmap!(4, 140, 170, 238),
+    cmap!(5, 202, 158, 230),
+    cmap!(6, 129, 200, 190),
+    cmap!(7, 41, 44, 60),
+    cmap!(8, 131, 139, 167),
+    cmap!(9, 158, 99, 107),
+    cmap!(10, 119, 146, 110),
+    cmap!(11, 157, 141, 114),
+    cmap!(12, 103, 123, 171),
+    cmap!(13, 140, 116, 166),
+    cmap!(14, 97, 141, 142),
+    cmap!(15, 98, 104, 128),
+    cmap!(32, 35, 38, 52),
+    cmap!(33, 35, 38, 53),
+    cmap!(34, 36, 39, 53),
+    cmap!(35, 36, 39, 54),
+    cmap!(36, 37, 40, 54),
+    cmap!(37, 37, 40, 55),
+    cmap!(38, 38, 41, 56),
+    cmap!(39, 38, 41, 56),
+    cmap!(40, 39, 42, 57),
+    cmap!(41, 39, 42, 58),
+    cmap!(42, 40, 43, 58),
+    cmap!(43, 40, 43, 59),
+    cmap!(44, 41, 44, 59),
+    cmap!(45, 41, 44, 60),
+    cmap!(46, 43, 46, 62),
+    cmap!(47, 44, 48, 65),
+    cmap!(48, 46, 50, 68),
+    cmap!(49, 48, 52, 70),
+    cmap!(50, 56, 60, 80),
+    cmap!(51, 65, 69, 89),
+    cmap!(52, 73, 78, 99),
+    cmap!(53, 81, 87, 109),
+    cmap!(54, 90, 96, 118),
+    cmap!(55, 98, 104, 128),
+    cmap!(56, 35, 38, 52),
+    cmap!(60, 119, 146, 110),
+    cmap!(63, 166, 209, 137),
+    cmap!(72, 158, 99, 107),
+    cmap!(76, 157, 141, 114),
+    cmap!(88, 231, 130, 132),
+    cmap!(90, 239, 159, 118),
+    cmap!(95, 229, 200, 144),
+    cmap!(136, 103, 123, 171),
+    cmap!(140, 97, 141, 142),
+    cmap!(152, 140, 116, 166),
+    cmap!(216, 140, 170, 238),
+    cmap!(223, 129, 200, 190),
+    cmap!(248, 202, 158, 230),
+    cmap!(255, 198, 208, 245),
+];
+
+/// Catppuccin Macchiato
+pub const CATPPUCCIN_MACCHIATO: &[crate::ColorMap] = &[
+    cmap!(0, 202, 211, 245),
+    cmap!(1, 237, 135, 150),
+    cmap!(2, 166, 218, 149),
+    cmap!(3, 238, 212, 159),
+    cmap!(4, 138, 173, 244),
+    cmap!(5, 198, 160, 246),
+    cmap!(6, 139, 213, 202),
+    cmap!(7, 30, 32, 48),
+    cmap!(8, 128, 135, 162),
+    cmap!(9, 157, 97, 113),
+    cmap!(10, 114, 146, 113),
+    cmap!(11, 157, 143, 119),
+    cmap!(12, 97, 119, 170),
+    cmap!(13, 133, 112, 171),
+    cmap!(14, 98, 143, 144),
+    cmap!(15, 91, 96, 120),
+    cmap!(32, 24, 25, 38),
+    cmap!(33, 24, 26, 39),
+    cmap!(34, 25, 26, 40),
+    cmap!(35, 25, 27, 40),
+    cmap!(36, 26, 27, 41),
+    cmap!(37, 26, 28, 42),
+    cmap!(38, 27, 28, 43),
+    cmap!(39, 27, 29, 43),
+    cmap!(40, 28, 29, 44),
+    cmap!(41, 28, 30, 45),
+    cmap!(42, 29, 30, 46),
+    cmap!(43, 29, 31, 46),
+    cmap!(44, 30, 31, 47),
+    cmap!(45, 30, 32, 48),
+    cmap!(46, 32, 34, 50),
+    cmap!(47, 33, 36, 53),
+    cmap!(48, 34, 37, 56),
+    cmap!(49, 36, 39, 58),
+    cmap!(50, 45, 48, 68),
+    cmap!(51, 54, 58, 79),
+    cmap!(52, 64, 68, 90),
+    cmap!(53, 73, 77, 100),
+    cmap!(54, 82, 86, 110),
+    cmap!(55, 91, 96, 120),
+    cmap!(56, 24, 25, 38),
+    cmap!(60, 114, 146, 113),
+    cmap!(63, 166, 218, 149),
+    cmap!(72, 157, 97, 113),
+    cmap!(76, 157, 143, 119),
+    cmap!(88, 237, 135, 150),
+    cmap!(90, 245, 169, 127),
+    cmap!(95, 238, 212, 159),
+    cmap!(136, 97, 119, 170),
+    cmap!(140, 98, 143, 144),
+    cmap!(152, 133, 112, 171),
+    cmap!(216, 138, 173, 244),
+    cmap!(223, 139, 213, 202),
+    cmap!(248, 198, 160, 246),
+    cmap!(255, 202, 211, 245),
+];
+
+/// Catppuccin Mocha, the darkest flavor
+pub const CATPPUCCIN_MOCHA: &[crate::ColorMap] = &[
+    cmap!(0, 205, 214, 244),
+    cmap!(1, 243, 139, 168),
+    cmap!(2, 166, 227, 161),
+    cmap!(3, 249, 226, 175),
+    cmap!(4, 137, 180, 250),
+    cmap!(5, 203, 166, 247),
+    cmap!(6, 148, 226, 213),
+    cmap!(7, 24, 24, 37),
+    cmap!(8, 127, 132, 156),
+    cmap!(9, 158, 95, 119),
+    cmap!(10, 112, 148, 115),
+    cmap!(11, 161, 148, 123),
+    cmap!(12, 94, 120, 168),
+    cmap!(13, 134, 112, 167),
+    cmap!(14, 101, 148, 146),
+    cmap!(15, 88, 91, 112),
+    cmap!(32, 17, 17, 27),
+    cmap!(33, 18, 18, 28),
+    cmap!(34, 18, 18, 29),
+    cmap!(35, 19, 19, 29),
+    cmap!(36, 19, 19, 30),
+    cmap!(37, 20, 20, 31),
+    cmap!(38, 20, 20, 32),
+    cmap!(39, 21, 21, 32),
+    cmap!(40, 21, 21, 33),
+    cmap!(41, 22, 22, 34),
+    cmap!(42, 22, 22, 35),
+    cmap!(43, 23, 23, 35),
+    cmap!(44, 23, 23, 36),
+    cmap!(45, 24, 24, 37),
+    cmap!(46, 26, 26, 39),
+    cmap!(47, 27, 27, 42),
+    cmap!(48, 28, 28, 44),
+    cmap!(49, 30, 30, 46),
+    cmap!(50, 40, 40, 57),
+    cmap!(51, 49, 50, 68),
+    cmap!(52, 59, 60, 79),
+    cmap!(53, 69, 71, 90),
+    cmap!(54, 78, 81, 101),
+    cmap!(55, 88, 91, 112),
+    cmap!(56, 17, 17, 27),
+    cmap!(60, 112, 148, 115),
+    cmap!(63, 166, 227, 161),
+    cmap!(72, 158, 95, 119),
+    cmap!(76, 161, 148, 123),
+    cmap!(88, 243, 139, 168),
+    cmap!(90, 250, 179, 135),
+    cmap!(95, 249, 226, 175),
+    cmap!(136, 94, 120, 168),
+    cmap!(140, 101, 148, 146),
+    cmap!(152, 134, 112, 167),
+    cmap!(216, 137, 180, 250),
+    cmap!(223, 148, 226, 213),
+    cmap!(248, 203, 166, 247),
+    cmap!(255, 205, 214, 244),
+];
@@ -0,0 +1,59 @@
+use crate::{cmap, ColorMap};
+
+/// Windows 95 system colors. When combined with `ThemeType::Classic`, apply it after the widget theme
+pub const CLASSIC95_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 0, 0, 0),
+    cmap!(1, 255, 0, 0),
+    cmap!(2, 0, 255, 0),
+    cmap!(3, 255, 255, 0),
+    cmap!(4, 0, 0, 255),
+    cmap!(5, 255, 0, 255),
+    cmap!(6, 0, 255, 255),
+    cmap!(7, 255, 255, 255),
+    cmap!(8, 128, 128, 128),
+    cmap!(9, 128, 0, 0),
+    cmap!(10, 0, 128, 0),
+    cmap!(11, 128, 128, 0),
+    cmap!(12, 0, 0, 128),
+    cmap!(13, 128, 0, 128),
+    cmap!(14, 0, 128, 128),
+    cmap!(15, 0, 0, 128),
+    cmap!(32, 0, 0, 0),
+    cmap!(33, 11, 11, 11),
+    cmap!(34, 21, 21, 21),
+    cmap!(35, 32, 32, 32),
+    cmap!(36, 43, 43, 43),
+    cmap!(37, 53, 53, 53),
+    cmap!(38, 64, 64, 64),
+    cmap!(39, 75, 75, 75),
+    cmap!(40, 85, 85, 85),
+    cmap!(41, 96, 96, 96),
+    cmap!(42, 107, 107, 107),
+    cmap!(43, 117, 117, 117),
+    cmap!(44, 128, 128, 128),
+    cmap!(45, 141, 141, 141),
+    cmap!(46, 154, 154, 154),
+    cmap!(47, 166, 166, 166),
+    cmap!(48, 179, 179, 179),
+    cmap!(49, 192, 192, 192),
+    cmap!(50, 205, 205, 205),
+    cmap!(51, 217, 217, 217),
+    cmap!(52, 230, 230, 230),
+    cmap!(53, 242, 242, 242),
+    cmap!(54, 255, 255, 255),
+    cmap!(55, 255, 255, 255),
+    cmap!(56, 0, 0, 0),
+    cmap!(60, 0, 128, 0),
+    cmap!(63, 0, 255, 0),
+    cmap!(72, 128, 0, 0),
+    cmap!(76, 128, 128, 0),
+    cmap!(88, 255, 0, 0),
+    cmap!(95, 255, 255, 0),
+    cmap!(136, 0, 0, 128),
+    cmap!(140, 0, 128, 128),
+    cmap!(152, 128, 0, 128),
+    cmap!(216, 0, 0, 255),
+    cmap!(223, 0, 255, 255),
+    cmap!(248, 255, 0, 255),
+    cmap!(255, 255, 255, 255),
+];
@@ -0,0 +1,60 @@
+use crate::{cmap, ColorMap};
+
+/// Dracula, following the official color specification
+pub const DRACULA_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 248, 248, 242),
+    cmap!(1, 255, 85, 85),
+    cmap!(2, 80, 250, 123),
+    cmap!(3, 241, 250, 140),
+    cmap!(4, 189, 147, 249),
+    cmap!(5, 255, 121, 198),
+    cmap!(6, 139, 233, 253),
+    cmap!(7, 33, 34, 44),
+    cmap!(8, 98, 114, 164),
+    cmap!(9, 169, 68, 73),
+    cmap!(10, 64, 167, 95),
+    cmap!(11, 161, 167, 106),
+    cmap!(12, 129, 105, 171),
+    cmap!(13, 169, 89, 140),
+    cmap!(14, 99, 157, 173),
+    cmap!(15, 68, 71, 90),
+    cmap!(32, 25, 26, 33),
+    cmap!(33, 26, 27, 34),
+    cmap!(34, 27, 28, 35),
+    cmap!(35, 28, 29, 37),
+    cmap!(36, 29, 30, 38),
+    cmap!(37, 29, 31, 39),
+    cmap!(38, 30, 32, 40),
+    cmap!(39, 31, 33, 42),
+    cmap!(40, 32, 34, 43),
+    cmap!(41, 33, 34, 44),
+    cmap!(42, 34, 35, 45),
+    cmap!(43, 35, 36, 47),
+    cmap!(44, 36, 37, 48),
+    cmap!(45, 36, 38, 49),
+    cmap!(46, 37, 39, 50),
+    cmap!(47, 38, 40, 52),
+    cmap!(48, 39, 41, 53),
+    cmap!(49, 40, 42, 54),
+    cmap!(50, 50, 54, 72),
+    cmap!(51, 59, 66, 91),
+    cmap!(52, 69, 78, 109),
+    cmap!(53, 79, 90, 127),
+    cmap!(54, 88, 102, 146),
+    cmap!(55, 98, 114, 164),
+    cmap!(56, 25, 26, 33),
+    cmap!(60, 64, 167, 95),
+    cmap!(63, 80, 250, 123),
+    cmap!(72, 169, 68, 73),
+    cmap!(76, 161, 167, 106),
+    cmap!(88, 255, 85, 85),
+    cmap!(90, 255, 184, 108),
+    cmap!(95, 241, 250, 140),
+    cmap!(136, 129, 105, 171),
+    cmap!(140, 99, 157, 173),
+    cmap!(152, 169, 89, 140),
+    cmap!(216, 189, 147, 249),
+    cmap!(223, 139, 233, 253),
+    cmap!(248, 255, 121, 198),
+    cmap!(255, 248, 248, 242),
+];
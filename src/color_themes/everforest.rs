@@ -0,0 +1,119 @@
+use crate::{cmap, ColorMap};
+
+/// Everforest dark, the accent green is at index 63 (`Color::Green`)
+pub const EVERFOREST_DARK_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 211, 198, 170),
+    cmap!(1, 230, 126, 128),
+    cmap!(2, 167, 192, 128),
+    cmap!(3, 219, 188, 127),
+    cmap!(4, 127, 187, 179),
+    cmap!(5, 214, 153, 182),
+    cmap!(6, 131, 192, 146),
+    cmap!(7, 35, 42, 46),
+    cmap!(8, 133, 146, 137),
+    cmap!(9, 156, 97, 100),
+    cmap!(10, 118, 136, 100),
+    cmap!(11, 149, 134, 100),
+    cmap!(12, 94, 133, 131),
+    cmap!(13, 146, 113, 133),
+    cmap!(14, 97, 136, 111),
+    cmap!(15, 84, 58, 72),
+    cmap!(32, 26, 31, 34),
+    cmap!(33, 27, 32, 36),
+    cmap!(34, 28, 34, 37),
+    cmap!(35, 29, 35, 38),
+    cmap!(36, 30, 36, 40),
+    cmap!(37, 32, 38, 42),
+    cmap!(38, 33, 39, 43),
+    cmap!(39, 34, 41, 44),
+    cmap!(40, 35, 42, 46),
+    cmap!(41, 36, 43, 47),
+    cmap!(42, 37, 44, 49),
+    cmap!(43, 38, 46, 50),
+    cmap!(44, 39, 47, 52),
+    cmap!(45, 41, 48, 53),
+    cmap!(46, 42, 49, 55),
+    cmap!(47, 43, 51, 56),
+    cmap!(48, 44, 52, 58),
+    cmap!(49, 45, 53, 59),
+    cmap!(50, 52, 63, 68),
+    cmap!(51, 61, 72, 77),
+    cmap!(52, 71, 82, 88),
+    cmap!(53, 79, 88, 94),
+    cmap!(54, 82, 94, 94),
+    cmap!(55, 86, 99, 95),
+    cmap!(56, 26, 31, 34),
+    cmap!(60, 118, 136, 100),
+    cmap!(63, 167, 192, 128),
+    cmap!(72, 156, 97, 100),
+    cmap!(76, 149, 134, 100),
+    cmap!(88, 230, 126, 128),
+    cmap!(90, 230, 152, 117),
+    cmap!(95, 219, 188, 127),
+    cmap!(136, 94, 133, 131),
+    cmap!(140, 97, 136, 111),
+    cmap!(152, 146, 113, 133),
+    cmap!(216, 127, 187, 179),
+    cmap!(223, 131, 192, 146),
+    cmap!(248, 214, 153, 182),
+    cmap!(255, 211, 198, 170),
+];
+
+/// Everforest light, the accent green is at index 63 (`Color::Green`)
+pub const EVERFOREST_LIGHT_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 92, 106, 114),
+    cmap!(1, 248, 85, 82),
+    cmap!(2, 141, 161, 1),
+    cmap!(3, 223, 160, 0),
+    cmap!(4, 58, 148, 197),
+    cmap!(5, 223, 105, 186),
+    cmap!(6, 53, 167, 124),
+    cmap!(7, 253, 246, 227),
+    cmap!(8, 147, 159, 145),
+    cmap!(9, 246, 147, 136),
+    cmap!(10, 182, 193, 87),
+    cmap!(11, 231, 192, 87),
+    cmap!(12, 132, 185, 205),
+    cmap!(13, 231, 159, 198),
+    cmap!(14, 129, 196, 161),
+    cmap!(15, 234, 237, 200),
+    cmap!(32, 166, 176, 160),
+    cmap!(33, 168, 177, 161),
+    cmap!(34, 170, 179, 162),
+    cmap!(35, 171, 180, 163),
+    cmap!(36, 173, 182, 165),
+    cmap!(37, 175, 183, 166),
+    cmap!(38, 177, 185, 167),
+    cmap!(39, 178, 186, 168),
+    cmap!(40, 180, 188, 169),
+    cmap!(41, 182, 189, 170),
+    cmap!(42, 184, 191, 172),
+    cmap!(43, 185, 192, 173),
+    cmap!(44, 187, 194, 174),
+    cmap!(45, 189, 195, 175),
+    cmap!(46, 224, 220, 199),
+    cmap!(47, 230, 226, 204),
+    cmap!(48, 239, 235, 212),
+    cmap!(49, 244, 240, 217),
+    cmap!(50, 246, 242, 221),
+    cmap!(51, 248, 244, 224),
+    cmap!(52, 250, 246, 228),
+    cmap!(53, 251, 247, 232),
+    cmap!(54, 253, 249, 235),
+    cmap!(55, 255, 251, 239),
+    cmap!(56, 92, 106, 114),
+    cmap!(60, 182, 193, 87),
+    cmap!(63, 141, 161, 1),
+    cmap!(72, 246, 147, 136),
+    cmap!(76, 231, 192, 87),
+    cmap!(88, 248, 85, 82),
+    cmap!(90, 245, 125, 38),
+    cmap!(95, 223, 160, 0),
+    cmap!(136, 132, 185, 205),
+    cmap!(140, 129, 196, 161),
+    cmap!(152, 231, 159, 198),
+    cmap!(216, 58, 148, 197),
+    cmap!(223, 53, 167, 124),
+    cmap!(248, 223, 105, 186),
+    cmap!(255, 255, 251, 239),
+];
@@ -0,0 +1,119 @@
+use crate::{cmap, ColorMap};
+
+/// Gruvbox dark, with the gray ramp stepping through bg0_h to bg4
+pub const GRUVBOX_DARK_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 235, 219, 178),
+    cmap!(1, 251, 73, 52),
+    cmap!(2, 184, 187, 38),
+    cmap!(3, 250, 189, 47),
+    cmap!(4, 131, 165, 152),
+    cmap!(5, 211, 134, 155),
+    cmap!(6, 142, 192, 124),
+    cmap!(7, 40, 40, 40),
+    cmap!(8, 189, 174, 147),
+    cmap!(9, 175, 66, 53),
+    cmap!(10, 134, 135, 44),
+    cmap!(11, 174, 136, 50),
+    cmap!(12, 103, 121, 113),
+    cmap!(13, 151, 103, 115),
+    cmap!(14, 109, 138, 96),
+    cmap!(15, 104, 157, 106),
+    cmap!(32, 29, 32, 33),
+    cmap!(33, 29, 32, 33),
+    cmap!(34, 29, 32, 33),
+    cmap!(35, 29, 32, 33),
+    cmap!(36, 29, 32, 33),
+    cmap!(37, 29, 32, 33),
+    cmap!(38, 29, 32, 33),
+    cmap!(39, 29, 32, 33),
+    cmap!(40, 29, 32, 33),
+    cmap!(41, 29, 32, 33),
+    cmap!(42, 40, 40, 40),
+    cmap!(43, 40, 40, 40),
+    cmap!(44, 40, 40, 40),
+    cmap!(45, 40, 40, 40),
+    cmap!(46, 40, 40, 40),
+    cmap!(47, 40, 40, 40),
+    cmap!(48, 60, 56, 54),
+    cmap!(49, 60, 56, 54),
+    cmap!(50, 80, 73, 69),
+    cmap!(51, 80, 73, 69),
+    cmap!(52, 102, 92, 84),
+    cmap!(53, 102, 92, 84),
+    cmap!(54, 124, 111, 100),
+    cmap!(55, 124, 111, 100),
+    cmap!(56, 29, 32, 33),
+    cmap!(60, 134, 135, 44),
+    cmap!(63, 184, 187, 38),
+    cmap!(72, 175, 66, 53),
+    cmap!(76, 174, 136, 50),
+    cmap!(88, 251, 73, 52),
+    cmap!(90, 254, 128, 25),
+    cmap!(95, 250, 189, 47),
+    cmap!(136, 103, 121, 113),
+    cmap!(140, 109, 138, 96),
+    cmap!(152, 151, 103, 115),
+    cmap!(216, 131, 165, 152),
+    cmap!(223, 142, 192, 124),
+    cmap!(248, 211, 134, 155),
+    cmap!(255, 251, 241, 199),
+];
+
+/// Gruvbox light, with the gray ramp stepping through bg4 to bg0_h
+pub const GRUVBOX_LIGHT_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 60, 56, 54),
+    cmap!(1, 157, 0, 6),
+    cmap!(2, 121, 116, 14),
+    cmap!(3, 181, 118, 20),
+    cmap!(4, 7, 102, 120),
+    cmap!(5, 143, 63, 113),
+    cmap!(6, 66, 123, 88),
+    cmap!(7, 251, 241, 199),
+    cmap!(8, 102, 92, 84),
+    cmap!(9, 188, 88, 75),
+    cmap!(10, 167, 157, 80),
+    cmap!(11, 203, 158, 83),
+    cmap!(12, 98, 149, 143),
+    cmap!(13, 180, 125, 139),
+    cmap!(14, 134, 161, 124),
+    cmap!(15, 104, 157, 106),
+    cmap!(32, 124, 111, 100),
+    cmap!(33, 124, 111, 100),
+    cmap!(34, 124, 111, 100),
+    cmap!(35, 124, 111, 100),
+    cmap!(36, 124, 111, 100),
+    cmap!(37, 124, 111, 100),
+    cmap!(38, 168, 153, 132),
+    cmap!(39, 168, 153, 132),
+    cmap!(40, 168, 153, 132),
+    cmap!(41, 168, 153, 132),
+    cmap!(42, 189, 174, 147),
+    cmap!(43, 189, 174, 147),
+    cmap!(44, 189, 174, 147),
+    cmap!(45, 189, 174, 147),
+    cmap!(46, 213, 196, 161),
+    cmap!(47, 213, 196, 161),
+    cmap!(48, 235, 219, 178),
+    cmap!(49, 235, 219, 178),
+    cmap!(50, 242, 229, 188),
+    cmap!(51, 242, 229, 188),
+    cmap!(52, 251, 241, 199),
+    cmap!(53, 251, 241, 199),
+    cmap!(54, 249, 245, 215),
+    cmap!(55, 249, 245, 215),
+    cmap!(56, 40, 40, 40),
+    cmap!(60, 167, 157, 80),
+    cmap!(63, 121, 116, 14),
+    cmap!(72, 188, 88, 75),
+    cmap!(76, 203, 158, 83),
+    cmap!(88, 157, 0, 6),
+    cmap!(90, 175, 58, 3),
+    cmap!(95, 181, 118, 20),
+    cmap!(136, 98, 149, 143),
+    cmap!(140, 134, 161, 124),
+    cmap!(152, 180, 125, 139),
+    cmap!(216, 7, 102, 120),
+    cmap!(223, 66, 123, 88),
+    cmap!(248, 143, 63, 113),
+    cmap!(255, 249, 245, 215),
+];
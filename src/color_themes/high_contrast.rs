@@ -0,0 +1,117 @@
+use crate::{cmap, ColorMap};
+
+/// High contrast white on black, every visible color keeps a 7:1 contrast against the background
+pub const HIGH_CONTRAST_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 255, 255, 255),
+    cmap!(1, 255, 112, 112),
+    cmap!(2, 0, 255, 0),
+    cmap!(3, 255, 255, 0),
+    cmap!(4, 128, 176, 255),
+    cmap!(5, 255, 112, 255),
+    cmap!(6, 0, 255, 255),
+    cmap!(7, 0, 0, 0),
+    cmap!(8, 192, 192, 192),
+    cmap!(9, 255, 112, 112),
+    cmap!(10, 0, 255, 0),
+    cmap!(11, 255, 255, 0),
+    cmap!(12, 128, 176, 255),
+    cmap!(13, 255, 112, 255),
+    cmap!(14, 0, 255, 255),
+    cmap!(15, 0, 55, 218),
+    cmap!(32, 0, 0, 0),
+    cmap!(33, 0, 0, 0),
+    cmap!(34, 0, 0, 0),
+    cmap!(35, 0, 0, 0),
+    cmap!(36, 0, 0, 0),
+    cmap!(37, 0, 0, 0),
+    cmap!(38, 0, 0, 0),
+    cmap!(39, 255, 255, 255),
+    cmap!(40, 0, 0, 0),
+    cmap!(41, 0, 0, 0),
+    cmap!(42, 0, 0, 0),
+    cmap!(43, 0, 0, 0),
+    cmap!(44, 0, 0, 0),
+    cmap!(45, 255, 255, 255),
+    cmap!(46, 0, 0, 0),
+    cmap!(47, 255, 255, 255),
+    cmap!(48, 0, 0, 0),
+    cmap!(49, 0, 0, 0),
+    cmap!(50, 255, 255, 255),
+    cmap!(51, 255, 255, 255),
+    cmap!(52, 255, 255, 255),
+    cmap!(53, 255, 255, 255),
+    cmap!(54, 255, 255, 255),
+    cmap!(55, 255, 255, 255),
+    cmap!(56, 255, 255, 255),
+    cmap!(60, 0, 255, 0),
+    cmap!(63, 0, 255, 0),
+    cmap!(72, 255, 112, 112),
+    cmap!(76, 255, 255, 0),
+    cmap!(88, 255, 112, 112),
+    cmap!(95, 255, 255, 0),
+    cmap!(136, 128, 176, 255),
+    cmap!(140, 0, 255, 255),
+    cmap!(152, 255, 112, 255),
+    cmap!(216, 128, 176, 255),
+    cmap!(223, 0, 255, 255),
+    cmap!(248, 255, 112, 255),
+    cmap!(255, 255, 255, 255),
+];
+
+/// High contrast yellow on black, every visible color keeps a 7:1 contrast against the background
+pub const HIGH_CONTRAST_YELLOW_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 255, 255, 0),
+    cmap!(1, 255, 112, 112),
+    cmap!(2, 0, 255, 0),
+    cmap!(3, 255, 255, 0),
+    cmap!(4, 128, 176, 255),
+    cmap!(5, 255, 112, 255),
+    cmap!(6, 0, 255, 255),
+    cmap!(7, 0, 0, 0),
+    cmap!(8, 200, 200, 0),
+    cmap!(9, 255, 112, 112),
+    cmap!(10, 0, 255, 0),
+    cmap!(11, 255, 255, 0),
+    cmap!(12, 128, 176, 255),
+    cmap!(13, 255, 112, 255),
+    cmap!(14, 0, 255, 255),
+    cmap!(15, 0, 55, 218),
+    cmap!(32, 0, 0, 0),
+    cmap!(33, 0, 0, 0),
+    cmap!(34, 0, 0, 0),
+    cmap!(35, 0, 0, 0),
+    cmap!(36, 0, 0, 0),
+    cmap!(37, 0, 0, 0),
+    cmap!(38, 0, 0, 0),
+    cmap!(39, 255, 255, 0),
+    cmap!(40, 0, 0, 0),
+    cmap!(41, 0, 0, 0),
+    cmap!(42, 0, 0, 0),
+    cmap!(43, 0, 0, 0),
+    cmap!(44, 0, 0, 0),
+    cmap!(45, 255, 255, 0),
+    cmap!(46, 0, 0, 0),
+    cmap!(47, 255, 255, 0),
+    cmap!(48, 0, 0, 0),
+    cmap!(49, 0, 0, 0),
+    cmap!(50, 255, 255, 0),
+    cmap!(51, 255, 255, 0),
+    cmap!(52, 255, 255, 0),
+    cmap!(53, 255, 255, 0),
+    cmap!(54, 255, 255, 0),
+    cmap!(55, 255, 255, 0),
+    cmap!(56, 255, 255, 0),
+    cmap!(60, 0, 255, 0),
+    cmap!(63, 0, 255, 0),
+    cmap!(72, 255, 112, 112),
+    cmap!(76, 255, 255, 0),
+    cmap!(88, 255, 112, 112),
+    cmap!(95, 255, 255, 0),
+    cmap!(136, 128, 176, 255),
+    cmap!(140, 0, 255, 255),
+    cmap!(152, 255, 112, 255),
+    cmap!(216, 128, 176, 255),
+    cmap!(223, 0, 255, 255),
+    cmap!(248, 255, 112, 255),
+    cmap!(255, 255, 255, 0),
+];
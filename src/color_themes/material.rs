@@ -0,0 +1,117 @@
+use crate::{cmap, ColorMap};
+
+/// Material Design baseline light theme, primary is the selection color and secondary is at the cyan indices
+pub const MATERIAL_LIGHT_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 33, 33, 33),
+    cmap!(1, 176, 0, 32),
+    cmap!(2, 56, 142, 60),
+    cmap!(3, 251, 192, 45),
+    cmap!(4, 98, 0, 238),
+    cmap!(5, 156, 39, 176),
+    cmap!(6, 3, 218, 198),
+    cmap!(7, 255, 255, 255),
+    cmap!(8, 117, 117, 117),
+    cmap!(9, 206, 100, 119),
+    cmap!(10, 134, 185, 136),
+    cmap!(11, 251, 215, 127),
+    cmap!(12, 159, 100, 243),
+    cmap!(13, 194, 123, 206),
+    cmap!(14, 102, 231, 219),
+    cmap!(15, 98, 0, 238),
+    cmap!(32, 158, 158, 158),
+    cmap!(33, 163, 163, 163),
+    cmap!(34, 168, 168, 168),
+    cmap!(35, 173, 173, 173),
+    cmap!(36, 178, 178, 178),
+    cmap!(37, 183, 183, 183),
+    cmap!(38, 188, 188, 188),
+    cmap!(39, 194, 194, 194),
+    cmap!(40, 199, 199, 199),
+    cmap!(41, 204, 204, 204),
+    cmap!(42, 209, 209, 209),
+    cmap!(43, 214, 214, 214),
+    cmap!(44, 219, 219, 219),
+    cmap!(45, 224, 224, 224),
+    cmap!(46, 231, 231, 231),
+    cmap!(47, 238, 238, 238),
+    cmap!(48, 245, 245, 245),
+    cmap!(49, 250, 250, 250),
+    cmap!(50, 251, 251, 251),
+    cmap!(51, 252, 252, 252),
+    cmap!(52, 252, 252, 252),
+    cmap!(53, 253, 253, 253),
+    cmap!(54, 254, 254, 254),
+    cmap!(55, 255, 255, 255),
+    cmap!(56, 33, 33, 33),
+    cmap!(60, 134, 185, 136),
+    cmap!(63, 56, 142, 60),
+    cmap!(72, 206, 100, 119),
+    cmap!(76, 251, 215, 127),
+    cmap!(88, 176, 0, 32),
+    cmap!(95, 251, 192, 45),
+    cmap!(136, 159, 100, 243),
+    cmap!(140, 102, 231, 219),
+    cmap!(152, 194, 123, 206),
+    cmap!(216, 98, 0, 238),
+    cmap!(223, 3, 218, 198),
+    cmap!(248, 156, 39, 176),
+    cmap!(255, 255, 255, 255),
+];
+
+/// Material Design baseline dark theme, using the elevation overlay grays above the background
+pub const MATERIAL_DARK_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 225, 225, 225),
+    cmap!(1, 207, 102, 121),
+    cmap!(2, 129, 199, 132),
+    cmap!(3, 255, 241, 118),
+    cmap!(4, 187, 134, 252),
+    cmap!(5, 206, 147, 216),
+    cmap!(6, 3, 218, 198),
+    cmap!(7, 30, 30, 30),
+    cmap!(8, 138, 138, 138),
+    cmap!(9, 131, 68, 80),
+    cmap!(10, 85, 127, 86),
+    cmap!(11, 160, 152, 78),
+    cmap!(12, 119, 88, 158),
+    cmap!(13, 131, 95, 137),
+    cmap!(14, 9, 138, 126),
+    cmap!(15, 187, 134, 252),
+    cmap!(32, 0, 0, 0),
+    cmap!(33, 1, 1, 1),
+    cmap!(34, 2, 2, 2),
+    cmap!(35, 3, 3, 3),
+    cmap!(36, 4, 4, 4),
+    cmap!(37, 5, 5, 5),
+    cmap!(38, 6, 6, 6),
+    cmap!(39, 7, 7, 7),
+    cmap!(40, 8, 8, 8),
+    cmap!(41, 10, 10, 10),
+    cmap!(42, 11, 11, 11),
+    cmap!(43, 12, 12, 12),
+    cmap!(44, 13, 13, 13),
+    cmap!(45, 14, 14, 14),
+    cmap!(46, 15, 15, 15),
+    cmap!(47, 16, 16, 16),
+    cmap!(48, 17, 17, 17),
+    cmap!(49, 18, 18, 18),
+    cmap!(50, 30, 30, 30),
+    cmap!(51, 35, 35, 35),
+    cmap!(52, 39, 39, 39),
+    cmap!(53, 44, 44, 44),
+    cmap!(54, 51, 51, 51),
+    cmap!(55, 56, 56, 56),
+    cmap!(56, 0, 0, 0),
+    cmap!(60, 85, 127, 86),
+    cmap!(63, 129, 199, 132),
+    cmap!(72, 131, 68, 80),
+    cmap!(76, 160, 152, 78),
+    cmap!(88, 207, 102, 121),
+    cmap!(95, 255, 241, 118),
+    cmap!(136, 119, 88, 158),
+    cmap!(140, 9, 138, 126),
+    cmap!(152, 131, 95, 137),
+    cmap!(216, 187, 134, 252),
+    cmap!(223, 3, 218, 198),
+    cmap!(248, 206, 147, 216),
+    cmap!(255, 255, 255, 255),
+];
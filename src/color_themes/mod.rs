@@ -1,11 +1,132 @@
+mod amoled;
 mod black;
+mod catppuccin;
+mod classic95;
 mod dark;
+mod dracula;
+mod everforest;
 mod gray;
+mod gruvbox;
+mod high_contrast;
+mod material;
+mod monokai;
+mod nord;
+mod one;
+mod rose_pine;
+mod sepia;
 mod shake;
+mod solarized;
 mod tan;
+mod tokyo_night;
+mod zenburn;
 
+pub use amoled::*;
 pub use black::*;
+pub use catppuccin::*;
+pub use classic95::*;
 pub use dark::*;
+pub use dracula::*;
+pub use everforest::*;
 pub use gray::*;
+pub use gruvbox::*;
+pub use high_contrast::*;
+pub use material::*;
+pub use monokai::*;
+pub use nord::*;
+pub use one::*;
+pub use rose_pine::*;
+pub use sepia::*;
 pub use shake::*;
+pub use solarized::*;
 pub use tan::*;
+pub use tokyo_night::*;
+pub use zenburn::*;
+
+use crate::{ColorMap, ColorTheme, ThemeInfo, Variant};
+
+/// The built-in color themes by name, e.g. to fill a menu or to refer to them in a config file
+pub const NAMED_THEMES: &[(&str, &[ColorMap])] = &[
+    ("AMOLED_THEME", AMOLED_THEME),
+    ("BLACK_THEME", BLACK_THEME),
+    ("CATPPUCCIN_LATTE", CATPPUCCIN_LATTE),
+    ("CATPPUCCIN_FRAPPE", CATPPUCCIN_FRAPPE),
+    ("CATPPUCCIN_MACCHIATO", CATPPUCCIN_MACCHIATO),
+    ("CATPPUCCIN_MOCHA", CATPPUCCIN_MOCHA),
+    ("CLASSIC95_THEME", CLASSIC95_THEME),
+    ("DARK_THEME", DARK_THEME),
+    ("DRACULA_THEME", DRACULA_THEME),
+    ("EVERFOREST_DARK_THEME", EVERFOREST_DARK_THEME),
+    ("EVERFOREST_LIGHT_THEME", EVERFOREST_LIGHT_THEME),
+    ("GRAY_THEME", GRAY_THEME),
+    ("GRUVBOX_DARK_THEME", GRUVBOX_DARK_THEME),
+    ("GRUVBOX_LIGHT_THEME", GRUVBOX_LIGHT_THEME),
+    ("HIGH_CONTRAST_THEME", HIGH_CONTRAST_THEME),
+    ("HIGH_CONTRAST_YELLOW_THEME", HIGH_CONTRAST_YELLOW_THEME),
+    ("MATERIAL_LIGHT_THEME", MATERIAL_LIGHT_THEME),
+    ("MATERIAL_DARK_THEME", MATERIAL_DARK_THEME),
+    ("MONOKAI_THEME", MONOKAI_THEME),
+    ("NORD_THEME", NORD_THEME),
+    ("NORD_LIGHT_THEME", NORD_LIGHT_THEME),
+    ("ONE_DARK_THEME", ONE_DARK_THEME),
+    ("ONE_LIGHT_THEME", ONE_LIGHT_THEME),
+    ("ROSE_PINE", ROSE_PINE),
+    ("ROSE_PINE_MOON", ROSE_PINE_MOON),
+    ("ROSE_PINE_DAWN", ROSE_PINE_DAWN),
+    ("SEPIA_THEME", SEPIA_THEME),
+    ("SHAKE_THEME", SHAKE_THEME),
+    ("SOLARIZED_DARK_THEME", SOLARIZED_DARK_THEME),
+    ("SOLARIZED_LIGHT_THEME", SOLARIZED_LIGHT_THEME),
+    ("TAN_THEME", TAN_THEME),
+    ("TOKYO_NIGHT_THEME", TOKYO_NIGHT_THEME),
+    ("TOKYO_NIGHT_STORM_THEME", TOKYO_NIGHT_STORM_THEME),
+    ("TOKYO_NIGHT_LIGHT_THEME", TOKYO_NIGHT_LIGHT_THEME),
+    ("ZENBURN_THEME", ZENBURN_THEME),
+];
+
+/// Looks a built-in color theme up by its constant name, e.g. `"NORD_THEME"`
+pub fn by_name(name: &str) -> Option<&'static [ColorMap]> {
+    NAMED_THEMES
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, theme)| *theme)
+}
+
+/// Turns `SOLARIZED_DARK_THEME` into `Solarized Dark`
+fn display_name(constant: &str) -> String {
+    let name = constant.strip_suffix("_THEME").unwrap_or(constant);
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_string() + &chars.as_str().to_ascii_lowercase(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+lazy_static::lazy_static! {
+    static ref BUILTIN_THEMES: Vec<(ThemeInfo, &'static [ColorMap])> = NAMED_THEMES
+        .iter()
+        .map(|(name, colors)| {
+            let variant = Variant::of(&ColorTheme::new(colors));
+            (ThemeInfo::new(&display_name(name), variant), *colors)
+        })
+        .collect();
+}
+
+/**
+The built-in color themes with their info, named like `Solarized Dark` for
+`SOLARIZED_DARK_THEME`, in the order of `NAMED_THEMES`. E.g. to fill a theme picker:
+```rust,no_run
+use fltk_theme::{color_themes, Variant};
+for (info, _) in color_themes::builtin_themes() {
+    let badge = if info.variant == Variant::Dark { "dark" } else { "light" };
+    println!("{} ({})", info.name, badge);
+}
+```
+*/
+pub fn builtin_themes() -> &'static [(ThemeInfo, &'static [ColorMap])] {
+    &BUILTIN_THEMES
+}
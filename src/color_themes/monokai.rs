@@ -0,0 +1,60 @@
+use crate::{cmap, ColorMap};
+
+/// Monokai, matching the classic editor palette
+pub const MONOKAI_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 248, 248, 242),
+    cmap!(1, 249, 38, 114),
+    cmap!(2, 166, 226, 46),
+    cmap!(3, 230, 219, 116),
+    cmap!(4, 102, 217, 239),
+    cmap!(5, 174, 129, 255),
+    cmap!(6, 161, 239, 228),
+    cmap!(7, 30, 31, 28),
+    cmap!(8, 117, 113, 94),
+    cmap!(9, 165, 39, 82),
+    cmap!(10, 115, 152, 41),
+    cmap!(11, 154, 147, 83),
+    cmap!(12, 77, 146, 157),
+    cmap!(13, 120, 93, 167),
+    cmap!(14, 112, 159, 150),
+    cmap!(15, 73, 72, 62),
+    cmap!(32, 20, 20, 17),
+    cmap!(33, 21, 21, 18),
+    cmap!(34, 22, 22, 19),
+    cmap!(35, 23, 24, 20),
+    cmap!(36, 24, 25, 21),
+    cmap!(37, 26, 26, 22),
+    cmap!(38, 27, 27, 23),
+    cmap!(39, 28, 28, 24),
+    cmap!(40, 29, 29, 25),
+    cmap!(41, 30, 31, 26),
+    cmap!(42, 31, 32, 27),
+    cmap!(43, 32, 33, 28),
+    cmap!(44, 33, 34, 29),
+    cmap!(45, 35, 35, 30),
+    cmap!(46, 36, 36, 31),
+    cmap!(47, 37, 38, 32),
+    cmap!(48, 38, 39, 33),
+    cmap!(49, 39, 40, 34),
+    cmap!(50, 52, 52, 44),
+    cmap!(51, 65, 64, 54),
+    cmap!(52, 78, 76, 64),
+    cmap!(53, 91, 89, 74),
+    cmap!(54, 104, 101, 84),
+    cmap!(55, 117, 113, 94),
+    cmap!(56, 20, 20, 17),
+    cmap!(60, 115, 152, 41),
+    cmap!(63, 166, 226, 46),
+    cmap!(72, 165, 39, 82),
+    cmap!(76, 154, 147, 83),
+    cmap!(88, 249, 38, 114),
+    cmap!(90, 253, 151, 31),
+    cmap!(95, 230, 219, 116),
+    cmap!(136, 77, 146, 157),
+    cmap!(140, 112, 159, 150),
+    cmap!(152, 120, 93, 167),
+    cmap!(216, 102, 217, 239),
+    cmap!(223, 161, 239, 228),
+    cmap!(248, 174, 129, 255),
+    cmap!(255, 248, 248, 242),
+];
@@ -0,0 +1,117 @@
+use crate::{cmap, ColorMap};
+
+/// Nord, based on the polar night and snow storm palettes
+pub const NORD_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 216, 222, 233),
+    cmap!(1, 191, 97, 106),
+    cmap!(2, 163, 190, 140),
+    cmap!(3, 235, 203, 139),
+    cmap!(4, 94, 129, 172),
+    cmap!(5, 180, 142, 173),
+    cmap!(6, 136, 192, 208),
+    cmap!(7, 46, 52, 64),
+    cmap!(8, 138, 144, 158),
+    cmap!(9, 138, 85, 96),
+    cmap!(10, 121, 140, 117),
+    cmap!(11, 165, 148, 116),
+    cmap!(12, 80, 104, 136),
+    cmap!(13, 132, 112, 137),
+    cmap!(14, 105, 142, 158),
+    cmap!(15, 94, 129, 172),
+    cmap!(32, 29, 33, 40),
+    cmap!(33, 31, 35, 42),
+    cmap!(34, 33, 37, 45),
+    cmap!(35, 34, 39, 47),
+    cmap!(36, 36, 41, 50),
+    cmap!(37, 38, 43, 52),
+    cmap!(38, 40, 45, 55),
+    cmap!(39, 41, 47, 57),
+    cmap!(40, 43, 49, 60),
+    cmap!(41, 45, 50, 62),
+    cmap!(42, 47, 52, 65),
+    cmap!(43, 48, 54, 67),
+    cmap!(44, 50, 56, 70),
+    cmap!(45, 52, 58, 72),
+    cmap!(46, 54, 60, 75),
+    cmap!(47, 55, 62, 77),
+    cmap!(48, 57, 64, 80),
+    cmap!(49, 59, 66, 82),
+    cmap!(50, 62, 69, 86),
+    cmap!(51, 65, 73, 90),
+    cmap!(52, 68, 76, 94),
+    cmap!(53, 70, 79, 98),
+    cmap!(54, 73, 83, 102),
+    cmap!(55, 76, 86, 106),
+    cmap!(56, 29, 33, 40),
+    cmap!(60, 121, 140, 117),
+    cmap!(63, 163, 190, 140),
+    cmap!(72, 138, 85, 96),
+    cmap!(76, 165, 148, 116),
+    cmap!(88, 191, 97, 106),
+    cmap!(95, 235, 203, 139),
+    cmap!(136, 80, 104, 136),
+    cmap!(140, 105, 142, 158),
+    cmap!(152, 132, 112, 137),
+    cmap!(216, 94, 129, 172),
+    cmap!(223, 136, 192, 208),
+    cmap!(248, 180, 142, 173),
+    cmap!(255, 236, 239, 244),
+];
+
+/// Nord, light variant using snow storm backgrounds
+pub const NORD_LIGHT_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 46, 52, 64),
+    cmap!(1, 191, 97, 106),
+    cmap!(2, 163, 190, 140),
+    cmap!(3, 235, 203, 139),
+    cmap!(4, 94, 129, 172),
+    cmap!(5, 180, 142, 173),
+    cmap!(6, 136, 192, 208),
+    cmap!(7, 236, 239, 244),
+    cmap!(8, 138, 142, 152),
+    cmap!(9, 206, 151, 160),
+    cmap!(10, 189, 207, 180),
+    cmap!(11, 233, 215, 179),
+    cmap!(12, 148, 171, 199),
+    cmap!(13, 200, 178, 200),
+    cmap!(14, 173, 208, 221),
+    cmap!(15, 94, 129, 172),
+    cmap!(32, 76, 86, 106),
+    cmap!(33, 85, 95, 114),
+    cmap!(34, 94, 103, 122),
+    cmap!(35, 103, 112, 130),
+    cmap!(36, 112, 121, 138),
+    cmap!(37, 121, 129, 145),
+    cmap!(38, 130, 138, 153),
+    cmap!(39, 139, 147, 161),
+    cmap!(40, 148, 155, 169),
+    cmap!(41, 157, 164, 177),
+    cmap!(42, 166, 172, 185),
+    cmap!(43, 175, 181, 193),
+    cmap!(44, 184, 190, 201),
+    cmap!(45, 193, 198, 208),
+    cmap!(46, 202, 207, 216),
+    cmap!(47, 211, 216, 224),
+    cmap!(48, 220, 224, 232),
+    cmap!(49, 229, 233, 240),
+    cmap!(50, 233, 237, 242),
+    cmap!(51, 238, 240, 245),
+    cmap!(52, 242, 244, 248),
+    cmap!(53, 246, 248, 250),
+    cmap!(54, 251, 251, 252),
+    cmap!(55, 255, 255, 255),
+    cmap!(56, 46, 52, 64),
+    cmap!(60, 189, 207, 180),
+    cmap!(63, 163, 190, 140),
+    cmap!(72, 206, 151, 160),
+    cmap!(76, 233, 215, 179),
+    cmap!(88, 191, 97, 106),
+    cmap!(95, 235, 203, 139),
+    cmap!(136, 148, 171, 199),
+    cmap!(140, 173, 208, 221),
+    cmap!(152, 200, 178, 200),
+    cmap!(216, 94, 129, 172),
+    cmap!(223, 136, 192, 208),
+    cmap!(248, 180, 142, 173),
+    cmap!(255, 255, 255, 255),
+];
@@ -0,0 +1,119 @@
+use crate::{cmap, ColorMap};
+
+/// One Dark, after Atom's default dark syntax theme
+pub const ONE_DARK_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 171, 178, 191),
+    cmap!(1, 224, 108, 117),
+    cmap!(2, 152, 195, 121),
+    cmap!(3, 229, 192, 123),
+    cmap!(4, 97, 175, 239),
+    cmap!(5, 198, 120, 221),
+    cmap!(6, 86, 182, 194),
+    cmap!(7, 33, 37, 43),
+    cmap!(8, 92, 99, 112),
+    cmap!(9, 150, 82, 91),
+    cmap!(10, 107, 135, 93),
+    cmap!(11, 153, 133, 95),
+    cmap!(12, 74, 123, 164),
+    cmap!(13, 135, 90, 153),
+    cmap!(14, 68, 127, 137),
+    cmap!(15, 62, 68, 81),
+    cmap!(32, 24, 26, 31),
+    cmap!(33, 25, 27, 32),
+    cmap!(34, 26, 28, 33),
+    cmap!(35, 27, 29, 35),
+    cmap!(36, 28, 30, 36),
+    cmap!(37, 29, 31, 37),
+    cmap!(38, 30, 32, 38),
+    cmap!(39, 31, 33, 40),
+    cmap!(40, 32, 34, 41),
+    cmap!(41, 32, 36, 42),
+    cmap!(42, 33, 37, 43),
+    cmap!(43, 34, 38, 45),
+    cmap!(44, 35, 39, 46),
+    cmap!(45, 36, 40, 47),
+    cmap!(46, 37, 41, 48),
+    cmap!(47, 38, 42, 50),
+    cmap!(48, 39, 43, 51),
+    cmap!(49, 40, 44, 52),
+    cmap!(50, 48, 52, 60),
+    cmap!(51, 55, 60, 68),
+    cmap!(52, 63, 68, 76),
+    cmap!(53, 71, 75, 85),
+    cmap!(54, 78, 83, 93),
+    cmap!(55, 86, 91, 101),
+    cmap!(56, 24, 26, 31),
+    cmap!(60, 107, 135, 93),
+    cmap!(63, 152, 195, 121),
+    cmap!(72, 150, 82, 91),
+    cmap!(76, 153, 133, 95),
+    cmap!(88, 224, 108, 117),
+    cmap!(90, 209, 154, 102),
+    cmap!(95, 229, 192, 123),
+    cmap!(136, 74, 123, 164),
+    cmap!(140, 68, 127, 137),
+    cmap!(152, 135, 90, 153),
+    cmap!(216, 97, 175, 239),
+    cmap!(223, 86, 182, 194),
+    cmap!(248, 198, 120, 221),
+    cmap!(255, 215, 218, 224),
+];
+
+/// One Light, sharing the layout of `ONE_DARK_THEME`
+pub const ONE_LIGHT_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 56, 58, 66),
+    cmap!(1, 228, 86, 73),
+    cmap!(2, 80, 161, 79),
+    cmap!(3, 193, 132, 1),
+    cmap!(4, 64, 120, 242),
+    cmap!(5, 166, 38, 164),
+    cmap!(6, 1, 132, 188),
+    cmap!(7, 250, 250, 250),
+    cmap!(8, 160, 161, 167),
+    cmap!(9, 233, 148, 140),
+    cmap!(10, 144, 193, 144),
+    cmap!(11, 212, 175, 97),
+    cmap!(12, 134, 168, 242),
+    cmap!(13, 196, 119, 195),
+    cmap!(14, 97, 175, 209),
+    cmap!(15, 229, 229, 230),
+    cmap!(32, 120, 122, 127),
+    cmap!(33, 127, 129, 134),
+    cmap!(34, 134, 136, 140),
+    cmap!(35, 141, 143, 147),
+    cmap!(36, 148, 150, 154),
+    cmap!(37, 155, 157, 161),
+    cmap!(38, 162, 164, 167),
+    cmap!(39, 169, 171, 174),
+    cmap!(40, 176, 178, 181),
+    cmap!(41, 184, 184, 187),
+    cmap!(42, 191, 191, 194),
+    cmap!(43, 198, 198, 201),
+    cmap!(44, 205, 205, 207),
+    cmap!(45, 212, 212, 214),
+    cmap!(46, 219, 219, 221),
+    cmap!(47, 226, 226, 228),
+    cmap!(48, 233, 233, 234),
+    cmap!(49, 240, 240, 241),
+    cmap!(50, 242, 242, 243),
+    cmap!(51, 245, 245, 246),
+    cmap!(52, 248, 248, 248),
+    cmap!(53, 250, 250, 250),
+    cmap!(54, 252, 252, 253),
+    cmap!(55, 255, 255, 255),
+    cmap!(56, 56, 58, 66),
+    cmap!(60, 144, 193, 144),
+    cmap!(63, 80, 161, 79),
+    cmap!(72, 233, 148, 140),
+    cmap!(76, 212, 175, 97),
+    cmap!(88, 228, 86, 73),
+    cmap!(90, 152, 104, 1),
+    cmap!(95, 193, 132, 1),
+    cmap!(136, 134, 168, 242),
+    cmap!(140, 97, 175, 209),
+    cmap!(152, 196, 119, 195),
+    cmap!(216, 64, 120, 242),
+    cmap!(223, 1, 132, 188),
+    cmap!(248, 166, 38, 164),
+    cmap!(255, 255, 255, 255),
+];
@@ -0,0 +1,175 @@
+use crate::{cmap, ColorMap};
+
+/// Rosé Pine
+pub const ROSE_PINE: &[crate::ColorMap] = &[
+    cmap!(0, 224, 222, 244),
+    cmap!(1, 235, 111, 146),
+    cmap!(2, 49, 116, 143),
+    cmap!(3, 246, 193, 119),
+    cmap!(4, 156, 207, 216),
+    cmap!(5, 196, 167, 231),
+    cmap!(6, 235, 188, 186),
+    cmap!(7, 31, 29, 46),
+    cmap!(8, 144, 140, 170),
+    cmap!(9, 151, 76, 102),
+    cmap!(10, 39, 79, 100),
+    cmap!(11, 158, 125, 86),
+    cmap!(12, 104, 133, 144),
+    cmap!(13, 128, 109, 153),
+    cmap!(14, 151, 122, 126),
+    cmap!(15, 64, 61, 82),
+    cmap!(32, 15, 14, 22),
+    cmap!(33, 16, 15, 23),
+    cmap!(34, 16, 15, 24),
+    cmap!(35, 17, 16, 24),
+    cmap!(36, 17, 16, 25),
+    cmap!(37, 18, 17, 26),
+    cmap!(38, 19, 17, 27),
+    cmap!(39, 19, 18, 28),
+    cmap!(40, 20, 18, 29),
+    cmap!(41, 20, 19, 29),
+    cmap!(42, 21, 19, 30),
+    cmap!(43, 21, 20, 31),
+    cmap!(44, 22, 20, 32),
+    cmap!(45, 23, 21, 33),
+    cmap!(46, 23, 21, 34),
+    cmap!(47, 24, 22, 34),
+    cmap!(48, 24, 22, 35),
+    cmap!(49, 25, 23, 36),
+    cmap!(50, 33, 32, 46),
+    cmap!(51, 31, 29, 46),
+    cmap!(52, 38, 35, 58),
+    cmap!(53, 64, 61, 82),
+    cmap!(54, 73, 70, 92),
+    cmap!(55, 82, 79, 103),
+    cmap!(56, 15, 14, 22),
+    cmap!(60, 39, 79, 100),
+    cmap!(63, 49, 116, 143),
+    cmap!(72, 151, 76, 102),
+    cmap!(76, 158, 125, 86),
+    cmap!(88, 235, 111, 146),
+    cmap!(95, 246, 193, 119),
+    cmap!(136, 104, 133, 144),
+    cmap!(140, 151, 122, 126),
+    cmap!(152, 128, 109, 153),
+    cmap!(216, 156, 207, 216),
+    cmap!(223, 235, 188, 186),
+    cmap!(248, 196, 167, 231),
+    cmap!(255, 224, 222, 244),
+];
+
+/// Rosé Pine Moon
+pub const ROSE_PINE_MOON: &[crate::ColorMap] = &[
+    cmap!(0, 224, 222, 244),
+    cmap!(1, 235, 111, 146),
+    cmap!(2, 62, 143, 176),
+    cmap!(3, 246, 193, 119),
+    cmap!(4, 156, 207, 216),
+    cmap!(5, 196, 167, 231),
+    cmap!(6, 234, 154, 151),
+    cmap!(7, 42, 39, 63),
+    cmap!(8, 144, 140, 170),
+    cmap!(9, 155, 80, 109),
+    cmap!(10, 51, 99, 127),
+    cmap!(11, 162, 129, 93),
+    cmap!(12, 108, 137, 151),
+    cmap!(13, 132, 113, 160),
+    cmap!(14, 154, 106, 112),
+    cmap!(15, 68, 65, 90),
+    cmap!(32, 21, 20, 32),
+    cmap!(33, 22, 21, 33),
+    cmap!(34, 23, 22, 35),
+    cmap!(35, 23, 22, 36),
+    cmap!(36, 24, 23, 37),
+    cmap!(37, 25, 24, 38),
+    cmap!(38, 26, 25, 40),
+    cmap!(39, 27, 25, 41),
+    cmap!(40, 28, 26, 42),
+    cmap!(41, 28, 27, 44),
+    cmap!(42, 29, 28, 45),
+    cmap!(43, 30, 28, 46),
+    cmap!(44, 31, 29, 48),
+    cmap!(45, 32, 30, 49),
+    cmap!(46, 33, 31, 50),
+    cmap!(47, 33, 31, 51),
+    cmap!(48, 34, 32, 53),
+    cmap!(49, 35, 33, 54),
+    cmap!(50, 42, 40, 62),
+    cmap!(51, 42, 39, 63),
+    cmap!(52, 57, 53, 82),
+    cmap!(53, 68, 65, 90),
+    cmap!(54, 77, 74, 100),
+    cmap!(55, 86, 82, 110),
+    cmap!(56, 21, 20, 32),
+    cmap!(60, 51, 99, 127),
+    cmap!(63, 62, 143, 176),
+    cmap!(72, 155, 80, 109),
+    cmap!(76, 162, 129, 93),
+    cmap!(88, 235, 111, 146),
+    cmap!(95, 246, 193, 119),
+    cmap!(136, 108, 137, 151),
+    cmap!(140, 154, 106, 112),
+    cmap!(152, 132, 113, 160),
+    cmap!(216, 156, 207, 216),
+    cmap!(223, 234, 154, 151),
+    cmap!(248, 196, 167, 231),
+    cmap!(255, 224, 222, 244),
+];
+
+/// Rosé Pine Dawn, the light variant
+pub const ROSE_PINE_DAWN: &[crate::ColorMap] = &[
+    cmap!(0, 87, 82, 121),
+    cmap!(1, 180, 99, 122),
+    cmap!(2, 40, 105, 131),
+    cmap!(3, 234, 157, 52),
+    cmap!(4, 86, 148, 159),
+    cmap!(5, 144, 122, 169),
+    cmap!(6, 215, 130, 126),
+    cmap!(7, 255, 250, 243),
+    cmap!(8, 121, 117, 147),
+    cmap!(9, 208, 157, 168),
+    cmap!(10, 124, 161, 173),
+    cmap!(11, 240, 192, 126),
+    cmap!(12, 152, 186, 190),
+    cmap!(13, 186, 171, 196),
+    cmap!(14, 229, 176, 170),
+    cmap!(15, 223, 218, 217),
+    cmap!(32, 152, 147, 165),
+    cmap!(33, 160, 155, 171),
+    cmap!(34, 167, 163, 176),
+    cmap!(35, 175, 171, 182),
+    cmap!(36, 183, 178, 188),
+    cmap!(37, 191, 186, 194),
+    cmap!(38, 198, 194, 199),
+    cmap!(39, 206, 202, 205),
+    cmap!(40, 209, 205, 207),
+    cmap!(41, 212, 207, 209),
+    cmap!(42, 214, 210, 211),
+    cmap!(43, 217, 213, 213),
+    cmap!(44, 220, 215, 215),
+    cmap!(45, 223, 218, 217),
+    cmap!(46, 232, 226, 221),
+    cmap!(47, 242, 233, 225),
+    cmap!(48, 244, 237, 232),
+    cmap!(49, 250, 244, 237),
+    cmap!(50, 251, 245, 238),
+    cmap!(51, 252, 246, 239),
+    cmap!(52, 252, 247, 240),
+    cmap!(53, 253, 248, 241),
+    cmap!(54, 254, 249, 242),
+    cmap!(55, 255, 250, 243),
+    cmap!(56, 87, 82, 121),
+    cmap!(60, 124, 161, 173),
+    cmap!(63, 40, 105, 131),
+    cmap!(72, 208, 157, 168),
+    cmap!(76, 240, 192, 126),
+    cmap!(88, 180, 99, 122),
+    cmap!(95, 234, 157, 52),
+    cmap!(136, 152, 186, 190),
+    cmap!(140, 229, 176, 170),
+    cmap!(152, 186, 171, 196),
+    cmap!(216, 86, 148, 159),
+    cmap!(223, 215, 130, 126),
+    cmap!(248, 144, 122, 169),
+    cmap!(255, 255, 250, 243),
+];
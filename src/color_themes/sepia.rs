@@ -0,0 +1,59 @@
+use crate::{cmap, ColorMap};
+
+/// Sepia, a warm paper-like theme with a warm gray ramp
+pub const SEPIA_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 91, 70, 54),
+    cmap!(1, 160, 64, 42),
+    cmap!(2, 107, 122, 42),
+    cmap!(3, 184, 134, 11),
+    cmap!(4, 74, 106, 138),
+    cmap!(5, 138, 74, 106),
+    cmap!(6, 74, 122, 122),
+    cmap!(7, 251, 246, 234),
+    cmap!(8, 122, 98, 72),
+    cmap!(9, 194, 133, 112),
+    cmap!(10, 162, 168, 112),
+    cmap!(11, 208, 175, 93),
+    cmap!(12, 142, 158, 169),
+    cmap!(13, 180, 139, 150),
+    cmap!(14, 142, 168, 160),
+    cmap!(15, 232, 163, 61),
+    cmap!(32, 125, 106, 80),
+    cmap!(33, 131, 112, 85),
+    cmap!(34, 137, 118, 91),
+    cmap!(35, 143, 124, 96),
+    cmap!(36, 148, 130, 101),
+    cmap!(37, 154, 136, 107),
+    cmap!(38, 160, 142, 112),
+    cmap!(39, 166, 149, 117),
+    cmap!(40, 172, 155, 122),
+    cmap!(41, 178, 161, 128),
+    cmap!(42, 183, 167, 133),
+    cmap!(43, 189, 173, 138),
+    cmap!(44, 195, 179, 144),
+    cmap!(45, 201, 185, 149),
+    cmap!(46, 212, 197, 165),
+    cmap!(47, 222, 210, 180),
+    cmap!(48, 233, 222, 196),
+    cmap!(49, 244, 236, 216),
+    cmap!(50, 246, 238, 220),
+    cmap!(51, 248, 241, 224),
+    cmap!(52, 250, 243, 228),
+    cmap!(53, 251, 245, 232),
+    cmap!(54, 253, 248, 236),
+    cmap!(55, 255, 250, 240),
+    cmap!(56, 59, 44, 32),
+    cmap!(60, 162, 168, 112),
+    cmap!(63, 107, 122, 42),
+    cmap!(72, 194, 133, 112),
+    cmap!(76, 208, 175, 93),
+    cmap!(88, 160, 64, 42),
+    cmap!(95, 184, 134, 11),
+    cmap!(136, 142, 158, 169),
+    cmap!(140, 142, 168, 160),
+    cmap!(152, 180, 139, 150),
+    cmap!(216, 74, 106, 138),
+    cmap!(223, 74, 122, 122),
+    cmap!(248, 138, 74, 106),
+    cmap!(255, 255, 250, 240),
+];
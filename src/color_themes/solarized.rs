@@ -0,0 +1,117 @@
+use crate::{cmap, ColorMap};
+
+/// Solarized dark, base03 backgrounds with base0 text and base02 behind inputs
+pub const SOLARIZED_DARK_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 131, 148, 150),
+    cmap!(1, 220, 50, 47),
+    cmap!(2, 133, 153, 0),
+    cmap!(3, 181, 137, 0),
+    cmap!(4, 38, 139, 210),
+    cmap!(5, 211, 54, 130),
+    cmap!(6, 42, 161, 152),
+    cmap!(7, 7, 54, 66),
+    cmap!(8, 69, 101, 108),
+    cmap!(9, 135, 52, 55),
+    cmap!(10, 83, 113, 26),
+    cmap!(11, 111, 104, 26),
+    cmap!(12, 26, 105, 152),
+    cmap!(13, 129, 54, 104),
+    cmap!(14, 28, 118, 118),
+    cmap!(15, 38, 139, 210),
+    cmap!(32, 0, 19, 26),
+    cmap!(33, 0, 20, 28),
+    cmap!(34, 0, 22, 29),
+    cmap!(35, 0, 23, 31),
+    cmap!(36, 0, 25, 33),
+    cmap!(37, 0, 26, 34),
+    cmap!(38, 0, 27, 36),
+    cmap!(39, 0, 29, 38),
+    cmap!(40, 0, 30, 39),
+    cmap!(41, 0, 32, 41),
+    cmap!(42, 0, 33, 42),
+    cmap!(43, 0, 35, 44),
+    cmap!(44, 0, 36, 46),
+    cmap!(45, 0, 37, 47),
+    cmap!(46, 0, 39, 49),
+    cmap!(47, 0, 40, 51),
+    cmap!(48, 0, 42, 52),
+    cmap!(49, 0, 43, 54),
+    cmap!(50, 15, 54, 65),
+    cmap!(51, 29, 65, 75),
+    cmap!(52, 44, 77, 86),
+    cmap!(53, 59, 88, 96),
+    cmap!(54, 73, 99, 107),
+    cmap!(55, 88, 110, 117),
+    cmap!(56, 0, 30, 38),
+    cmap!(60, 83, 113, 26),
+    cmap!(63, 133, 153, 0),
+    cmap!(72, 135, 52, 55),
+    cmap!(76, 111, 104, 26),
+    cmap!(88, 220, 50, 47),
+    cmap!(95, 181, 137, 0),
+    cmap!(136, 26, 105, 152),
+    cmap!(140, 28, 118, 118),
+    cmap!(152, 129, 54, 104),
+    cmap!(216, 38, 139, 210),
+    cmap!(223, 42, 161, 152),
+    cmap!(248, 211, 54, 130),
+    cmap!(255, 253, 246, 227),
+];
+
+/// Solarized light, base3 backgrounds with base00 text and base2 behind inputs
+pub const SOLARIZED_LIGHT_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 101, 123, 131),
+    cmap!(1, 220, 50, 47),
+    cmap!(2, 133, 153, 0),
+    cmap!(3, 181, 137, 0),
+    cmap!(4, 38, 139, 210),
+    cmap!(5, 211, 54, 130),
+    cmap!(6, 42, 161, 152),
+    cmap!(7, 238, 232, 213),
+    cmap!(8, 170, 178, 172),
+    cmap!(9, 227, 123, 113),
+    cmap!(10, 175, 185, 85),
+    cmap!(11, 204, 175, 85),
+    cmap!(12, 118, 176, 211),
+    cmap!(13, 222, 125, 163),
+    cmap!(14, 120, 189, 176),
+    cmap!(15, 38, 139, 210),
+    cmap!(32, 147, 161, 161),
+    cmap!(33, 153, 166, 165),
+    cmap!(34, 159, 171, 169),
+    cmap!(35, 166, 176, 173),
+    cmap!(36, 172, 181, 177),
+    cmap!(37, 178, 186, 180),
+    cmap!(38, 184, 191, 184),
+    cmap!(39, 191, 196, 188),
+    cmap!(40, 197, 201, 192),
+    cmap!(41, 203, 206, 196),
+    cmap!(42, 209, 211, 200),
+    cmap!(43, 216, 216, 204),
+    cmap!(44, 222, 221, 208),
+    cmap!(45, 228, 226, 211),
+    cmap!(46, 234, 231, 215),
+    cmap!(47, 241, 236, 219),
+    cmap!(48, 247, 241, 223),
+    cmap!(49, 253, 246, 227),
+    cmap!(50, 253, 248, 232),
+    cmap!(51, 254, 249, 236),
+    cmap!(52, 254, 251, 241),
+    cmap!(53, 254, 252, 246),
+    cmap!(54, 255, 254, 250),
+    cmap!(55, 255, 255, 255),
+    cmap!(56, 7, 54, 66),
+    cmap!(60, 175, 185, 85),
+    cmap!(63, 133, 153, 0),
+    cmap!(72, 227, 123, 113),
+    cmap!(76, 204, 175, 85),
+    cmap!(88, 220, 50, 47),
+    cmap!(95, 181, 137, 0),
+    cmap!(136, 118, 176, 211),
+    cmap!(140, 120, 189, 176),
+    cmap!(152, 222, 125, 163),
+    cmap!(216, 38, 139, 210),
+    cmap!(223, 42, 161, 152),
+    cmap!(248, 211, 54, 130),
+    cmap!(255, 253, 246, 227),
+];
@@ -0,0 +1,180 @@
+use crate::{cmap, ColorMap};
+
+/// Tokyo Night
+pub const TOKYO_NIGHT_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 192, 202, 245),
+    cmap!(1, 247, 118, 142),
+    cmap!(2, 158, 206, 106),
+    cmap!(3, 224, 175, 104),
+    cmap!(4, 122, 162, 247),
+    cmap!(5, 187, 154, 247),
+    cmap!(6, 125, 207, 255),
+    cmap!(7, 22, 22, 30),
+    cmap!(8, 115, 122, 162),
+    cmap!(9, 159, 82, 100),
+    cmap!(10, 105, 134, 79),
+    cmap!(11, 145, 116, 78),
+    cmap!(12, 84, 108, 163),
+    cmap!(13, 123, 103, 163),
+    cmap!(14, 85, 135, 168),
+    cmap!(15, 122, 162, 247),
+    cmap!(32, 15, 15, 20),
+    cmap!(33, 16, 16, 21),
+    cmap!(34, 16, 16, 22),
+    cmap!(35, 17, 17, 23),
+    cmap!(36, 18, 18, 24),
+    cmap!(37, 18, 19, 25),
+    cmap!(38, 19, 19, 26),
+    cmap!(39, 20, 20, 27),
+    cmap!(40, 20, 21, 28),
+    cmap!(41, 21, 21, 30),
+    cmap!(42, 21, 22, 31),
+    cmap!(43, 22, 23, 32),
+    cmap!(44, 23, 23, 33),
+    cmap!(45, 23, 24, 34),
+    cmap!(46, 24, 25, 35),
+    cmap!(47, 25, 26, 36),
+    cmap!(48, 25, 26, 37),
+    cmap!(49, 26, 27, 38),
+    cmap!(50, 32, 34, 49),
+    cmap!(51, 39, 42, 60),
+    cmap!(52, 46, 50, 71),
+    cmap!(53, 52, 57, 82),
+    cmap!(54, 58, 64, 93),
+    cmap!(55, 65, 72, 104),
+    cmap!(56, 15, 15, 20),
+    cmap!(60, 105, 134, 79),
+    cmap!(63, 158, 206, 106),
+    cmap!(72, 159, 82, 100),
+    cmap!(76, 145, 116, 78),
+    cmap!(88, 247, 118, 142),
+    cmap!(90, 255, 158, 100),
+    cmap!(95, 224, 175, 104),
+    cmap!(136, 84, 108, 163),
+    cmap!(140, 85, 135, 168),
+    cmap!(152, 123, 103, 163),
+    cmap!(216, 122, 162, 247),
+    cmap!(223, 125, 207, 255),
+    cmap!(248, 187, 154, 247),
+    cmap!(255, 192, 202, 245),
+];
+
+/// Tokyo Night Storm. The inactive color (index 8) keeps a contrast of 3.49:1 against the
+/// background, while text drawn inactive through `Color::inactive()`, which mixes a third of the
+/// official foreground into the background, gets 2.25:1
+pub const TOKYO_NIGHT_STORM_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 192, 202, 245),
+    cmap!(1, 247, 118, 142),
+    cmap!(2, 158, 206, 106),
+    cmap!(3, 224, 175, 104),
+    cmap!(4, 122, 162, 247),
+    cmap!(5, 187, 154, 247),
+    cmap!(6, 125, 207, 255),
+    cmap!(7, 31, 35, 53),
+    cmap!(8, 115, 122, 162),
+    cmap!(9, 163, 87, 109),
+    cmap!(10, 109, 140, 87),
+    cmap!(11, 149, 121, 86),
+    cmap!(12, 88, 113, 172),
+    cmap!(13, 127, 108, 172),
+    cmap!(14, 89, 140, 177),
+    cmap!(15, 122, 162, 247),
+    cmap!(32, 22, 22, 30),
+    cmap!(33, 23, 23, 32),
+    cmap!(34, 24, 24, 33),
+    cmap!(35, 24, 25, 35),
+    cmap!(36, 25, 26, 37),
+    cmap!(37, 26, 27, 39),
+    cmap!(38, 27, 28, 40),
+    cmap!(39, 28, 29, 42),
+    cmap!(40, 29, 30, 44),
+    cmap!(41, 29, 32, 45),
+    cmap!(42, 30, 33, 47),
+    cmap!(43, 31, 34, 49),
+    cmap!(44, 32, 35, 50),
+    cmap!(45, 33, 36, 52),
+    cmap!(46, 34, 37, 54),
+    cmap!(47, 34, 38, 56),
+    cmap!(48, 35, 39, 57),
+    cmap!(49, 36, 40, 59),
+    cmap!(50, 44, 49, 70),
+    cmap!(51, 52, 57, 81),
+    cmap!(52, 60, 66, 92),
+    cmap!(53, 68, 75, 104),
+    cmap!(54, 76, 83, 115),
+    cmap!(55, 84, 92, 126),
+    cmap!(56, 22, 22, 30),
+    cmap!(60, 109, 140, 87),
+    cmap!(63, 158, 206, 106),
+    cmap!(72, 163, 87, 109),
+    cmap!(76, 149, 121, 86),
+    cmap!(88, 247, 118, 142),
+    cmap!(90, 255, 158, 100),
+    cmap!(95, 224, 175, 104),
+    cmap!(136, 88, 113, 172),
+    cmap!(140, 89, 140, 177),
+    cmap!(152, 127, 108, 172),
+    cmap!(216, 122, 162, 247),
+    cmap!(223, 125, 207, 255),
+    cmap!(248, 187, 154, 247),
+    cmap!(255, 192, 202, 245),
+];
+
+/// Tokyo Night Light
+pub const TOKYO_NIGHT_LIGHT_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 52, 59, 88),
+    cmap!(1, 140, 67, 81),
+    cmap!(2, 72, 94, 48),
+    cmap!(3, 143, 94, 21),
+    cmap!(4, 52, 84, 138),
+    cmap!(5, 90, 74, 120),
+    cmap!(6, 15, 75, 110),
+    cmap!(7, 230, 231, 237),
+    cmap!(8, 108, 110, 117),
+    cmap!(9, 169, 126, 136),
+    cmap!(10, 128, 142, 116),
+    cmap!(11, 171, 142, 100),
+    cmap!(12, 116, 136, 170),
+    cmap!(13, 139, 130, 160),
+    cmap!(14, 94, 131, 154),
+    cmap!(15, 122, 162, 247),
+    cmap!(32, 150, 153, 163),
+    cmap!(33, 154, 157, 166),
+    cmap!(34, 157, 160, 170),
+    cmap!(35, 161, 164, 173),
+    cmap!(36, 165, 167, 176),
+    cmap!(37, 169, 171, 179),
+    cmap!(38, 172, 175, 183),
+    cmap!(39, 176, 178, 186),
+    cmap!(40, 180, 182, 189),
+    cmap!(41, 183, 185, 193),
+    cmap!(42, 187, 189, 196),
+    cmap!(43, 191, 192, 199),
+    cmap!(44, 194, 196, 203),
+    cmap!(45, 198, 200, 206),
+    cmap!(46, 202, 203, 209),
+    cmap!(47, 206, 207, 212),
+    cmap!(48, 209, 210, 216),
+    cmap!(49, 213, 214, 219),
+    cmap!(50, 218, 219, 224),
+    cmap!(51, 223, 224, 229),
+    cmap!(52, 228, 230, 234),
+    cmap!(53, 234, 235, 239),
+    cmap!(54, 239, 240, 244),
+    cmap!(55, 244, 245, 249),
+    cmap!(56, 52, 59, 88),
+    cmap!(60, 128, 142, 116),
+    cmap!(63, 72, 94, 48),
+    cmap!(72, 169, 126, 136),
+    cmap!(76, 171, 142, 100),
+    cmap!(88, 140, 67, 81),
+    cmap!(90, 150, 80, 39),
+    cmap!(95, 143, 94, 21),
+    cmap!(136, 116, 136, 170),
+    cmap!(140, 94, 131, 154),
+    cmap!(152, 139, 130, 160),
+    cmap!(216, 52, 84, 138),
+    cmap!(223, 15, 75, 110),
+    cmap!(248, 90, 74, 120),
+    cmap!(255, 244, 245, 249),
+];
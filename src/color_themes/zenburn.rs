@@ -0,0 +1,60 @@
+use crate::{cmap, ColorMap};
+
+/// Zenburn, a low contrast theme with a gray ramp compressed around the background
+pub const ZENBURN_THEME: &[crate::ColorMap] = &[
+    cmap!(0, 220, 220, 204),
+    cmap!(1, 204, 147, 147),
+    cmap!(2, 127, 159, 127),
+    cmap!(3, 240, 223, 175),
+    cmap!(4, 140, 208, 211),
+    cmap!(5, 220, 140, 195),
+    cmap!(6, 147, 224, 227),
+    cmap!(7, 56, 56, 56),
+    cmap!(8, 159, 159, 143),
+    cmap!(9, 148, 113, 113),
+    cmap!(10, 101, 121, 101),
+    cmap!(11, 169, 159, 130),
+    cmap!(12, 109, 150, 152),
+    cmap!(13, 157, 109, 142),
+    cmap!(14, 113, 160, 161),
+    cmap!(15, 95, 95, 95),
+    cmap!(32, 28, 28, 28),
+    cmap!(33, 29, 29, 29),
+    cmap!(34, 30, 30, 30),
+    cmap!(35, 31, 31, 31),
+    cmap!(36, 32, 32, 32),
+    cmap!(37, 34, 34, 34),
+    cmap!(38, 35, 35, 35),
+    cmap!(39, 36, 36, 36),
+    cmap!(40, 38, 38, 38),
+    cmap!(41, 39, 39, 39),
+    cmap!(42, 41, 41, 41),
+    cmap!(43, 42, 42, 42),
+    cmap!(44, 44, 44, 44),
+    cmap!(45, 46, 46, 46),
+    cmap!(46, 48, 48, 48),
+    cmap!(47, 51, 51, 51),
+    cmap!(48, 55, 55, 55),
+    cmap!(49, 63, 63, 63),
+    cmap!(50, 83, 83, 83),
+    cmap!(51, 91, 91, 91),
+    cmap!(52, 97, 97, 97),
+    cmap!(53, 102, 102, 102),
+    cmap!(54, 107, 107, 107),
+    cmap!(55, 111, 111, 111),
+    cmap!(56, 28, 28, 28),
+    cmap!(60, 101, 121, 101),
+    cmap!(63, 127, 159, 127),
+    cmap!(72, 148, 113, 113),
+    cmap!(76, 169, 159, 130),
+    cmap!(88, 204, 147, 147),
+    cmap!(90, 223, 175, 143),
+    cmap!(95, 240, 223, 175),
+    cmap!(136, 109, 150, 152),
+    cmap!(140, 113, 160, 161),
+    cmap!(152, 157, 109, 142),
+    cmap!(216, 140, 208, 211),
+    cmap!(223, 147, 224, 227),
+    cmap!(248, 220, 140, 195),
+    cmap!(255, 255, 255, 239),
+];
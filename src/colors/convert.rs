@@ -0,0 +1,131 @@
+use fltk::enums::Color;
+
+/// A color the helpers of this module take and return: an rgb triple or an FLTK `Color`
+pub trait RgbColor: Sized {
+    /// The rgb value
+    fn rgb(&self) -> (u8, u8, u8);
+    /// A color of the same type from an rgb value
+    fn with_rgb(rgb: (u8, u8, u8)) -> Self;
+}
+
+impl RgbColor for (u8, u8, u8) {
+    fn rgb(&self) -> (u8, u8, u8) {
+        *self
+    }
+
+    fn with_rgb(rgb: (u8, u8, u8)) -> Self {
+        rgb
+    }
+}
+
+impl RgbColor for Color {
+    fn rgb(&self) -> (u8, u8, u8) {
+        self.to_rgb()
+    }
+
+    fn with_rgb((r, g, b): (u8, u8, u8)) -> Self {
+        Color::from_rgb(r, g, b)
+    }
+}
+
+/// The hue (from 0 to 360 degrees) and the maximum and minimum of the components (from 0 to 1)
+fn hue_max_min((r, g, b): (u8, u8, u8)) -> (f32, f32, f32) {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+    let h = if d == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / d + 2.0)
+    } else {
+        60.0 * ((r - g) / d + 4.0)
+    };
+    (h, max, min)
+}
+
+/// The rgb value of a hue and chroma, plus m added to each component
+fn from_hue_chroma(h: f32, c: f32, m: f32) -> (u8, u8, u8) {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let f = |v: f32| ((v + m).clamp(0.0, 1.0) * 255.0).round() as u8;
+    (f(r), f(g), f(b))
+}
+
+/// Converts to HSL: the hue from 0 to 360 degrees, the saturation and the lightness from 0 to 1
+pub fn rgb_to_hsl(rgb: (u8, u8, u8)) -> (f32, f32, f32) {
+    let (h, max, min) = hue_max_min(rgb);
+    let l = (max + min) / 2.0;
+    let s = if max == min {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+    };
+    (h, s, l)
+}
+
+/// Converts from HSL, see `rgb_to_hsl`. The hue wraps around, the saturation and the lightness
+/// are clamped
+pub fn hsl_to_rgb((h, s, l): (f32, f32, f32)) -> (u8, u8, u8) {
+    let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    from_hue_chroma(h, c, l - c / 2.0)
+}
+
+/// Converts to HSV: the hue from 0 to 360 degrees, the saturation and the value from 0 to 1
+pub fn rgb_to_hsv(rgb: (u8, u8, u8)) -> (f32, f32, f32) {
+    let (h, max, min) = hue_max_min(rgb);
+    let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+    (h, s, max)
+}
+
+/// Converts from HSV, see `rgb_to_hsv`. The hue wraps around, the saturation and the value are
+/// clamped
+pub fn hsv_to_rgb((h, s, v): (f32, f32, f32)) -> (u8, u8, u8) {
+    let (s, v) = (s.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+    let c = v * s;
+    from_hue_chroma(h, c, v - c)
+}
+
+/**
+Sets the HSL lightness (from 0 to 1) of a color, keeping its hue and saturation, unlike
+`Color::lighter()` and `darker()` which mix it with white or black:
+```rust
+use fltk_theme::colors::convert::{rgb_to_hsl, with_lightness};
+let hover = with_lightness((0x1e, 0x88, 0xe5), 0.65);
+assert!((rgb_to_hsl(hover).2 - 0.65).abs() < 0.01);
+```
+*/
+pub fn with_lightness<C: RgbColor>(color: C, l: f32) -> C {
+    let (h, s, _) = rgb_to_hsl(color.rgb());
+    C::with_rgb(hsl_to_rgb((h, s, l)))
+}
+
+/// Sets the HSL saturation (from 0 to 1) of a color, keeping its hue and lightness
+pub fn with_saturation<C: RgbColor>(color: C, s: f32) -> C {
+    let (h, _, l) = rgb_to_hsl(color.rgb());
+    C::with_rgb(hsl_to_rgb((h, s, l)))
+}
+
+/// Rotates the hue of a color by deg degrees, keeping its saturation and lightness
+pub fn rotate_hue<C: RgbColor>(color: C, deg: f32) -> C {
+    let (h, s, l) = rgb_to_hsl(color.rgb());
+    C::with_rgb(hsl_to_rgb((h + deg, s, l)))
+}
+
+/// Adds amount (from -1 to 1) to the HSL lightness of a color, a hue-preserving
+/// `Color::lighter()` (amount > 0) or `darker()` (amount < 0)
+pub fn shift_lightness<C: RgbColor>(color: C, amount: f32) -> C {
+    let (h, s, l) = rgb_to_hsl(color.rgb());
+    C::with_rgb(hsl_to_rgb((h, s, l + amount)))
+}
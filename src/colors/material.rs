@@ -0,0 +1,857 @@
+use fltk::enums::Color;
+
+type Rgb = (u8, u8, u8);
+
+/// The shades of each hue, from lightest to darkest, then the accents, which brown, gray and
+/// blue gray don't have
+pub const SHADES: [&str; 14] = [
+    "50", "100", "200", "300", "400", "500", "600", "700", "800", "900", "A100", "A200", "A400",
+    "A700",
+];
+
+pub const RED_50: Rgb = (255, 235, 238);
+pub const RED_100: Rgb = (255, 205, 210);
+pub const RED_200: Rgb = (239, 154, 154);
+pub const RED_300: Rgb = (229, 115, 115);
+pub const RED_400: Rgb = (239, 83, 80);
+pub const RED_500: Rgb = (244, 67, 54);
+pub const RED_600: Rgb = (229, 57, 53);
+pub const RED_700: Rgb = (211, 47, 47);
+pub const RED_800: Rgb = (198, 40, 40);
+pub const RED_900: Rgb = (183, 28, 28);
+pub const RED_A100: Rgb = (255, 138, 128);
+pub const RED_A200: Rgb = (255, 82, 82);
+pub const RED_A400: Rgb = (255, 23, 68);
+pub const RED_A700: Rgb = (213, 0, 0);
+
+pub const PINK_50: Rgb = (252, 228, 236);
+pub const PINK_100: Rgb = (248, 187, 208);
+pub const PINK_200: Rgb = (244, 143, 177);
+pub const PINK_300: Rgb = (240, 98, 146);
+pub const PINK_400: Rgb = (236, 64, 122);
+pub const PINK_500: Rgb = (233, 30, 99);
+pub const PINK_600: Rgb = (216, 27, 96);
+pub const PINK_700: Rgb = (194, 24, 91);
+pub const PINK_800: Rgb = (173, 20, 87);
+pub const PINK_900: Rgb = (136, 14, 79);
+pub const PINK_A100: Rgb = (255, 128, 171);
+pub const PINK_A200: Rgb = (255, 64, 129);
+pub const PINK_A400: Rgb = (245, 0, 87);
+pub const PINK_A700: Rgb = (197, 17, 98);
+
+pub const PURPLE_50: Rgb = (243, 229, 245);
+pub const PURPLE_100: Rgb = (225, 190, 231);
+pub const PURPLE_200: Rgb = (206, 147, 216);
+pub const PURPLE_300: Rgb = (186, 104, 200);
+pub const PURPLE_400: Rgb = (171, 71, 188);
+pub const PURPLE_500: Rgb = (156, 39, 176);
+pub const PURPLE_600: Rgb = (142, 36, 170);
+pub const PURPLE_700: Rgb = (123, 31, 162);
+pub const PURPLE_800: Rgb = (106, 27, 154);
+pub const PURPLE_900: Rgb = (74, 20, 140);
+pub const PURPLE_A100: Rgb = (234, 128, 252);
+pub const PURPLE_A200: Rgb = (224, 64, 251);
+pub const PURPLE_A400: Rgb = (213, 0, 249);
+pub const PURPLE_A700: Rgb = (170, 0, 255);
+
+pub const DEEP_PURPLE_50: Rgb = (237, 231, 246);
+pub const DEEP_PURPLE_100: Rgb = (209, 196, 233);
+pub const DEEP_PURPLE_200: Rgb = (179, 157, 219);
+pub const DEEP_PURPLE_300: Rgb = (149, 117, 205);
+pub const DEEP_PURPLE_400: Rgb = (126, 87, 194);
+pub const DEEP_PURPLE_500: Rgb = (103, 58, 183);
+pub const DEEP_PURPLE_600: Rgb = (94, 53, 177);
+pub const DEEP_PURPLE_700: Rgb = (81, 45, 168);
+pub const DEEP_PURPLE_800: Rgb = (69, 39, 160);
+pub const DEEP_PURPLE_900: Rgb = (49, 27, 146);
+pub const DEEP_PURPLE_A100: Rgb = (179, 136, 255);
+pub const DEEP_PURPLE_A200: Rgb = (124, 77, 255);
+pub const DEEP_PURPLE_A400: Rgb = (101, 31, 255);
+pub const DEEP_PURPLE_A700: Rgb = (98, 0, 234);
+
+pub const INDIGO_50: Rgb = (232, 234, 246);
+pub const INDIGO_100: Rgb = (197, 202, 233);
+pub const INDIGO_200: Rgb = (159, 168, 218);
+pub const INDIGO_300: Rgb = (121, 134, 203);
+pub const INDIGO_400: Rgb = (92, 107, 192);
+pub const INDIGO_500: Rgb = (63, 81, 181);
+pub const INDIGO_600: Rgb = (57, 73, 171);
+pub const INDIGO_700: Rgb = (48, 63, 159);
+pub const INDIGO_800: Rgb = (40, 53, 147);
+pub const INDIGO_900: Rgb = (26, 35, 126);
+pub const INDIGO_A100: Rgb = (140, 158, 255);
+pub const INDIGO_A200: Rgb = (83, 109, 254);
+pub const INDIGO_A400: Rgb = (61, 90, 254);
+pub const INDIGO_A700: Rgb = (48, 79, 254);
+
+pub const BLUE_50: Rgb = (227, 242, 253);
+pub const BLUE_100: Rgb = (187, 222, 251);
+pub const BLUE_200: Rgb = (144, 202, 249);
+pub const BLUE_300: Rgb = (100, 181, 246);
+pub const BLUE_400: Rgb = (66, 165, 245);
+pub const BLUE_500: Rgb = (33, 150, 243);
+pub const BLUE_600: Rgb = (30, 136, 229);
+pub const BLUE_700: Rgb = (25, 118, 210);
+pub const BLUE_800: Rgb = (21, 101, 192);
+pub const BLUE_900: Rgb = (13, 71, 161);
+pub const BLUE_A100: Rgb = (130, 177, 255);
+pub const BLUE_A200: Rgb = (68, 138, 255);
+pub const BLUE_A400: Rgb = (41, 121, 255);
+pub const BLUE_A700: Rgb = (41, 98, 255);
+
+pub const LIGHT_BLUE_50: Rgb = (225, 245, 254);
+pub const LIGHT_BLUE_100: Rgb = (179, 229, 252);
+pub const LIGHT_BLUE_200: Rgb = (129, 212, 250);
+pub const LIGHT_BLUE_300: Rgb = (79, 195, 247);
+pub const LIGHT_BLUE_400: Rgb = (41, 182, 246);
+pub const LIGHT_BLUE_500: Rgb = (3, 169, 244);
+pub const LIGHT_BLUE_600: Rgb = (3, 155, 229);
+pub const LIGHT_BLUE_700: Rgb = (2, 136, 209);
+pub const LIGHT_BLUE_800: Rgb = (2, 119, 189);
+pub const LIGHT_BLUE_900: Rgb = (1, 87, 155);
+pub const LIGHT_BLUE_A100: Rgb = (128, 216, 255);
+pub const LIGHT_BLUE_A200: Rgb = (64, 196, 255);
+pub const LIGHT_BLUE_A400: Rgb = (0, 176, 255);
+pub const LIGHT_BLUE_A700: Rgb = (0, 145, 234);
+
+pub const CYAN_50: Rgb = (224, 247, 250);
+pub const CYAN_100: Rgb = (178, 235, 242);
+pub const CYAN_200: Rgb = (128, 222, 234);
+pub const CYAN_300: Rgb = (77, 208, 225);
+pub const CYAN_400: Rgb = (38, 198, 218);
+pub const CYAN_500: Rgb = (0, 188, 212);
+pub const CYAN_600: Rgb = (0, 172, 193);
+pub const CYAN_700: Rgb = (0, 151, 167);
+pub const CYAN_800: Rgb = (0, 131, 143);
+pub const CYAN_900: Rgb = (0, 96, 100);
+pub const CYAN_A100: Rgb = (132, 255, 255);
+pub const CYAN_A200: Rgb = (24, 255, 255);
+pub const CYAN_A400: Rgb = (0, 229, 255);
+pub const CYAN_A700: Rgb = (0, 184, 212);
+
+pub const TEAL_50: Rgb = (224, 242, 241);
+pub const TEAL_100: Rgb = (178, 223, 219);
+pub const TEAL_200: Rgb = (128, 203, 196);
+pub const TEAL_300: Rgb = (77, 182, 172);
+pub const TEAL_400: Rgb = (38, 166, 154);
+pub const TEAL_500: Rgb = (0, 150, 136);
+pub const TEAL_600: Rgb = (0, 137, 123);
+pub const TEAL_700: Rgb = (0, 121, 107);
+pub const TEAL_800: Rgb = (0, 105, 92);
+pub const TEAL_900: Rgb = (0, 77, 64);
+pub const TEAL_A100: Rgb = (167, 255, 235);
+pub const TEAL_A200: Rgb = (100, 255, 218);
+pub const TEAL_A400: Rgb = (29, 233, 182);
+pub const TEAL_A700: Rgb = (0, 191, 165);
+
+pub const GREEN_50: Rgb = (232, 245, 233);
+pub const GREEN_100: Rgb = (200, 230, 201);
+pub const GREEN_200: Rgb = (165, 214, 167);
+pub const GREEN_300: Rgb = (129, 199, 132);
+pub const GREEN_400: Rgb = (102, 187, 106);
+pub const GREEN_500: Rgb = (76, 175, 80);
+pub const GREEN_600: Rgb = (67, 160, 71);
+pub const GREEN_700: Rgb = (56, 142, 60);
+pub const GREEN_800: Rgb = (46, 125, 50);
+pub const GREEN_900: Rgb = (27, 94, 32);
+pub const GREEN_A100: Rgb = (185, 246, 202);
+pub const GREEN_A200: Rgb = (105, 240, 174);
+pub const GREEN_A400: Rgb = (0, 230, 118);
+pub const GREEN_A700: Rgb = (0, 200, 83);
+
+pub const LIGHT_GREEN_50: Rgb = (241, 248, 233);
+pub const LIGHT_GREEN_100: Rgb = (220, 237, 200);
+pub const LIGHT_GREEN_200: Rgb = (197, 225, 165);
+pub const LIGHT_GREEN_300: Rgb = (174, 213, 129);
+pub const LIGHT_GREEN_400: Rgb = (156, 204, 101);
+pub const LIGHT_GREEN_500: Rgb = (139, 195, 74);
+pub const LIGHT_GREEN_600: Rgb = (124, 179, 66);
+pub const LIGHT_GREEN_700: Rgb = (104, 159, 56);
+pub const LIGHT_GREEN_800: Rgb = (85, 139, 47);
+pub const LIGHT_GREEN_900: Rgb = (51, 105, 30);
+pub const LIGHT_GREEN_A100: Rgb = (204, 255, 144);
+pub const LIGHT_GREEN_A200: Rgb = (178, 255, 89);
+pub const LIGHT_GREEN_A400: Rgb = (118, 255, 3);
+pub const LIGHT_GREEN_A700: Rgb = (100, 221, 23);
+
+pub const LIME_50: Rgb = (249, 251, 231);
+pub const LIME_100: Rgb = (240, 244, 195);
+pub const LIME_200: Rgb = (230, 238, 156);
+pub const LIME_300: Rgb = (220, 231, 117);
+pub const LIME_400: Rgb = (212, 225, 87);
+pub const LIME_500: Rgb = (205, 220, 57);
+pub const LIME_600: Rgb = (192, 202, 51);
+pub const LIME_700: Rgb = (175, 180, 43);
+pub const LIME_800: Rgb = (158, 157, 36);
+pub const LIME_900: Rgb = (130, 119, 23);
+pub const LIME_A100: Rgb = (244, 255, 129);
+pub const LIME_A200: Rgb = (238, 255, 65);
+pub const LIME_A400: Rgb = (198, 255, 0);
+pub const LIME_A700: Rgb = (174, 234, 0);
+
+pub const YELLOW_50: Rgb = (255, 253, 231);
+pub const YELLOW_100: Rgb = (255, 249, 196);
+pub const YELLOW_200: Rgb = (255, 245, 157);
+pub const YELLOW_300: Rgb = (255, 241, 118);
+pub const YELLOW_400: Rgb = (255, 238, 88);
+pub const YELLOW_500: Rgb = (255, 235, 59);
+pub const YELLOW_600: Rgb = (253, 216, 53);
+pub const YELLOW_700: Rgb = (251, 192, 45);
+pub const YELLOW_800: Rgb = (249, 168, 37);
+pub const YELLOW_900: Rgb = (245, 127, 23);
+pub const YELLOW_A100: Rgb = (255, 255, 141);
+pub const YELLOW_A200: Rgb = (255, 255, 0);
+pub const YELLOW_A400: Rgb = (255, 234, 0);
+pub const YELLOW_A700: Rgb = (255, 214, 0);
+
+pub const AMBER_50: Rgb = (255, 248, 225);
+pub const AMBER_100: Rgb = (255, 236, 179);
+pub const AMBER_200: Rgb = (255, 224, 130);
+pub const AMBER_300: Rgb = (255, 213, 79);
+pub const AMBER_400: Rgb = (255, 202, 40);
+pub const AMBER_500: Rgb = (255, 193, 7);
+pub const AMBER_600: Rgb = (255, 179, 0);
+pub const AMBER_700: Rgb = (255, 160, 0);
+pub const AMBER_800: Rgb = (255, 143, 0);
+pub const AMBER_900: Rgb = (255, 111, 0);
+pub const AMBER_A100: Rgb = (255, 229, 127);
+pub const AMBER_A200: Rgb = (255, 215, 64);
+pub const AMBER_A400: Rgb = (255, 196, 0);
+pub const AMBER_A700: Rgb = (255, 171, 0);
+
+pub const ORANGE_50: Rgb = (255, 243, 224);
+pub const ORANGE_100: Rgb = (255, 224, 178);
+pub const ORANGE_200: Rgb = (255, 204, 128);
+pub const ORANGE_300: Rgb = (255, 183, 77);
+pub const ORANGE_400: Rgb = (255, 167, 38);
+pub const ORANGE_500: Rgb = (255, 152, 0);
+pub const ORANGE_600: Rgb = (251, 140, 0);
+pub const ORANGE_700: Rgb = (245, 124, 0);
+pub const ORANGE_800: Rgb = (239, 108, 0);
+pub const ORANGE_900: Rgb = (230, 81, 0);
+pub const ORANGE_A100: Rgb = (255, 209, 128);
+pub const ORANGE_A200: Rgb = (255, 171, 64);
+pub const ORANGE_A400: Rgb = (255, 145, 0);
+pub const ORANGE_A700: Rgb = (255, 109, 0);
+
+pub const DEEP_ORANGE_50: Rgb = (251, 233, 231);
+pub const DEEP_ORANGE_100: Rgb = (255, 204, 188);
+pub const DEEP_ORANGE_200: Rgb = (255, 171, 145);
+pub const DEEP_ORANGE_300: Rgb = (255, 138, 101);
+pub const DEEP_ORANGE_400: Rgb = (255, 112, 67);
+pub const DEEP_ORANGE_500: Rgb = (255, 87, 34);
+pub const DEEP_ORANGE_600: Rgb = (244, 81, 30);
+pub const DEEP_ORANGE_700: Rgb = (230, 74, 25);
+pub const DEEP_ORANGE_800: Rgb = (216, 67, 21);
+pub const DEEP_ORANGE_900: Rgb = (191, 54, 12);
+pub const DEEP_ORANGE_A100: Rgb = (255, 158, 128);
+pub const DEEP_ORANGE_A200: Rgb = (255, 110, 64);
+pub const DEEP_ORANGE_A400: Rgb = (255, 61, 0);
+pub const DEEP_ORANGE_A700: Rgb = (221, 44, 0);
+
+pub const BROWN_50: Rgb = (239, 235, 233);
+pub const BROWN_100: Rgb = (215, 204, 200);
+pub const BROWN_200: Rgb = (188, 170, 164);
+pub const BROWN_300: Rgb = (161, 136, 127);
+pub const BROWN_400: Rgb = (141, 110, 99);
+pub const BROWN_500: Rgb = (121, 85, 72);
+pub const BROWN_600: Rgb = (109, 76, 65);
+pub const BROWN_700: Rgb = (93, 64, 55);
+pub const BROWN_800: Rgb = (78, 52, 46);
+pub const BROWN_900: Rgb = (62, 39, 35);
+
+pub const GRAY_50: Rgb = (250, 250, 250);
+pub const GRAY_100: Rgb = (245, 245, 245);
+pub const GRAY_200: Rgb = (238, 238, 238);
+pub const GRAY_300: Rgb = (224, 224, 224);
+pub const GRAY_400: Rgb = (189, 189, 189);
+pub const GRAY_500: Rgb = (158, 158, 158);
+pub const GRAY_600: Rgb = (117, 117, 117);
+pub const GRAY_700: Rgb = (97, 97, 97);
+pub const GRAY_800: Rgb = (66, 66, 66);
+pub const GRAY_900: Rgb = (33, 33, 33);
+
+pub const BLUE_GRAY_50: Rgb = (236, 239, 241);
+pub const BLUE_GRAY_100: Rgb = (207, 216, 220);
+pub const BLUE_GRAY_200: Rgb = (176, 190, 197);
+pub const BLUE_GRAY_300: Rgb = (144, 164, 174);
+pub const BLUE_GRAY_400: Rgb = (120, 144, 156);
+pub const BLUE_GRAY_500: Rgb = (96, 125, 139);
+pub const BLUE_GRAY_600: Rgb = (84, 110, 122);
+pub const BLUE_GRAY_700: Rgb = (69, 90, 100);
+pub const BLUE_GRAY_800: Rgb = (55, 71, 79);
+pub const BLUE_GRAY_900: Rgb = (38, 50, 56);
+
+/// The swatches as FLTK `Color`s, named as their rgb triples
+pub mod color {
+    use fltk::enums::Color;
+
+    pub const RED_50: Color = Color::from_rgb(255, 235, 238);
+    pub const RED_100: Color = Color::from_rgb(255, 205, 210);
+    pub const RED_200: Color = Color::from_rgb(239, 154, 154);
+    pub const RED_300: Color = Color::from_rgb(229, 115, 115);
+    pub const RED_400: Color = Color::from_rgb(239, 83, 80);
+    pub const RED_500: Color = Color::from_rgb(244, 67, 54);
+    pub const RED_600: Color = Color::from_rgb(229, 57, 53);
+    pub const RED_700: Color = Color::from_rgb(211, 47, 47);
+    pub const RED_800: Color = Color::from_rgb(198, 40, 40);
+    pub const RED_900: Color = Color::from_rgb(183, 28, 28);
+    pub const RED_A100: Color = Color::from_rgb(255, 138, 128);
+    pub const RED_A200: Color = Color::from_rgb(255, 82, 82);
+    pub const RED_A400: Color = Color::from_rgb(255, 23, 68);
+    pub const RED_A700: Color = Color::from_rgb(213, 0, 0);
+
+    pub const PINK_50: Color = Color::from_rgb(252, 228, 236);
+    pub const PINK_100: Color = Color::from_rgb(248, 187, 208);
+    pub const PINK_200: Color = Color::from_rgb(244, 143, 177);
+    pub const PINK_300: Color = Color::from_rgb(240, 98, 146);
+    pub const PINK_400: Color = Color::from_rgb(236, 64, 122);
+    pub const PINK_500: Color = Color::from_rgb(233, 30, 99);
+    pub const PINK_600: Color = Color::from_rgb(216, 27, 96);
+    pub const PINK_700: Color = Color::from_rgb(194, 24, 91);
+    pub const PINK_800: Color = Color::from_rgb(173, 20, 87);
+    pub const PINK_900: Color = Color::from_rgb(136, 14, 79);
+    pub const PINK_A100: Color = Color::from_rgb(255, 128, 171);
+    pub const PINK_A200: Color = Color::from_rgb(255, 64, 129);
+    pub const PINK_A400: Color = Color::from_rgb(245, 0, 87);
+    pub const PINK_A700: Color = Color::from_rgb(197, 17, 98);
+
+    pub const PURPLE_50: Color = Color::from_rgb(243, 229, 245);
+    pub const PURPLE_100: Color = Color::from_rgb(225, 190, 231);
+    pub const PURPLE_200: Color = Color::from_rgb(206, 147, 216);
+    pub const PURPLE_300: Color = Color::from_rgb(186, 104, 200);
+    pub const PURPLE_400: Color = Color::from_rgb(171, 71, 188);
+    pub const PURPLE_500: Color = Color::from_rgb(156, 39, 176);
+    pub const PURPLE_600: Color = Color::from_rgb(142, 36, 170);
+    pub const PURPLE_700: Color = Color::from_rgb(123, 31, 162);
+    pub const PURPLE_800: Color = Color::from_rgb(106, 27, 154);
+    pub const PURPLE_900: Color = Color::from_rgb(74, 20, 140);
+    pub const PURPLE_A100: Color = Color::from_rgb(234, 128, 252);
+    pub const PURPLE_A200: Color = Color::from_rgb(224, 64, 251);
+    pub const PURPLE_A400: Color = Color::from_rgb(213, 0, 249);
+    pub const PURPLE_A700: Color = Color::from_rgb(170, 0, 255);
+
+    pub const DEEP_PURPLE_50: Color = Color::from_rgb(237, 231, 246);
+    pub const DEEP_PURPLE_100: Color = Color::from_rgb(209, 196, 233);
+    pub const DEEP_PURPLE_200: Color = Color::from_rgb(179, 157, 219);
+    pub const DEEP_PURPLE_300: Color = Color::from_rgb(149, 117, 205);
+    pub const DEEP_PURPLE_400: Color = Color::from_rgb(126, 87, 194);
+    pub const DEEP_PURPLE_500: Color = Color::from_rgb(103, 58, 183);
+    pub const DEEP_PURPLE_600: Color = Color::from_rgb(94, 53, 177);
+    pub const DEEP_PURPLE_700: Color = Color::from_rgb(81, 45, 168);
+    pub const DEEP_PURPLE_800: Color = Color::from_rgb(69, 39, 160);
+    pub const DEEP_PURPLE_900: Color = Color::from_rgb(49, 27, 146);
+    pub const DEEP_PURPLE_A100: Color = Color::from_rgb(179, 136, 255);
+    pub const DEEP_PURPLE_A200: Color = Color::from_rgb(124, 77, 255);
+    pub const DEEP_PURPLE_A400: Color = Color::from_rgb(101, 31, 255);
+    pub const DEEP_PURPLE_A700: Color = Color::from_rgb(98, 0, 234);
+
+    pub const INDIGO_50: Color = Color::from_rgb(232, 234, 246);
+    pub const INDIGO_100: Color = Color::from_rgb(197, 202, 233);
+    pub const INDIGO_200: Color = Color::from_rgb(159, 168, 218);
+    pub const INDIGO_300: Color = Color::from_rgb(121, 134, 203);
+    pub const INDIGO_400: Color = Color::from_rgb(92, 107, 192);
+    pub const INDIGO_500: Color = Color::from_rgb(63, 81, 181);
+    pub const INDIGO_600: Color = Color::from_rgb(57, 73, 171);
+    pub const INDIGO_700: Color = Color::from_rgb(48, 63, 159);
+    pub const INDIGO_800: Color = Color::from_rgb(40, 53, 147);
+    pub const INDIGO_900: Color = Color::from_rgb(26, 35, 126);
+    pub const INDIGO_A100: Color = Color::from_rgb(140, 158, 255);
+    pub const INDIGO_A200: Color = Color::from_rgb(83, 109, 254);
+    pub const INDIGO_A400: Color = Color::from_rgb(61, 90, 254);
+    pub const INDIGO_A700: Color = Color::from_rgb(48, 79, 254);
+
+    pub const BLUE_50: Color = Color::from_rgb(227, 242, 253);
+    pub const BLUE_100: Color = Color::from_rgb(187, 222, 251);
+    pub const BLUE_200: Color = Color::from_rgb(144, 202, 249);
+    pub const BLUE_300: Color = Color::from_rgb(100, 181, 246);
+    pub const BLUE_400: Color = Color::from_rgb(66, 165, 245);
+    pub const BLUE_500: Color = Color::from_rgb(33, 150, 243);
+    pub const BLUE_600: Color = Color::from_rgb(30, 136, 229);
+    pub const BLUE_700: Color = Color::from_rgb(25, 118, 210);
+    pub const BLUE_800: Color = Color::from_rgb(21, 101, 192);
+    pub const BLUE_900: Color = Color::from_rgb(13, 71, 161);
+    pub const BLUE_A100: Color = Color::from_rgb(130, 177, 255);
+    pub const BLUE_A200: Color = Color::from_rgb(68, 138, 255);
+    pub const BLUE_A400: Color = Color::from_rgb(41, 121, 255);
+    pub const BLUE_A700: Color = Color::from_rgb(41, 98, 255);
+
+    pub const LIGHT_BLUE_50: Color = Color::from_rgb(225, 245, 254);
+    pub const LIGHT_BLUE_100: Color = Color::from_rgb(179, 229, 252);
+    pub const LIGHT_BLUE_200: Color = Color::from_rgb(129, 212, 250);
+    pub const LIGHT_BLUE_300: Color = Color::from_rgb(79, 195, 247);
+    pub const LIGHT_BLUE_400: Color = Color::from_rgb(41, 182, 246);
+    pub const LIGHT_BLUE_500: Color = Color::from_rgb(3, 169, 244);
+    pub const LIGHT_BLUE_600: Color = Color::from_rgb(3, 155, 229);
+    pub const LIGHT_BLUE_700: Color = Color::from_rgb(2, 136, 209);
+    pub const LIGHT_BLUE_800: Color = Color::from_rgb(2, 119, 189);
+    pub const LIGHT_BLUE_900: Color = Color::from_rgb(1, 87, 155);
+    pub const LIGHT_BLUE_A100: Color = Color::from_rgb(128, 216, 255);
+    pub const LIGHT_BLUE_A200: Color = Color::from_rgb(64, 196, 255);
+    pub const LIGHT_BLUE_A400: Color = Color::from_rgb(0, 176, 255);
+    pub const LIGHT_BLUE_A700: Color = Color::from_rgb(0, 145, 234);
+
+    pub const CYAN_50: Color = Color::from_rgb(224, 247, 250);
+    pub const CYAN_100: Color = Color::from_rgb(178, 235, 242);
+    pub const CYAN_200: Color = Color::from_rgb(128, 222, 234);
+    pub const CYAN_300: Color = Color::from_rgb(77, 208, 225);
+    pub const CYAN_400: Color = Color::from_rgb(38, 198, 218);
+    pub const CYAN_500: Color = Color::from_rgb(0, 188, 212);
+    pub const CYAN_600: Color = Color::from_rgb(0, 172, 193);
+    pub const CYAN_700: Color = Color::from_rgb(0, 151, 167);
+    pub const CYAN_800: Color = Color::from_rgb(0, 131, 143);
+    pub const CYAN_900: Color = Color::from_rgb(0, 96, 100);
+    pub const CYAN_A100: Color = Color::from_rgb(132, 255, 255);
+    pub const CYAN_A200: Color = Color::from_rgb(24, 255, 255);
+    pub const CYAN_A400: Color = Color::from_rgb(0, 229, 255);
+    pub const CYAN_A700: Color = Color::from_rgb(0, 184, 212);
+
+    pub const TEAL_50: Color = Color::from_rgb(224, 242, 241);
+    pub const TEAL_100: Color = Color::from_rgb(178, 223, 219);
+    pub const TEAL_200: Color = Color::from_rgb(128, 203, 196);
+    pub const TEAL_300: Color = Color::from_rgb(77, 182, 172);
+    pub const TEAL_400: Color = Color::from_rgb(38, 166, 154);
+    pub const TEAL_500: Color = Color::from_rgb(0, 150, 136);
+    pub const TEAL_600: Color = Color::from_rgb(0, 137, 123);
+    pub const TEAL_700: Color = Color::from_rgb(0, 121, 107);
+    pub const TEAL_800: Color = Color::from_rgb(0, 105, 92);
+    pub const TEAL_900: Color = Color::from_rgb(0, 77, 64);
+    pub const TEAL_A100: Color = Color::from_rgb(167, 255, 235);
+    pub const TEAL_A200: Color = Color::from_rgb(100, 255, 218);
+    pub const TEAL_A400: Color = Color::from_rgb(29, 233, 182);
+    pub const TEAL_A700: Color = Color::from_rgb(0, 191, 165);
+
+    pub const GREEN_50: Color = Color::from_rgb(232, 245, 233);
+    pub const GREEN_100: Color = Color::from_rgb(200, 230, 201);
+    pub const GREEN_200: Color = Color::from_rgb(165, 214, 167);
+    pub const GREEN_300: Color = Color::from_rgb(129, 199, 132);
+    pub const GREEN_400: Color = Color::from_rgb(102, 187, 106);
+    pub const GREEN_500: Color = Color::from_rgb(76, 175, 80);
+    pub const GREEN_600: Color = Color::from_rgb(67, 160, 71);
+    pub const GREEN_700: Color = Color::from_rgb(56, 142, 60);
+    pub const GREEN_800: Color = Color::from_rgb(46, 125, 50);
+    pub const GREEN_900: Color = Color::from_rgb(27, 94, 32);
+    pub const GREEN_A100: Color = Color::from_rgb(185, 246, 202);
+    pub const GREEN_A200: Color = Color::from_rgb(105, 240, 174);
+    pub const GREEN_A400: Color = Color::from_rgb(0, 230, 118);
+    pub const GREEN_A700: Color = Color::from_rgb(0, 200, 83);
+
+    pub const LIGHT_GREEN_50: Color = Color::from_rgb(241, 248, 233);
+    pub const LIGHT_GREEN_100: Color = Color::from_rgb(220, 237, 200);
+    pub const LIGHT_GREEN_200: Color = Color::from_rgb(197, 225, 165);
+    pub const LIGHT_GREEN_300: Color = Color::from_rgb(174, 213, 129);
+    pub const LIGHT_GREEN_400: Color = Color::from_rgb(156, 204, 101);
+    pub const LIGHT_GREEN_500: Color = Color::from_rgb(139, 195, 74);
+    pub const LIGHT_GREEN_600: Color = Color::from_rgb(124, 179, 66);
+    pub const LIGHT_GREEN_700: Color = Color::from_rgb(104, 159, 56);
+    pub const LIGHT_GREEN_800: Color = Color::from_rgb(85, 139, 47);
+    pub const LIGHT_GREEN_900: Color = Color::from_rgb(51, 105, 30);
+    pub const LIGHT_GREEN_A100: Color = Color::from_rgb(204, 255, 144);
+    pub const LIGHT_GREEN_A200: Color = Color::from_rgb(178, 255, 89);
+    pub const LIGHT_GREEN_A400: Color = Color::from_rgb(118, 255, 3);
+    pub const LIGHT_GREEN_A700: Color = Color::from_rgb(100, 221, 23);
+
+    pub const LIME_50: Color = Color::from_rgb(249, 251, 231);
+    pub const LIME_100: Color = Color::from_rgb(240, 244, 195);
+    pub const LIME_200: Color = Color::from_rgb(230, 238, 156);
+    pub const LIME_300: Color = Color::from_rgb(220, 231, 117);
+    pub const LIME_400: Color = Color::from_rgb(212, 225, 87);
+    pub const LIME_500: Color = Color::from_rgb(205, 220, 57);
+    pub const LIME_600: Color = Color::from_rgb(192, 202, 51);
+    pub const LIME_700: Color = Color::from_rgb(175, 180, 43);
+    pub const LIME_800: Color = Color::from_rgb(158, 157, 36);
+    pub const LIME_900: Color = Color::from_rgb(130, 119, 23);
+    pub const LIME_A100: Color = Color::from_rgb(244, 255, 129);
+    pub const LIME_A200: Color = Color::from_rgb(238, 255, 65);
+    pub const LIME_A400: Color = Color::from_rgb(198, 255, 0);
+    pub const LIME_A700: Color = Color::from_rgb(174, 234, 0);
+
+    pub const YELLOW_50: Color = Color::from_rgb(255, 253, 231);
+    pub const YELLOW_100: Color = Color::from_rgb(255, 249, 196);
+    pub const YELLOW_200: Color = Color::from_rgb(255, 245, 157);
+    pub const YELLOW_300: Color = Color::from_rgb(255, 241, 118);
+    pub const YELLOW_400: Color = Color::from_rgb(255, 238, 88);
+    pub const YELLOW_500: Color = Color::from_rgb(255, 235, 59);
+    pub const YELLOW_600: Color = Color::from_rgb(253, 216, 53);
+    pub const YELLOW_700: Color = Color::from_rgb(251, 192, 45);
+    pub const YELLOW_800: Color = Color::from_rgb(249, 168, 37);
+    pub const YELLOW_900: Color = Color::from_rgb(245, 127, 23);
+    pub const YELLOW_A100: Color = Color::from_rgb(255, 255, 141);
+    pub const YELLOW_A200: Color = Color::from_rgb(255, 255, 0);
+    pub const YELLOW_A400: Color = Color::from_rgb(255, 234, 0);
+    pub const YELLOW_A700: Color = Color::from_rgb(255, 214, 0);
+
+    pub const AMBER_50: Color = Color::from_rgb(255, 248, 225);
+    pub const AMBER_100: Color = Color::from_rgb(255, 236, 179);
+    pub const AMBER_200: Color = Color::from_rgb(255, 224, 130);
+    pub const AMBER_300: Color = Color::from_rgb(255, 213, 79);
+    pub const AMBER_400: Color = Color::from_rgb(255, 202, 40);
+    pub const AMBER_500: Color = Color::from_rgb(255, 193, 7);
+    pub const AMBER_600: Color = Color::from_rgb(255, 179, 0);
+    pub const AMBER_700: Color = Color::from_rgb(255, 160, 0);
+    pub const AMBER_800: Color = Color::from_rgb(255, 143, 0);
+    pub const AMBER_900: Color = Color::from_rgb(255, 111, 0);
+    pub const AMBER_A100: Color = Color::from_rgb(255, 229, 127);
+    pub const AMBER_A200: Color = Color::from_rgb(255, 215, 64);
+    pub const AMBER_A400: Color = Color::from_rgb(255, 196, 0);
+    pub const AMBER_A700: Color = Color::from_rgb(255, 171, 0);
+
+    pub const ORANGE_50: Color = Color::from_rgb(255, 243, 224);
+    pub const ORANGE_100: Color = Color::from_rgb(255, 224, 178);
+    pub const ORANGE_200: Color = Color::from_rgb(255, 204, 128);
+    pub const ORANGE_300: Color = Color::from_rgb(255, 183, 77);
+    pub const ORANGE_400: Color = Color::from_rgb(255, 167, 38);
+    pub const ORANGE_500: Color = Color::from_rgb(255, 152, 0);
+    pub const ORANGE_600: Color = Color::from_rgb(251, 140, 0);
+    pub const ORANGE_700: Color = Color::from_rgb(245, 124, 0);
+    pub const ORANGE_800: Color = Color::from_rgb(239, 108, 0);
+    pub const ORANGE_900: Color = Color::from_rgb(230, 81, 0);
+    pub const ORANGE_A100: Color = Color::from_rgb(255, 209, 128);
+    pub const ORANGE_A200: Color = Color::from_rgb(255, 171, 64);
+    pub const ORANGE_A400: Color = Color::from_rgb(255, 145, 0);
+    pub const ORANGE_A700: Color = Color::from_rgb(255, 109, 0);
+
+    pub const DEEP_ORANGE_50: Color = Color::from_rgb(251, 233, 231);
+    pub const DEEP_ORANGE_100: Color = Color::from_rgb(255, 204, 188);
+    pub const DEEP_ORANGE_200: Color = Color::from_rgb(255, 171, 145);
+    pub const DEEP_ORANGE_300: Color = Color::from_rgb(255, 138, 101);
+    pub const DEEP_ORANGE_400: Color = Color::from_rgb(255, 112, 67);
+    pub const DEEP_ORANGE_500: Color = Color::from_rgb(255, 87, 34);
+    pub const DEEP_ORANGE_600: Color = Color::from_rgb(244, 81, 30);
+    pub const DEEP_ORANGE_700: Color = Color::from_rgb(230, 74, 25);
+    pub const DEEP_ORANGE_800: Color = Color::from_rgb(216, 67, 21);
+    pub const DEEP_ORANGE_900: Color = Color::from_rgb(191, 54, 12);
+    pub const DEEP_ORANGE_A100: Color = Color::from_rgb(255, 158, 128);
+    pub const DEEP_ORANGE_A200: Color = Color::from_rgb(255, 110, 64);
+    pub const DEEP_ORANGE_A400: Color = Color::from_rgb(255, 61, 0);
+    pub const DEEP_ORANGE_A700: Color = Color::from_rgb(221, 44, 0);
+
+    pub const BROWN_50: Color = Color::from_rgb(239, 235, 233);
+    pub const BROWN_100: Color = Color::from_rgb(215, 204, 200);
+    pub const BROWN_200: Color = Color::from_rgb(188, 170, 164);
+    pub const BROWN_300: Color = Color::from_rgb(161, 136, 127);
+    pub const BROWN_400: Color = Color::from_rgb(141, 110, 99);
+    pub const BROWN_500: Color = Color::from_rgb(121, 85, 72);
+    pub const BROWN_600: Color = Color::from_rgb(109, 76, 65);
+    pub const BROWN_700: Color = Color::from_rgb(93, 64, 55);
+    pub const BROWN_800: Color = Color::from_rgb(78, 52, 46);
+    pub const BROWN_900: Color = Color::from_rgb(62, 39, 35);
+
+    pub const GRAY_50: Color = Color::from_rgb(250, 250, 250);
+    pub const GRAY_100: Color = Color::from_rgb(245, 245, 245);
+    pub const GRAY_200: Color = Color::from_rgb(238, 238, 238);
+    pub const GRAY_300: Color = Color::from_rgb(224, 224, 224);
+    pub const GRAY_400: Color = Color::from_rgb(189, 189, 189);
+    pub const GRAY_500: Color = Color::from_rgb(158, 158, 158);
+    pub const GRAY_600: Color = Color::from_rgb(117, 117, 117);
+    pub const GRAY_700: Color = Color::from_rgb(97, 97, 97);
+    pub const GRAY_800: Color = Color::from_rgb(66, 66, 66);
+    pub const GRAY_900: Color = Color::from_rgb(33, 33, 33);
+
+    pub const BLUE_GRAY_50: Color = Color::from_rgb(236, 239, 241);
+    pub const BLUE_GRAY_100: Color = Color::from_rgb(207, 216, 220);
+    pub const BLUE_GRAY_200: Color = Color::from_rgb(176, 190, 197);
+    pub const BLUE_GRAY_300: Color = Color::from_rgb(144, 164, 174);
+    pub const BLUE_GRAY_400: Color = Color::from_rgb(120, 144, 156);
+    pub const BLUE_GRAY_500: Color = Color::from_rgb(96, 125, 139);
+    pub const BLUE_GRAY_600: Color = Color::from_rgb(84, 110, 122);
+    pub const BLUE_GRAY_700: Color = Color::from_rgb(69, 90, 100);
+    pub const BLUE_GRAY_800: Color = Color::from_rgb(55, 71, 79);
+    pub const BLUE_GRAY_900: Color = Color::from_rgb(38, 50, 56);
+}
+
+/// The swatches by hue and shade, in Material's order
+const PALETTE: &[(&str, &str, Rgb)] = &[
+    ("red", "50", RED_50),
+    ("red", "100", RED_100),
+    ("red", "200", RED_200),
+    ("red", "300", RED_300),
+    ("red", "400", RED_400),
+    ("red", "500", RED_500),
+    ("red", "600", RED_600),
+    ("red", "700", RED_700),
+    ("red", "800", RED_800),
+    ("red", "900", RED_900),
+    ("red", "A100", RED_A100),
+    ("red", "A200", RED_A200),
+    ("red", "A400", RED_A400),
+    ("red", "A700", RED_A700),
+    ("pink", "50", PINK_50),
+    ("pink", "100", PINK_100),
+    ("pink", "200", PINK_200),
+    ("pink", "300", PINK_300),
+    ("pink", "400", PINK_400),
+    ("pink", "500", PINK_500),
+    ("pink", "600", PINK_600),
+    ("pink", "700", PINK_700),
+    ("pink", "800", PINK_800),
+    ("pink", "900", PINK_900),
+    ("pink", "A100", PINK_A100),
+    ("pink", "A200", PINK_A200),
+    ("pink", "A400", PINK_A400),
+    ("pink", "A700", PINK_A700),
+    ("purple", "50", PURPLE_50),
+    ("purple", "100", PURPLE_100),
+    ("purple", "200", PURPLE_200),
+    ("purple", "300", PURPLE_300),
+    ("purple", "400", PURPLE_400),
+    ("purple", "500", PURPLE_500),
+    ("purple", "600", PURPLE_600),
+    ("purple", "700", PURPLE_700),
+    ("purple", "800", PURPLE_800),
+    ("purple", "900", PURPLE_900),
+    ("purple", "A100", PURPLE_A100),
+    ("purple", "A200", PURPLE_A200),
+    ("purple", "A400", PURPLE_A400),
+    ("purple", "A700", PURPLE_A700),
+    ("deep purple", "50", DEEP_PURPLE_50),
+    ("deep purple", "100", DEEP_PURPLE_100),
+    ("deep purple", "200", DEEP_PURPLE_200),
+    ("deep purple", "300", DEEP_PURPLE_300),
+    ("deep purple", "400", DEEP_PURPLE_400),
+    ("deep purple", "500", DEEP_PURPLE_500),
+    ("deep purple", "600", DEEP_PURPLE_600),
+    ("deep purple", "700", DEEP_PURPLE_700),
+    ("deep purple", "800", DEEP_PURPLE_800),
+    ("deep purple", "900", DEEP_PURPLE_900),
+    ("deep purple", "A100", DEEP_PURPLE_A100),
+    ("deep purple", "A200", DEEP_PURPLE_A200),
+    ("deep purple", "A400", DEEP_PURPLE_A400),
+    ("deep purple", "A700", DEEP_PURPLE_A700),
+    ("indigo", "50", INDIGO_50),
+    ("indigo", "100", INDIGO_100),
+    ("indigo", "200", INDIGO_200),
+    ("indigo", "300", INDIGO_300),
+    ("indigo", "400", INDIGO_400),
+    ("indigo", "500", INDIGO_500),
+    ("indigo", "600", INDIGO_600),
+    ("indigo", "700", INDIGO_700),
+    ("indigo", "800", INDIGO_800),
+    ("indigo", "900", INDIGO_900),
+    ("indigo", "A100", INDIGO_A100),
+    ("indigo", "A200", INDIGO_A200),
+    ("indigo", "A400", INDIGO_A400),
+    ("indigo", "A700", INDIGO_A700),
+    ("blue", "50", BLUE_50),
+    ("blue", "100", BLUE_100),
+    ("blue", "200", BLUE_200),
+    ("blue", "300", BLUE_300),
+    ("blue", "400", BLUE_400),
+    ("blue", "500", BLUE_500),
+    ("blue", "600", BLUE_600),
+    ("blue", "700", BLUE_700),
+    ("blue", "800", BLUE_800),
+    ("blue", "900", BLUE_900),
+    ("blue", "A100", BLUE_A100),
+    ("blue", "A200", BLUE_A200),
+    ("blue", "A400", BLUE_A400),
+    ("blue", "A700", BLUE_A700),
+    ("light blue", "50", LIGHT_BLUE_50),
+    ("light blue", "100", LIGHT_BLUE_100),
+    ("light blue", "200", LIGHT_BLUE_200),
+    ("light blue", "300", LIGHT_BLUE_300),
+    ("light blue", "400", LIGHT_BLUE_400),
+    ("light blue", "500", LIGHT_BLUE_500),
+    ("light blue", "600", LIGHT_BLUE_600),
+    ("light blue", "700", LIGHT_BLUE_700),
+    ("light blue", "800", LIGHT_BLUE_800),
+    ("light blue", "900", LIGHT_BLUE_900),
+    ("light blue", "A100", LIGHT_BLUE_A100),
+    ("light blue", "A200", LIGHT_BLUE_A200),
+    ("light blue", "A400", LIGHT_BLUE_A400),
+    ("light blue", "A700", LIGHT_BLUE_A700),
+    ("cyan", "50", CYAN_50),
+    ("cyan", "100", CYAN_100),
+    ("cyan", "200", CYAN_200),
+    ("cyan", "300", CYAN_300),
+    ("cyan", "400", CYAN_400),
+    ("cyan", "500", CYAN_500),
+    ("cyan", "600", CYAN_600),
+    ("cyan", "700", CYAN_700),
+    ("cyan", "800", CYAN_800),
+    ("cyan", "900", CYAN_900),
+    ("cyan", "A100", CYAN_A100),
+    ("cyan", "A200", CYAN_A200),
+    ("cyan", "A400", CYAN_A400),
+    ("cyan", "A700", CYAN_A700),
+    ("teal", "50", TEAL_50),
+    ("teal", "100", TEAL_100),
+    ("teal", "200", TEAL_200),
+    ("teal", "300", TEAL_300),
+    ("teal", "400", TEAL_400),
+    ("teal", "500", TEAL_500),
+    ("teal", "600", TEAL_600),
+    ("teal", "700", TEAL_700),
+    ("teal", "800", TEAL_800),
+    ("teal", "900", TEAL_900),
+    ("teal", "A100", TEAL_A100),
+    ("teal", "A200", TEAL_A200),
+    ("teal", "A400", TEAL_A400),
+    ("teal", "A700", TEAL_A700),
+    ("green", "50", GREEN_50),
+    ("green", "100", GREEN_100),
+    ("green", "200", GREEN_200),
+    ("green", "300", GREEN_300),
+    ("green", "400", GREEN_400),
+    ("green", "500", GREEN_500),
+    ("green", "600", GREEN_600),
+    ("green", "700", GREEN_700),
+    ("green", "800", GREEN_800),
+    ("green", "900", GREEN_900),
+    ("green", "A100", GREEN_A100),
+    ("green", "A200", GREEN_A200),
+    ("green", "A400", GREEN_A400),
+    ("green", "A700", GREEN_A700),
+    ("light green", "50", LIGHT_GREEN_50),
+    ("light green", "100", LIGHT_GREEN_100),
+    ("light green", "200", LIGHT_GREEN_200),
+    ("light green", "300", LIGHT_GREEN_300),
+    ("light green", "400", LIGHT_GREEN_400),
+    ("light green", "500", LIGHT_GREEN_500),
+    ("light green", "600", LIGHT_GREEN_600),
+    ("light green", "700", LIGHT_GREEN_700),
+    ("light green", "800", LIGHT_GREEN_800),
+    ("light green", "900", LIGHT_GREEN_900),
+    ("light green", "A100", LIGHT_GREEN_A100),
+    ("light green", "A200", LIGHT_GREEN_A200),
+    ("light green", "A400", LIGHT_GREEN_A400),
+    ("light green", "A700", LIGHT_GREEN_A700),
+    ("lime", "50", LIME_50),
+    ("lime", "100", LIME_100),
+    ("lime", "200", LIME_200),
+    ("lime", "300", LIME_300),
+    ("lime", "400", LIME_400),
+    ("lime", "500", LIME_500),
+    ("lime", "600", LIME_600),
+    ("lime", "700", LIME_700),
+    ("lime", "800", LIME_800),
+    ("lime", "900", LIME_900),
+    ("lime", "A100", LIME_A100),
+    ("lime", "A200", LIME_A200),
+    ("lime", "A400", LIME_A400),
+    ("lime", "A700", LIME_A700),
+    ("yellow", "50", YELLOW_50),
+    ("yellow", "100", YELLOW_100),
+    ("yellow", "200", YELLOW_200),
+    ("yellow", "300", YELLOW_300),
+    ("yellow", "400", YELLOW_400),
+    ("yellow", "500", YELLOW_500),
+    ("yellow", "600", YELLOW_600),
+    ("yellow", "700", YELLOW_700),
+    ("yellow", "800", YELLOW_800),
+    ("yellow", "900", YELLOW_900),
+    ("yellow", "A100", YELLOW_A100),
+    ("yellow", "A200", YELLOW_A200),
+    ("yellow", "A400", YELLOW_A400),
+    ("yellow", "A700", YELLOW_A700),
+    ("amber", "50", AMBER_50),
+    ("amber", "100", AMBER_100),
+    ("amber", "200", AMBER_200),
+    ("amber", "300", AMBER_300),
+    ("amber", "400", AMBER_400),
+    ("amber", "500", AMBER_500),
+    ("amber", "600", AMBER_600),
+    ("amber", "700", AMBER_700),
+    ("amber", "800", AMBER_800),
+    ("amber", "900", AMBER_900),
+    ("amber", "A100", AMBER_A100),
+    ("amber", "A200", AMBER_A200),
+    ("amber", "A400", AMBER_A400),
+    ("amber", "A700", AMBER_A700),
+    ("orange", "50", ORANGE_50),
+    ("orange", "100", ORANGE_100),
+    ("orange", "200", ORANGE_200),
+    ("orange", "300", ORANGE_300),
+    ("orange", "400", ORANGE_400),
+    ("orange", "500", ORANGE_500),
+    ("orange", "600", ORANGE_600),
+    ("orange", "700", ORANGE_700),
+    ("orange", "800", ORANGE_800),
+    ("orange", "900", ORANGE_900),
+    ("orange", "A100", ORANGE_A100),
+    ("orange", "A200", ORANGE_A200),
+    ("orange", "A400", ORANGE_A400),
+    ("orange", "A700", ORANGE_A700),
+    ("deep orange", "50", DEEP_ORANGE_50),
+    ("deep orange", "100", DEEP_ORANGE_100),
+    ("deep orange", "200", DEEP_ORANGE_200),
+    ("deep orange", "300", DEEP_ORANGE_300),
+    ("deep orange", "400", DEEP_ORANGE_400),
+    ("deep orange", "500", DEEP_ORANGE_500),
+    ("deep orange", "600", DEEP_ORANGE_600),
+    ("deep orange", "700", DEEP_ORANGE_700),
+    ("deep orange", "800", DEEP_ORANGE_800),
+    ("deep orange", "900", DEEP_ORANGE_900),
+    ("deep orange", "A100", DEEP_ORANGE_A100),
+    ("deep orange", "A200", DEEP_ORANGE_A200),
+    ("deep orange", "A400", DEEP_ORANGE_A400),
+    ("deep orange", "A700", DEEP_ORANGE_A700),
+    ("brown", "50", BROWN_50),
+    ("brown", "100", BROWN_100),
+    ("brown", "200", BROWN_200),
+    ("brown", "300", BROWN_300),
+    ("brown", "400", BROWN_400),
+    ("brown", "500", BROWN_500),
+    ("brown", "600", BROWN_600),
+    ("brown", "700", BROWN_700),
+    ("brown", "800", BROWN_800),
+    ("brown", "900", BROWN_900),
+    ("gray", "50", GRAY_50),
+    ("gray", "100", GRAY_100),
+    ("gray", "200", GRAY_200),
+    ("gray", "300", GRAY_300),
+    ("gray", "400", GRAY_400),
+    ("gray", "500", GRAY_500),
+    ("gray", "600", GRAY_600),
+    ("gray", "700", GRAY_700),
+    ("gray", "800", GRAY_800),
+    ("gray", "900", GRAY_900),
+    ("blue gray", "50", BLUE_GRAY_50),
+    ("blue gray", "100", BLUE_GRAY_100),
+    ("blue gray", "200", BLUE_GRAY_200),
+    ("blue gray", "300", BLUE_GRAY_300),
+    ("blue gray", "400", BLUE_GRAY_400),
+    ("blue gray", "500", BLUE_GRAY_500),
+    ("blue gray", "600", BLUE_GRAY_600),
+    ("blue gray", "700", BLUE_GRAY_700),
+    ("blue gray", "800", BLUE_GRAY_800),
+    ("blue gray", "900", BLUE_GRAY_900),
+];
+
+/// Lowercase without spaces, `_` and `-`, with `grey` spelled `gray`
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect::<String>()
+        .replace("grey", "gray")
+}
+
+/// Looks a swatch up by hue and shade, e.g. `get_rgb("deep purple", "A200")`, ignoring case,
+/// spaces and underscores and accepting `grey`. None for unknown hues and shades
+pub fn get_rgb(hue: &str, shade: &str) -> Option<(u8, u8, u8)> {
+    let (hue, shade) = (normalize(hue), shade.trim());
+    PALETTE
+        .iter()
+        .find(|(h, s, _)| s.eq_ignore_ascii_case(shade) && normalize(h) == hue)
+        .map(|(_, _, rgb)| *rgb)
+}
+
+/**
+Like `get_rgb`, as an FLTK `Color`:
+```rust
+use fltk_theme::colors::material;
+assert_eq!(material::get("blue_grey", "800"), Some(material::color::BLUE_GRAY_800));
+```
+*/
+pub fn get(hue: &str, shade: &str) -> Option<Color> {
+    get_rgb(hue, shade).map(|(r, g, b)| Color::from_rgb(r, g, b))
+}
+
+/// The names of the hues, e.g. `red` and `deep purple`, in Material's order
+pub fn names() -> impl Iterator<Item = &'static str> {
+    PALETTE
+        .iter()
+        .filter(|(_, shade, _)| *shade == SHADES[0])
+        .map(|(name, _, _)| *name)
+}
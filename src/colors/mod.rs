@@ -1,2 +1,274 @@
+use fltk::enums::Color;
+
 pub mod aqua;
+/// HSL and HSV conversions, and helpers changing the lightness, saturation or hue of a color
+/// without the hue shifts of `Color::lighter()` and `darker()`
+pub mod convert;
 pub mod html;
+/**
+The [Material Design](https://m2.material.io/design/color/the-color-system.html) palette, as rgb
+triples such as `INDIGO_500` which `cmap!` and `ColorThemeBuilder` take directly, and as `Color`s
+in `material::color`:
+```rust
+use fltk_theme::{cmap, colors::material, ColorMap};
+const BRAND: &[ColorMap] = &[cmap!(49, material::BLUE_GRAY_900), cmap!(15, material::TEAL_A700)];
+```
+*/
+pub mod material;
+/**
+The [Tailwind CSS](https://tailwindcss.com/docs/customizing-colors) palette, as rgb triples such
+as `SLATE_700` which `cmap!` and `ColorThemeBuilder` take directly:
+```rust
+use fltk_theme::{cmap, colors::tailwind, ColorMap};
+const BRAND: &[ColorMap] = &[cmap!(49, tailwind::SLATE_900), cmap!(15, tailwind::EMERALD_600)];
+```
+*/
+pub mod tailwind;
+
+/// The CSS named colors, lowercase, with the `grey` spellings CSS accepts besides `gray`
+const NAMED: &[(&str, Color)] = &[
+    ("aliceblue", html::AliceBlue),
+    ("antiquewhite", html::AntiqueWhite),
+    ("aqua", html::Aqua),
+    ("aquamarine", html::Aquamarine),
+    ("azure", html::Azure),
+    ("beige", html::Beige),
+    ("bisque", html::Bisque),
+    ("black", html::Black),
+    ("blanchedalmond", html::BlanchedAlmond),
+    ("blue", html::Blue),
+    ("blueviolet", html::BlueViolet),
+    ("brown", html::Brown),
+    ("burlywood", html::BurlyWood),
+    ("cadetblue", html::CadetBlue),
+    ("chartreuse", html::Chartreuse),
+    ("chocolate", html::Chocolate),
+    ("coral", html::Coral),
+    ("cornflowerblue", html::CornflowerBlue),
+    ("cornsilk", html::Cornsilk),
+    ("crimson", html::Crimson),
+    ("cyan", html::Cyan),
+    ("darkblue", html::DarkBlue),
+    ("darkcyan", html::DarkCyan),
+    ("darkgoldenrod", html::DarkGoldenrod),
+    ("darkgray", html::DarkGray),
+    ("darkgreen", html::DarkGreen),
+    ("darkgrey", html::DarkGray),
+    ("darkkhaki", html::DarkKhaki),
+    ("darkmagenta", html::DarkMagenta),
+    ("darkolivegreen", html::DarkOliveGreen),
+    ("darkorange", html::DarkOrange),
+    ("darkorchid", html::DarkOrchid),
+    ("darkred", html::DarkRed),
+    ("darksalmon", html::DarkSalmon),
+    ("darkseagreen", html::DarkSeaGreen),
+    ("darkslateblue", html::DarkSlateBlue),
+    ("darkslategray", html::DarkSlateGray),
+    ("darkslategrey", html::DarkSlateGray),
+    ("darkturquoise", html::DarkTurquoise),
+    ("darkviolet", html::DarkViolet),
+    ("deeppink", html::DeepPink),
+    ("deepskyblue", html::DeepSkyBlue),
+    ("dimgray", html::DimGray),
+    ("dimgrey", html::DimGray),
+    ("dodgerblue", html::DodgerBlue),
+    ("firebrick", html::FireBrick),
+    ("floralwhite", html::FloralWhite),
+    ("forestgreen", html::ForestGreen),
+    ("fuchsia", html::Fuchsia),
+    ("gainsboro", html::Gainsboro),
+    ("ghostwhite", html::GhostWhite),
+    ("gold", html::Gold),
+    ("goldenrod", html::Goldenrod),
+    ("gray", html::Gray),
+    ("green", html::Green),
+    ("greenyellow", html::GreenYellow),
+    ("grey", html::Gray),
+    ("honeydew", html::HoneyDew),
+    ("hotpink", html::HotPink),
+    ("indianred", html::IndianRed),
+    ("indigo", html::Indigo),
+    ("ivory", html::Ivory),
+    ("khaki", html::Khaki),
+    ("lavender", html::Lavender),
+    ("lavenderblush", html::LavenderBlush),
+    ("lawngreen", html::LawnGreen),
+    ("lemonchiffon", html::LemonChiffon),
+    ("lightblue", html::LightBlue),
+    ("lightcoral", html::LightCoral),
+    ("lightcyan", html::LightCyan),
+    ("lightgoldenrodyellow", html::LightGoldenrodYellow),
+    ("lightgray", html::LightGray),
+    ("lightgreen", html::LightGreen),
+    ("lightgrey", html::LightGray),
+    ("lightpink", html::LightPink),
+    ("lightsalmon", html::LightSalmon),
+    ("lightseagreen", html::LightSeaGreen),
+    ("lightskyblue", html::LightSkyBlue),
+    ("lightslategray", html::LightSlateGray),
+    ("lightslategrey", html::LightSlateGray),
+    ("lightsteelblue", html::LightSteelBlue),
+    ("lightyellow", html::LightYellow),
+    ("lime", html::Lime),
+    ("limegreen", html::LimeGreen),
+    ("linen", html::Linen),
+    ("magenta", html::Magenta),
+    ("maroon", html::Maroon),
+    ("mediumaquamarine", html::MediumAquamarine),
+    ("mediumblue", html::MediumBlue),
+    ("mediumorchid", html::MediumOrchid),
+    ("mediumpurple", html::MediumPurple),
+    ("mediumseagreen", html::MediumSeaGreen),
+    ("mediumslateblue", html::MediumSlateBlue),
+    ("mediumspringgreen", html::MediumSpringGreen),
+    ("mediumturquoise", html::MediumTurquoise),
+    ("mediumvioletred", html::MediumVioletRed),
+    ("midnightblue", html::MidnightBlue),
+    ("mintcream", html::MintCream),
+    ("mistyrose", html::MistyRose),
+    ("moccasin", html::Moccasin),
+    ("navajowhite", html::NavajoWhite),
+    ("navy", html::Navy),
+    ("oldlace", html::OldLace),
+    ("olive", html::Olive),
+    ("olivedrab", html::OliveDrab),
+    ("orange", html::Orange),
+    ("orangered", html::OrangeRed),
+    ("orchid", html::Orchid),
+    ("palegoldenrod", html::PaleGoldenrod),
+    ("palegreen", html::PaleGreen),
+    ("paleturquoise", html::PaleTurquoise),
+    ("palevioletred", html::PaleVioletRed),
+    ("papayawhip", html::PapayaWhip),
+    ("peachpuff", html::PeachPuff),
+    ("peru", html::Peru),
+    ("pink", html::Pink),
+    ("plum", html::Plum),
+    ("powderblue", html::PowderBlue),
+    ("purple", html::Purple),
+    ("rebeccapurple", html::RebeccaPurple),
+    ("red", html::Red),
+    ("rosybrown", html::RosyBrown),
+    ("royalblue", html::RoyalBlue),
+    ("saddlebrown", html::SaddleBrown),
+    ("salmon", html::Salmon),
+    ("sandybrown", html::SandyBrown),
+    ("seagreen", html::SeaGreen),
+    ("seashell", html::SeaShell),
+    ("sienna", html::Sienna),
+    ("silver", html::Silver),
+    ("skyblue", html::SkyBlue),
+    ("slateblue", html::SlateBlue),
+    ("slategray", html::SlateGray),
+    ("slategrey", html::SlateGray),
+    ("snow", html::Snow),
+    ("springgreen", html::SpringGreen),
+    ("steelblue", html::SteelBlue),
+    ("tan", html::Tan),
+    ("teal", html::Teal),
+    ("thistle", html::Thistle),
+    ("tomato", html::Tomato),
+    ("turquoise", html::Turquoise),
+    ("violet", html::Violet),
+    ("wheat", html::Wheat),
+    ("white", html::White),
+    ("whitesmoke", html::WhiteSmoke),
+    ("yellow", html::Yellow),
+    ("yellowgreen", html::YellowGreen),
+];
+
+/**
+Looks a color up by its CSS/X11 name, ignoring case, spaces, `_` and `-` (`rebeccapurple`,
+`RebeccaPurple` and `rebecca_purple` are the same), or parses it as `#rgb`, `#rrggbb` or `rrggbb`,
+so that a theme file can take either:
+```rust
+use fltk_theme::colors;
+assert_eq!(colors::by_name("Steel_Blue"), Some(colors::html::SteelBlue));
+assert_eq!(colors::by_name("#4682b4"), Some(colors::html::SteelBlue));
+assert_eq!(colors::by_name("not a color"), None);
+```
+*/
+pub fn by_name(name: &str) -> Option<Color> {
+    let name = name.trim();
+    if let Some((r, g, b)) = crate::hex_rgb(name) {
+        return Some(Color::from_rgb(r, g, b));
+    }
+    let key: String = name
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    NAMED
+        .binary_search_by(|(n, _)| n.cmp(&key.as_str()))
+        .ok()
+        .map(|i| NAMED[i].1)
+}
+
+/// The names `by_name` knows, lowercase and in alphabetical order
+pub fn names() -> impl Iterator<Item = &'static str> {
+    NAMED.iter().map(|(name, _)| *name)
+}
+
+/// An sRGB component to linear light, from 0 to 1
+pub(crate) fn to_linear(c: u8) -> f32 {
+    let c = c as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// The WCAG relative luminance of a color, from 0 (black) to 1 (white)
+pub fn relative_luminance((r, g, b): (u8, u8, u8)) -> f32 {
+    0.2126 * to_linear(r) + 0.7152 * to_linear(g) + 0.0722 * to_linear(b)
+}
+
+/**
+The WCAG contrast ratio of two colors, from 1 (the same luminance) to 21 (black on white), in
+either order:
+```rust
+use fltk_theme::colors::contrast_ratio;
+assert_eq!(format!("{:.2}", contrast_ratio((0, 0, 0), (255, 255, 255))), "21.00");
+assert_eq!(format!("{:.2}", contrast_ratio((0x77, 0x77, 0x77), (255, 255, 255))), "4.48");
+assert_eq!(format!("{:.2}", contrast_ratio((255, 255, 255), (0x76, 0x76, 0x76))), "4.54");
+assert_eq!(contrast_ratio((0x12, 0x34, 0x56), (0x12, 0x34, 0x56)), 1.0);
+```
+*/
+pub fn contrast_ratio(a: (u8, u8, u8), b: (u8, u8, u8)) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Whether white text contrasts more with c than black text
+pub(crate) fn is_dark(c: (u8, u8, u8)) -> bool {
+    // the relative luminance where both contrast equally
+    relative_luminance(c) < 0.179
+}
+
+/**
+Whether text in fg on bg meets WCAG level AA: a contrast of 4.5:1, or 3:1 for large text (18pt,
+or 14pt bold). E.g. to check a color picked in a settings dialog:
+```rust
+use fltk_theme::colors::meets_aa;
+assert!(!meets_aa((0x77, 0x77, 0x77), (255, 255, 255), false));
+assert!(meets_aa((0x76, 0x76, 0x76), (255, 255, 255), false));
+assert!(meets_aa((0x77, 0x77, 0x77), (255, 255, 255), true));
+```
+*/
+pub fn meets_aa(fg: (u8, u8, u8), bg: (u8, u8, u8), large_text: bool) -> bool {
+    contrast_ratio(fg, bg) >= if large_text { 3.0 } else { 4.5 }
+}
+
+/**
+Whether text in fg on bg meets WCAG level AAA: a contrast of 7:1, or 4.5:1 for large text:
+```rust
+use fltk_theme::colors::meets_aaa;
+assert!(meets_aaa((0x59, 0x59, 0x59), (255, 255, 255), false));
+assert!(!meets_aaa((0x76, 0x76, 0x76), (255, 255, 255), false));
+assert!(meets_aaa((0x76, 0x76, 0x76), (255, 255, 255), true));
+```
+*/
+pub fn meets_aaa(fg: (u8, u8, u8), bg: (u8, u8, u8), large_text: bool) -> bool {
+    contrast_ratio(fg, bg) >= if large_text { 4.5 } else { 7.0 }
+}
@@ -0,0 +1,544 @@
+use fltk::enums::Color;
+
+type Rgb = (u8, u8, u8);
+
+/// The shades of each color, from lightest to darkest
+pub const SHADES: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+pub const SLATE_50: Rgb = (248, 250, 252);
+pub const SLATE_100: Rgb = (241, 245, 249);
+pub const SLATE_200: Rgb = (226, 232, 240);
+pub const SLATE_300: Rgb = (203, 213, 225);
+pub const SLATE_400: Rgb = (148, 163, 184);
+pub const SLATE_500: Rgb = (100, 116, 139);
+pub const SLATE_600: Rgb = (71, 85, 105);
+pub const SLATE_700: Rgb = (51, 65, 85);
+pub const SLATE_800: Rgb = (30, 41, 59);
+pub const SLATE_900: Rgb = (15, 23, 42);
+pub const SLATE_950: Rgb = (2, 6, 23);
+
+pub const GRAY_50: Rgb = (249, 250, 251);
+pub const GRAY_100: Rgb = (243, 244, 246);
+pub const GRAY_200: Rgb = (229, 231, 235);
+pub const GRAY_300: Rgb = (209, 213, 219);
+pub const GRAY_400: Rgb = (156, 163, 175);
+pub const GRAY_500: Rgb = (107, 114, 128);
+pub const GRAY_600: Rgb = (75, 85, 99);
+pub const GRAY_700: Rgb = (55, 65, 81);
+pub const GRAY_800: Rgb = (31, 41, 55);
+pub const GRAY_900: Rgb = (17, 24, 39);
+pub const GRAY_950: Rgb = (3, 7, 18);
+
+pub const ZINC_50: Rgb = (250, 250, 250);
+pub const ZINC_100: Rgb = (244, 244, 245);
+pub const ZINC_200: Rgb = (228, 228, 231);
+pub const ZINC_300: Rgb = (212, 212, 216);
+pub const ZINC_400: Rgb = (161, 161, 170);
+pub const ZINC_500: Rgb = (113, 113, 122);
+pub const ZINC_600: Rgb = (82, 82, 91);
+pub const ZINC_700: Rgb = (63, 63, 70);
+pub const ZINC_800: Rgb = (39, 39, 42);
+pub const ZINC_900: Rgb = (24, 24, 27);
+pub const ZINC_950: Rgb = (9, 9, 11);
+
+pub const NEUTRAL_50: Rgb = (250, 250, 250);
+pub const NEUTRAL_100: Rgb = (245, 245, 245);
+pub const NEUTRAL_200: Rgb = (229, 229, 229);
+pub const NEUTRAL_300: Rgb = (212, 212, 212);
+pub const NEUTRAL_400: Rgb = (163, 163, 163);
+pub const NEUTRAL_500: Rgb = (115, 115, 115);
+pub const NEUTRAL_600: Rgb = (82, 82, 82);
+pub const NEUTRAL_700: Rgb = (64, 64, 64);
+pub const NEUTRAL_800: Rgb = (38, 38, 38);
+pub const NEUTRAL_900: Rgb = (23, 23, 23);
+pub const NEUTRAL_950: Rgb = (10, 10, 10);
+
+pub const STONE_50: Rgb = (250, 250, 249);
+pub const STONE_100: Rgb = (245, 245, 244);
+pub const STONE_200: Rgb = (231, 229, 228);
+pub const STONE_300: Rgb = (214, 211, 209);
+pub const STONE_400: Rgb = (168, 162, 158);
+pub const STONE_500: Rgb = (120, 113, 108);
+pub const STONE_600: Rgb = (87, 83, 78);
+pub const STONE_700: Rgb = (68, 64, 60);
+pub const STONE_800: Rgb = (41, 37, 36);
+pub const STONE_900: Rgb = (28, 25, 23);
+pub const STONE_950: Rgb = (12, 10, 9);
+
+pub const RED_50: Rgb = (254, 242, 242);
+pub const RED_100: Rgb = (254, 226, 226);
+pub const RED_200: Rgb = (254, 202, 202);
+pub const RED_300: Rgb = (252, 165, 165);
+pub const RED_400: Rgb = (248, 113, 113);
+pub const RED_500: Rgb = (239, 68, 68);
+pub const RED_600: Rgb = (220, 38, 38);
+pub const RED_700: Rgb = (185, 28, 28);
+pub const RED_800: Rgb = (153, 27, 27);
+pub const RED_900: Rgb = (127, 29, 29);
+pub const RED_950: Rgb = (69, 10, 10);
+
+pub const ORANGE_50: Rgb = (255, 247, 237);
+pub const ORANGE_100: Rgb = (255, 237, 213);
+pub const ORANGE_200: Rgb = (254, 215, 170);
+pub const ORANGE_300: Rgb = (253, 186, 116);
+pub const ORANGE_400: Rgb = (251, 146, 60);
+pub const ORANGE_500: Rgb = (249, 115, 22);
+pub const ORANGE_600: Rgb = (234, 88, 12);
+pub const ORANGE_700: Rgb = (194, 65, 12);
+pub const ORANGE_800: Rgb = (154, 52, 18);
+pub const ORANGE_900: Rgb = (124, 45, 18);
+pub const ORANGE_950: Rgb = (67, 20, 7);
+
+pub const AMBER_50: Rgb = (255, 251, 235);
+pub const AMBER_100: Rgb = (254, 243, 199);
+pub const AMBER_200: Rgb = (253, 230, 138);
+pub const AMBER_300: Rgb = (252, 211, 77);
+pub const AMBER_400: Rgb = (251, 191, 36);
+pub const AMBER_500: Rgb = (245, 158, 11);
+pub const AMBER_600: Rgb = (217, 119, 6);
+pub const AMBER_700: Rgb = (180, 83, 9);
+pub const AMBER_800: Rgb = (146, 64, 14);
+pub const AMBER_900: Rgb = (120, 53, 15);
+pub const AMBER_950: Rgb = (69, 26, 3);
+
+pub const YELLOW_50: Rgb = (254, 252, 232);
+pub const YELLOW_100: Rgb = (254, 249, 195);
+pub const YELLOW_200: Rgb = (254, 240, 138);
+pub const YELLOW_300: Rgb = (253, 224, 71);
+pub const YELLOW_400: Rgb = (250, 204, 21);
+pub const YELLOW_500: Rgb = (234, 179, 8);
+pub const YELLOW_600: Rgb = (202, 138, 4);
+pub const YELLOW_700: Rgb = (161, 98, 7);
+pub const YELLOW_800: Rgb = (133, 77, 14);
+pub const YELLOW_900: Rgb = (113, 63, 18);
+pub const YELLOW_950: Rgb = (66, 32, 6);
+
+pub const LIME_50: Rgb = (247, 254, 231);
+pub const LIME_100: Rgb = (236, 252, 203);
+pub const LIME_200: Rgb = (217, 249, 157);
+pub const LIME_300: Rgb = (190, 242, 100);
+pub const LIME_400: Rgb = (163, 230, 53);
+pub const LIME_500: Rgb = (132, 204, 22);
+pub const LIME_600: Rgb = (101, 163, 13);
+pub const LIME_700: Rgb = (77, 124, 15);
+pub const LIME_800: Rgb = (63, 98, 18);
+pub const LIME_900: Rgb = (54, 83, 20);
+pub const LIME_950: Rgb = (26, 46, 5);
+
+pub const GREEN_50: Rgb = (240, 253, 244);
+pub const GREEN_100: Rgb = (220, 252, 231);
+pub const GREEN_200: Rgb = (187, 247, 208);
+pub const GREEN_300: Rgb = (134, 239, 172);
+pub const GREEN_400: Rgb = (74, 222, 128);
+pub const GREEN_500: Rgb = (34, 197, 94);
+pub const GREEN_600: Rgb = (22, 163, 74);
+pub const GREEN_700: Rgb = (21, 128, 61);
+pub const GREEN_800: Rgb = (22, 101, 52);
+pub const GREEN_900: Rgb = (20, 83, 45);
+pub const GREEN_950: Rgb = (5, 46, 22);
+
+pub const EMERALD_50: Rgb = (236, 253, 245);
+pub const EMERALD_100: Rgb = (209, 250, 229);
+pub const EMERALD_200: Rgb = (167, 243, 208);
+pub const EMERALD_300: Rgb = (110, 231, 183);
+pub const EMERALD_400: Rgb = (52, 211, 153);
+pub const EMERALD_500: Rgb = (16, 185, 129);
+pub const EMERALD_600: Rgb = (5, 150, 105);
+pub const EMERALD_700: Rgb = (4, 120, 87);
+pub const EMERALD_800: Rgb = (6, 95, 70);
+pub const EMERALD_900: Rgb = (6, 78, 59);
+pub const EMERALD_950: Rgb = (2, 44, 34);
+
+pub const TEAL_50: Rgb = (240, 253, 250);
+pub const TEAL_100: Rgb = (204, 251, 241);
+pub const TEAL_200: Rgb = (153, 246, 228);
+pub const TEAL_300: Rgb = (94, 234, 212);
+pub const TEAL_400: Rgb = (45, 212, 191);
+pub const TEAL_500: Rgb = (20, 184, 166);
+pub const TEAL_600: Rgb = (13, 148, 136);
+pub const TEAL_700: Rgb = (15, 118, 110);
+pub const TEAL_800: Rgb = (17, 94, 89);
+pub const TEAL_900: Rgb = (19, 78, 74);
+pub const TEAL_950: Rgb = (4, 47, 46);
+
+pub const CYAN_50: Rgb = (236, 254, 255);
+pub const CYAN_100: Rgb = (207, 250, 254);
+pub const CYAN_200: Rgb = (165, 243, 252);
+pub const CYAN_300: Rgb = (103, 232, 249);
+pub const CYAN_400: Rgb = (34, 211, 238);
+pub const CYAN_500: Rgb = (6, 182, 212);
+pub const CYAN_600: Rgb = (8, 145, 178);
+pub const CYAN_700: Rgb = (14, 116, 144);
+pub const CYAN_800: Rgb = (21, 94, 117);
+pub const CYAN_900: Rgb = (22, 78, 99);
+pub const CYAN_950: Rgb = (8, 51, 68);
+
+pub const SKY_50: Rgb = (240, 249, 255);
+pub const SKY_100: Rgb = (224, 242, 254);
+pub const SKY_200: Rgb = (186, 230, 253);
+pub const SKY_300: Rgb = (125, 211, 252);
+pub const SKY_400: Rgb = (56, 189, 248);
+pub const SKY_500: Rgb = (14, 165, 233);
+pub const SKY_600: Rgb = (2, 132, 199);
+pub const SKY_700: Rgb = (3, 105, 161);
+pub const SKY_800: Rgb = (7, 89, 133);
+pub const SKY_900: Rgb = (12, 74, 110);
+pub const SKY_950: Rgb = (8, 47, 73);
+
+pub const BLUE_50: Rgb = (239, 246, 255);
+pub const BLUE_100: Rgb = (219, 234, 254);
+pub const BLUE_200: Rgb = (191, 219, 254);
+pub const BLUE_300: Rgb = (147, 197, 253);
+pub const BLUE_400: Rgb = (96, 165, 250);
+pub const BLUE_500: Rgb = (59, 130, 246);
+pub const BLUE_600: Rgb = (37, 99, 235);
+pub const BLUE_700: Rgb = (29, 78, 216);
+pub const BLUE_800: Rgb = (30, 64, 175);
+pub const BLUE_900: Rgb = (30, 58, 138);
+pub const BLUE_950: Rgb = (23, 37, 84);
+
+pub const INDIGO_50: Rgb = (238, 242, 255);
+pub const INDIGO_100: Rgb = (224, 231, 255);
+pub const INDIGO_200: Rgb = (199, 210, 254);
+pub const INDIGO_300: Rgb = (165, 180, 252);
+pub const INDIGO_400: Rgb = (129, 140, 248);
+pub const INDIGO_500: Rgb = (99, 102, 241);
+pub const INDIGO_600: Rgb = (79, 70, 229);
+pub const INDIGO_700: Rgb = (67, 56, 202);
+pub const INDIGO_800: Rgb = (55, 48, 163);
+pub const INDIGO_900: Rgb = (49, 46, 129);
+pub const INDIGO_950: Rgb = (30, 27, 75);
+
+pub const VIOLET_50: Rgb = (245, 243, 255);
+pub const VIOLET_100: Rgb = (237, 233, 254);
+pub const VIOLET_200: Rgb = (221, 214, 254);
+pub const VIOLET_300: Rgb = (196, 181, 253);
+pub const VIOLET_400: Rgb = (167, 139, 250);
+pub const VIOLET_500: Rgb = (139, 92, 246);
+pub const VIOLET_600: Rgb = (124, 58, 237);
+pub const VIOLET_700: Rgb = (109, 40, 217);
+pub const VIOLET_800: Rgb = (91, 33, 182);
+pub const VIOLET_900: Rgb = (76, 29, 149);
+pub const VIOLET_950: Rgb = (46, 16, 101);
+
+pub const PURPLE_50: Rgb = (250, 245, 255);
+pub const PURPLE_100: Rgb = (243, 232, 255);
+pub const PURPLE_200: Rgb = (233, 213, 255);
+pub const PURPLE_300: Rgb = (216, 180, 254);
+pub const PURPLE_400: Rgb = (192, 132, 252);
+pub const PURPLE_500: Rgb = (168, 85, 247);
+pub const PURPLE_600: Rgb = (147, 51, 234);
+pub const PURPLE_700: Rgb = (126, 34, 206);
+pub const PURPLE_800: Rgb = (107, 33, 168);
+pub const PURPLE_900: Rgb = (88, 28, 135);
+pub const PURPLE_950: Rgb = (59, 7, 100);
+
+pub const FUCHSIA_50: Rgb = (253, 244, 255);
+pub const FUCHSIA_100: Rgb = (250, 232, 255);
+pub const FUCHSIA_200: Rgb = (245, 208, 254);
+pub const FUCHSIA_300: Rgb = (240, 171, 252);
+pub const FUCHSIA_400: Rgb = (232, 121, 249);
+pub const FUCHSIA_500: Rgb = (217, 70, 239);
+pub const FUCHSIA_600: Rgb = (192, 38, 211);
+pub const FUCHSIA_700: Rgb = (162, 28, 175);
+pub const FUCHSIA_800: Rgb = (134, 25, 143);
+pub const FUCHSIA_900: Rgb = (112, 26, 117);
+pub const FUCHSIA_950: Rgb = (74, 4, 78);
+
+pub const PINK_50: Rgb = (253, 242, 248);
+pub const PINK_100: Rgb = (252, 231, 243);
+pub const PINK_200: Rgb = (251, 207, 232);
+pub const PINK_300: Rgb = (249, 168, 212);
+pub const PINK_400: Rgb = (244, 114, 182);
+pub const PINK_500: Rgb = (236, 72, 153);
+pub const PINK_600: Rgb = (219, 39, 119);
+pub const PINK_700: Rgb = (190, 24, 93);
+pub const PINK_800: Rgb = (157, 23, 77);
+pub const PINK_900: Rgb = (131, 24, 67);
+pub const PINK_950: Rgb = (80, 7, 36);
+
+pub const ROSE_50: Rgb = (255, 241, 242);
+pub const ROSE_100: Rgb = (255, 228, 230);
+pub const ROSE_200: Rgb = (254, 205, 211);
+pub const ROSE_300: Rgb = (253, 164, 175);
+pub const ROSE_400: Rgb = (251, 113, 133);
+pub const ROSE_500: Rgb = (244, 63, 94);
+pub const ROSE_600: Rgb = (225, 29, 72);
+pub const ROSE_700: Rgb = (190, 18, 60);
+pub const ROSE_800: Rgb = (159, 18, 57);
+pub const ROSE_900: Rgb = (136, 19, 55);
+pub const ROSE_950: Rgb = (76, 5, 25);
+
+/// The colors by name and shade, in Tailwind's order
+const PALETTE: &[(&str, u16, Rgb)] = &[
+    ("slate", 50, SLATE_50),
+    ("slate", 100, SLATE_100),
+    ("slate", 200, SLATE_200),
+    ("slate", 300, SLATE_300),
+    ("slate", 400, SLATE_400),
+    ("slate", 500, SLATE_500),
+    ("slate", 600, SLATE_600),
+    ("slate", 700, SLATE_700),
+    ("slate", 800, SLATE_800),
+    ("slate", 900, SLATE_900),
+    ("slate", 950, SLATE_950),
+    ("gray", 50, GRAY_50),
+    ("gray", 100, GRAY_100),
+    ("gray", 200, GRAY_200),
+    ("gray", 300, GRAY_300),
+    ("gray", 400, GRAY_400),
+    ("gray", 500, GRAY_500),
+    ("gray", 600, GRAY_600),
+    ("gray", 700, GRAY_700),
+    ("gray", 800, GRAY_800),
+    ("gray", 900, GRAY_900),
+    ("gray", 950, GRAY_950),
+    ("zinc", 50, ZINC_50),
+    ("zinc", 100, ZINC_100),
+    ("zinc", 200, ZINC_200),
+    ("zinc", 300, ZINC_300),
+    ("zinc", 400, ZINC_400),
+    ("zinc", 500, ZINC_500),
+    ("zinc", 600, ZINC_600),
+    ("zinc", 700, ZINC_700),
+    ("zinc", 800, ZINC_800),
+    ("zinc", 900, ZINC_900),
+    ("zinc", 950, ZINC_950),
+    ("neutral", 50, NEUTRAL_50),
+    ("neutral", 100, NEUTRAL_100),
+    ("neutral", 200, NEUTRAL_200),
+    ("neutral", 300, NEUTRAL_300),
+    ("neutral", 400, NEUTRAL_400),
+    ("neutral", 500, NEUTRAL_500),
+    ("neutral", 600, NEUTRAL_600),
+    ("neutral", 700, NEUTRAL_700),
+    ("neutral", 800, NEUTRAL_800),
+    ("neutral", 900, NEUTRAL_900),
+    ("neutral", 950, NEUTRAL_950),
+    ("stone", 50, STONE_50),
+    ("stone", 100, STONE_100),
+    ("stone", 200, STONE_200),
+    ("stone", 300, STONE_300),
+    ("stone", 400, STONE_400),
+    ("stone", 500, STONE_500),
+    ("stone", 600, STONE_600),
+    ("stone", 700, STONE_700),
+    ("stone", 800, STONE_800),
+    ("stone", 900, STONE_900),
+    ("stone", 950, STONE_950),
+    ("red", 50, RED_50),
+    ("red", 100, RED_100),
+    ("red", 200, RED_200),
+    ("red", 300, RED_300),
+    ("red", 400, RED_400),
+    ("red", 500, RED_500),
+    ("red", 600, RED_600),
+    ("red", 700, RED_700),
+    ("red", 800, RED_800),
+    ("red", 900, RED_900),
+    ("red", 950, RED_950),
+    ("orange", 50, ORANGE_50),
+    ("orange", 100, ORANGE_100),
+    ("orange", 200, ORANGE_200),
+    ("orange", 300, ORANGE_300),
+    ("orange", 400, ORANGE_400),
+    ("orange", 500, ORANGE_500),
+    ("orange", 600, ORANGE_600),
+    ("orange", 700, ORANGE_700),
+    ("orange", 800, ORANGE_800),
+    ("orange", 900, ORANGE_900),
+    ("orange", 950, ORANGE_950),
+    ("amber", 50, AMBER_50),
+    ("amber", 100, AMBER_100),
+    ("amber", 200, AMBER_200),
+    ("amber", 300, AMBER_300),
+    ("amber", 400, AMBER_400),
+    ("amber", 500, AMBER_500),
+    ("amber", 600, AMBER_600),
+    ("amber", 700, AMBER_700),
+    ("amber", 800, AMBER_800),
+    ("amber", 900, AMBER_900),
+    ("amber", 950, AMBER_950),
+    ("yellow", 50, YELLOW_50),
+    ("yellow", 100, YELLOW_100),
+    ("yellow", 200, YELLOW_200),
+    ("yellow", 300, YELLOW_300),
+    ("yellow", 400, YELLOW_400),
+    ("yellow", 500, YELLOW_500),
+    ("yellow", 600, YELLOW_600),
+    ("yellow", 700, YELLOW_700),
+    ("yellow", 800, YELLOW_800),
+    ("yellow", 900, YELLOW_900),
+    ("yellow", 950, YELLOW_950),
+    ("lime", 50, LIME_50),
+    ("lime", 100, LIME_100),
+    ("lime", 200, LIME_200),
+    ("lime", 300, LIME_300),
+    ("lime", 400, LIME_400),
+    ("lime", 500, LIME_500),
+    ("lime", 600, LIME_600),
+    ("lime", 700, LIME_700),
+    ("lime", 800, LIME_800),
+    ("lime", 900, LIME_900),
+    ("lime", 950, LIME_950),
+    ("green", 50, GREEN_50),
+    ("green", 100, GREEN_100),
+    ("green", 200, GREEN_200),
+    ("green", 300, GREEN_300),
+    ("green", 400, GREEN_400),
+    ("green", 500, GREEN_500),
+    ("green", 600, GREEN_600),
+    ("green", 700, GREEN_700),
+    ("green", 800, GREEN_800),
+    ("green", 900, GREEN_900),
+    ("green", 950, GREEN_950),
+    ("emerald", 50, EMERALD_50),
+    ("emerald", 100, EMERALD_100),
+    ("emerald", 200, EMERALD_200),
+    ("emerald", 300, EMERALD_300),
+    ("emerald", 400, EMERALD_400),
+    ("emerald", 500, EMERALD_500),
+    ("emerald", 600, EMERALD_600),
+    ("emerald", 700, EMERALD_700),
+    ("emerald", 800, EMERALD_800),
+    ("emerald", 900, EMERALD_900),
+    ("emerald", 950, EMERALD_950),
+    ("teal", 50, TEAL_50),
+    ("teal", 100, TEAL_100),
+    ("teal", 200, TEAL_200),
+    ("teal", 300, TEAL_300),
+    ("teal", 400, TEAL_400),
+    ("teal", 500, TEAL_500),
+    ("teal", 600, TEAL_600),
+    ("teal", 700, TEAL_700),
+    ("teal", 800, TEAL_800),
+    ("teal", 900, TEAL_900),
+    ("teal", 950, TEAL_950),
+    ("cyan", 50, CYAN_50),
+    ("cyan", 100, CYAN_100),
+    ("cyan", 200, CYAN_200),
+    ("cyan", 300, CYAN_300),
+    ("cyan", 400, CYAN_400),
+    ("cyan", 500, CYAN_500),
+    ("cyan", 600, CYAN_600),
+    ("cyan", 700, CYAN_700),
+    ("cyan", 800, CYAN_800),
+    ("cyan", 900, CYAN_900),
+    ("cyan", 950, CYAN_950),
+    ("sky", 50, SKY_50),
+    ("sky", 100, SKY_100),
+    ("sky", 200, SKY_200),
+    ("sky", 300, SKY_300),
+    ("sky", 400, SKY_400),
+    ("sky", 500, SKY_500),
+    ("sky", 600, SKY_600),
+    ("sky", 700, SKY_700),
+    ("sky", 800, SKY_800),
+    ("sky", 900, SKY_900),
+    ("sky", 950, SKY_950),
+    ("blue", 50, BLUE_50),
+    ("blue", 100, BLUE_100),
+    ("blue", 200, BLUE_200),
+    ("blue", 300, BLUE_300),
+    ("blue", 400, BLUE_400),
+    ("blue", 500, BLUE_500),
+    ("blue", 600, BLUE_600),
+    ("blue", 700, BLUE_700),
+    ("blue", 800, BLUE_800),
+    ("blue", 900, BLUE_900),
+    ("blue", 950, BLUE_950),
+    ("indigo", 50, INDIGO_50),
+    ("indigo", 100, INDIGO_100),
+    ("indigo", 200, INDIGO_200),
+    ("indigo", 300, INDIGO_300),
+    ("indigo", 400, INDIGO_400),
+    ("indigo", 500, INDIGO_500),
+    ("indigo", 600, INDIGO_600),
+    ("indigo", 700, INDIGO_700),
+    ("indigo", 800, INDIGO_800),
+    ("indigo", 900, INDIGO_900),
+    ("indigo", 950, INDIGO_950),
+    ("violet", 50, VIOLET_50),
+    ("violet", 100, VIOLET_100),
+    ("violet", 200, VIOLET_200),
+    ("violet", 300, VIOLET_300),
+    ("violet", 400, VIOLET_400),
+    ("violet", 500, VIOLET_500),
+    ("violet", 600, VIOLET_600),
+    ("violet", 700, VIOLET_700),
+    ("violet", 800, VIOLET_800),
+    ("violet", 900, VIOLET_900),
+    ("violet", 950, VIOLET_950),
+    ("purple", 50, PURPLE_50),
+    ("purple", 100, PURPLE_100),
+    ("purple", 200, PURPLE_200),
+    ("purple", 300, PURPLE_300),
+    ("purple", 400, PURPLE_400),
+    ("purple", 500, PURPLE_500),
+    ("purple", 600, PURPLE_600),
+    ("purple", 700, PURPLE_700),
+    ("purple", 800, PURPLE_800),
+    ("purple", 900, PURPLE_900),
+    ("purple", 950, PURPLE_950),
+    ("fuchsia", 50, FUCHSIA_50),
+    ("fuchsia", 100, FUCHSIA_100),
+    ("fuchsia", 200, FUCHSIA_200),
+    ("fuchsia", 300, FUCHSIA_300),
+    ("fuchsia", 400, FUCHSIA_400),
+    ("fuchsia", 500, FUCHSIA_500),
+    ("fuchsia", 600, FUCHSIA_600),
+    ("fuchsia", 700, FUCHSIA_700),
+    ("fuchsia", 800, FUCHSIA_800),
+    ("fuchsia", 900, FUCHSIA_900),
+    ("fuchsia", 950, FUCHSIA_950),
+    ("pink", 50, PINK_50),
+    ("pink", 100, PINK_100),
+    ("pink", 200, PINK_200),
+    ("pink", 300, PINK_300),
+    ("pink", 400, PINK_400),
+    ("pink", 500, PINK_500),
+    ("pink", 600, PINK_600),
+    ("pink", 700, PINK_700),
+    ("pink", 800, PINK_800),
+    ("pink", 900, PINK_900),
+    ("pink", 950, PINK_950),
+    ("rose", 50, ROSE_50),
+    ("rose", 100, ROSE_100),
+    ("rose", 200, ROSE_200),
+    ("rose", 300, ROSE_300),
+    ("rose", 400, ROSE_400),
+    ("rose", 500, ROSE_500),
+    ("rose", 600, ROSE_600),
+    ("rose", 700, ROSE_700),
+    ("rose", 800, ROSE_800),
+    ("rose", 900, ROSE_900),
+    ("rose", 950, ROSE_950),
+];
+
+/// Looks a Tailwind color up by name and shade, e.g. `get_rgb("slate", 700)`, ignoring the case
+/// of the name. None for unknown names and shades other than those of `SHADES`
+pub fn get_rgb(name: &str, shade: u16) -> Option<(u8, u8, u8)> {
+    PALETTE
+        .iter()
+        .find(|(n, s, _)| *s == shade && n.eq_ignore_ascii_case(name.trim()))
+        .map(|(_, _, rgb)| *rgb)
+}
+
+/**
+Like `get_rgb`, as an FLTK `Color`:
+```rust
+use fltk_theme::colors::tailwind;
+assert_eq!(tailwind::get("slate", 700), Some(fltk::enums::Color::from_hex(0x334155)));
+```
+*/
+pub fn get(name: &str, shade: u16) -> Option<Color> {
+    get_rgb(name, shade).map(|(r, g, b)| Color::from_rgb(r, g, b))
+}
+
+/// The names of the colors, e.g. `slate` and `emerald`, in Tailwind's order
+pub fn names() -> impl Iterator<Item = &'static str> {
+    PALETTE
+        .iter()
+        .filter(|(_, shade, _)| *shade == SHADES[0])
+        .map(|(name, _, _)| *name)
+}
@@ -0,0 +1,150 @@
+use crate::{color_themes, ColorTheme, SchemeType, ThemeType};
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static WIDGET_THEME: Cell<Option<ThemeType>> = const { Cell::new(None) };
+    static WIDGET_SCHEME: Cell<Option<SchemeType>> = const { Cell::new(None) };
+    static COLOR_THEME: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Records an applied widget theme, which replaces the scheme and the colors
+pub(crate) fn set_widget_theme(theme: Option<ThemeType>) {
+    WIDGET_THEME.with(|t| t.set(theme));
+    WIDGET_SCHEME.with(|s| s.set(None));
+    COLOR_THEME.with(|c| *c.borrow_mut() = None);
+}
+
+/// Records an applied widget scheme, which replaces the frame drawing of the widget theme
+pub(crate) fn set_widget_scheme(scheme: Option<SchemeType>) {
+    WIDGET_SCHEME.with(|s| s.set(scheme));
+    WIDGET_THEME.with(|t| t.set(None));
+}
+
+/// Records an applied color theme, named if it's one of the constants of `color_themes`,
+/// and notifies the `on_theme_applied` callbacks
+pub(crate) fn set_color_theme(theme: &ColorTheme) {
+    let name = color_themes::NAMED_THEMES
+        .iter()
+        .find(|(_, colors)| theme.colors.as_slice() == *colors)
+        .map(|(name, _)| name.to_string());
+    COLOR_THEME.with(|c| *c.borrow_mut() = name.clone());
+    notify(ThemeEvent::ColorTheme(name));
+}
+
+/// Records the parts of an applied `Theme`, the color theme being recorded by its own apply.
+/// Returns the color theme's name
+pub(crate) fn set_combined(
+    widget: Option<ThemeType>,
+    scheme: Option<SchemeType>,
+) -> Option<String> {
+    if widget.is_some() {
+        WIDGET_THEME.with(|t| t.set(widget));
+    }
+    if scheme.is_some() {
+        WIDGET_SCHEME.with(|s| s.set(scheme));
+    }
+    current_color_theme_name()
+}
+
+/// Records that the colors were reset to FLTK's
+pub(crate) fn clear_color_theme() {
+    COLOR_THEME.with(|c| *c.borrow_mut() = None);
+}
+
+/**
+Returns the last applied widget theme, None if none was applied, or if a widget scheme was
+applied or `WidgetTheme::reset()` called since:
+```rust,no_run
+use fltk_theme::{ThemeType, WidgetTheme};
+WidgetTheme::new(ThemeType::Dark).apply();
+assert_eq!(fltk_theme::current_widget_theme(), Some(ThemeType::Dark));
+```
+*/
+pub fn current_widget_theme() -> Option<ThemeType> {
+    WIDGET_THEME.with(|t| t.get())
+}
+
+/// Returns the last applied widget scheme, None if none was applied, or if a widget theme was
+/// applied or `WidgetScheme::reset()` called since
+pub fn current_widget_scheme() -> Option<SchemeType> {
+    WIDGET_SCHEME.with(|s| s.get())
+}
+
+/// Returns the name in `color_themes` (e.g. `"NORD_THEME"`) of the last applied color theme,
+/// None if it isn't one of the constants, or if a widget theme was applied or the colors reset
+/// since
+pub fn current_color_theme_name() -> Option<String> {
+    COLOR_THEME.with(|c| c.borrow().clone())
+}
+
+/// What `on_theme_applied` callbacks are told about
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemeEvent {
+    /// A color theme was applied, with its name in `color_themes` if it's one of the constants
+    ColorTheme(Option<String>),
+    /// A widget theme was applied
+    WidgetTheme(ThemeType),
+    /// A widget scheme was applied
+    WidgetScheme(SchemeType),
+    /// A `Theme` was applied, with the parts it sets
+    Theme {
+        /// The widget theme
+        widget: Option<ThemeType>,
+        /// The widget scheme
+        scheme: Option<SchemeType>,
+        /// The name in `color_themes` of the colors, if they're one of the constants
+        color: Option<String>,
+    },
+    /// `reset_color_theme`, `WidgetTheme::reset` or `WidgetScheme::reset` was called
+    Reset,
+}
+
+type Callback = Box<dyn FnMut(&ThemeEvent)>;
+
+thread_local! {
+    static CALLBACKS: RefCell<Vec<Callback>> = const { RefCell::new(Vec::new()) };
+    static QUIET: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Runs f without notifying, for the applies done as part of a bigger one
+pub(crate) fn quiet<R>(f: impl FnOnce() -> R) -> R {
+    QUIET.with(|q| q.set(q.get() + 1));
+    let ret = f();
+    QUIET.with(|q| q.set(q.get() - 1));
+    ret
+}
+
+/// Calls the `on_theme_applied` callbacks
+pub(crate) fn notify(event: ThemeEvent) {
+    if QUIET.with(|q| q.get()) > 0 {
+        return;
+    }
+    // taken out while running, so that callbacks can apply themes or register callbacks
+    let mut running = CALLBACKS.with(|cbs| std::mem::take(&mut *cbs.borrow_mut()));
+    for cb in &mut running {
+        cb(&event);
+    }
+    CALLBACKS.with(|cbs| {
+        let mut cbs = cbs.borrow_mut();
+        running.append(&mut cbs);
+        *cbs = running;
+    });
+}
+
+/**
+Calls callback whenever a theme of the crate is applied or reset, after the colors and frame types
+are set and before the redraw, e.g. to recompute the colors cached by custom-drawn widgets.
+Callbacks are kept for the lifetime of the app, and should be registered from the main thread.
+A callback which applies a theme itself isn't called back for it:
+```rust,no_run
+use fltk_theme::ThemeEvent;
+fltk_theme::on_theme_applied(|event| {
+    if let ThemeEvent::WidgetTheme(theme) = event {
+        println!("now using {:?}", theme);
+    }
+});
+```
+*/
+pub fn on_theme_applied<F: FnMut(&ThemeEvent) + 'static>(callback: F) {
+    CALLBACKS.with(|cbs| cbs.borrow_mut().push(Box::new(callback)));
+}
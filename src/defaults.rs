@@ -0,0 +1,166 @@
+use crate::current::{self, ThemeEvent};
+use crate::hover;
+use crate::{ColorMap, ColorTheme};
+use fltk::{
+    app,
+    enums::{Color, FrameType},
+    misc::Tooltip,
+};
+use std::cell::RefCell;
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+
+/// The original frame types are copied to the end of FLTK's table of 256 frame types,
+/// away from the free ones (starting at `FrameType::FreeBoxType`) which apps allocate from
+const SPARE_END: usize = 255;
+
+/// FLTK's state before the crate first changed it
+struct Defaults {
+    colors: ColorTheme,
+    scheme: app::Scheme,
+    /// NoBox through FreeBoxType
+    frame_count: usize,
+    visible_focus: bool,
+    scrollbar_size: i32,
+    tooltip_color: Color,
+    tooltip_text_color: Color,
+    tooltip_font_size: i32,
+    tooltip_delay: f32,
+}
+
+/// The thread which applied the first theme, FLTK's UI thread
+static UI_THREAD: Mutex<Option<ThreadId>> = Mutex::new(None);
+
+/// A color a widget theme changed: its value before it and the one it set
+type SavedColor = (ColorMap, (u8, u8, u8));
+
+thread_local! {
+    static ORIGINAL: RefCell<Option<Defaults>> = const { RefCell::new(None) };
+    /// The colors the last widget theme changed
+    static WIDGET_COLORS: RefCell<Vec<SavedColor>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Snapshots FLTK's 256 colors, its scheme and frame drawing and the settings changed by widget
+/// themes and schemes, the first time it's called, before the crate changes any of them
+pub(crate) fn record_defaults() {
+    UI_THREAD
+        .lock()
+        .unwrap()
+        .get_or_insert_with(|| thread::current().id());
+    ORIGINAL.with(|original| {
+        let mut original = original.borrow_mut();
+        if original.is_some() {
+            return;
+        }
+        let mut frame_count = 0;
+        loop {
+            let frame = FrameType::by_index(frame_count);
+            app::set_frame_type2(FrameType::by_index(SPARE_END - frame_count), frame);
+            frame_count += 1;
+            if frame == FrameType::FreeBoxType {
+                break;
+            }
+        }
+        *original = Some(Defaults {
+            colors: ColorTheme::from_app(),
+            scheme: app::scheme(),
+            frame_count,
+            visible_focus: app::visible_focus(),
+            scrollbar_size: app::scrollbar_size(),
+            tooltip_color: Tooltip::color(),
+            tooltip_text_color: Tooltip::text_color(),
+            tooltip_font_size: Tooltip::font_size(),
+            tooltip_delay: Tooltip::delay(),
+        });
+    });
+}
+
+/// The copy of frame's stock drawing, for frame types drawn by the crate to fall back on. Only
+/// valid after `record_defaults`
+pub(crate) fn stock_frame(frame: FrameType) -> FrameType {
+    FrameType::by_index(SPARE_END - frame as usize)
+}
+
+/// Whether the current thread is the one which applied the first theme, or before any was
+/// applied, the one named main
+pub(crate) fn on_ui_thread() -> bool {
+    match *UI_THREAD.lock().unwrap() {
+        Some(id) => id == thread::current().id(),
+        None => thread::current().name() == Some("main"),
+    }
+}
+
+/// Restores the recorded scheme, frame drawing and settings, used by `WidgetTheme::reset` and
+/// `WidgetScheme::reset`
+pub(crate) fn restore_drawing() {
+    ORIGINAL.with(|original| {
+        if let Some(original) = original.borrow().as_ref() {
+            // the scheme reassigns some frame types, so it goes first
+            app::set_scheme(original.scheme);
+            for i in 0..original.frame_count {
+                app::set_frame_type2(FrameType::by_index(i), FrameType::by_index(SPARE_END - i));
+            }
+            app::set_visible_focus(original.visible_focus);
+            app::set_scrollbar_size(original.scrollbar_size);
+            Tooltip::set_color(original.tooltip_color);
+            Tooltip::set_text_color(original.tooltip_text_color);
+            Tooltip::set_font_size(original.tooltip_font_size);
+            Tooltip::set_delay(original.tooltip_delay);
+        }
+    });
+    hover::set_styled(false);
+    app::redraw();
+}
+
+/// Sets the colors of a widget theme through set, recording the ones it changes for
+/// `undo_widget_colors`
+pub(crate) fn record_widget_colors(set: impl FnOnce()) {
+    let before = ColorTheme::from_app();
+    set();
+    let changed = before
+        .colors
+        .into_iter()
+        .filter_map(|elem| {
+            let now = app::get_color(Color::by_index(elem.index));
+            if now != (elem.r, elem.g, elem.b) {
+                Some((elem, now))
+            } else {
+                None
+            }
+        })
+        .collect();
+    WIDGET_COLORS.with(|colors| *colors.borrow_mut() = changed);
+}
+
+/// Puts back the colors the last widget theme replaced, except those changed since, e.g. by a
+/// `ColorTheme` applied after it
+pub(crate) fn undo_widget_colors() {
+    let changed = WIDGET_COLORS.with(|colors| colors.take());
+    for (elem, set) in changed {
+        let color = Color::by_index(elem.index);
+        if app::get_color(color) == set {
+            app::set_color(color, elem.r, elem.g, elem.b);
+        }
+    }
+}
+
+/**
+Restores the colors FLTK had before the first theme of this crate was applied, however many themes
+were applied since, e.g. for the "Default" entry of a theme picker. Does nothing if no theme was
+applied yet. The frame drawing set by widget themes and schemes is left as it is, see
+`WidgetTheme::reset` for that. Should be called from the main thread:
+```rust,no_run
+use fltk_theme::{color_themes, ColorTheme};
+ColorTheme::new(color_themes::DARK_THEME).apply();
+ColorTheme::new(color_themes::TAN_THEME).apply();
+fltk_theme::reset_color_theme();
+```
+*/
+pub fn reset_color_theme() {
+    let colors = ORIGINAL.with(|original| original.borrow().as_ref().map(|o| o.colors.clone()));
+    if let Some(colors) = colors {
+        current::quiet(|| colors.apply());
+        current::clear_color_theme();
+        current::notify(ThemeEvent::Reset);
+    }
+}
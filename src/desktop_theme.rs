@@ -0,0 +1,94 @@
+use crate::gtk_css::current_theme_name;
+use crate::system_theme::{output, prefers_dark};
+use crate::{ColorTheme, ThemeType};
+
+/// The GTK theme of the desktop, as returned by `current_desktop_theme`
+#[derive(Debug, Clone)]
+pub struct DesktopTheme {
+    /// The GTK theme name, e.g. `Adwaita` or `Yaru-dark`
+    pub name: String,
+    /// Whether the desktop uses a dark appearance
+    pub dark: bool,
+}
+
+impl DesktopTheme {
+    /**
+    Picks the closest widget theme, along with a color theme for the desktop themes which have
+    no built-in counterpart:
+    - Adwaita, Yaru and Breeze: the matching theme, or its dark variant
+    - Greybird and elementary: the matching theme when light
+    - others: Greybird when light and AdwaitaDark when dark, with the palette read by
+      `ColorTheme::from_current_gtk_theme()` if the theme's stylesheet is found
+    */
+    pub fn suggest(&self) -> (ThemeType, Option<ColorTheme>) {
+        let name = self.name.to_ascii_lowercase();
+        let known = if name.starts_with("adwaita") {
+            Some(if self.dark {
+                ThemeType::AdwaitaDark
+            } else {
+                ThemeType::Adwaita
+            })
+        } else if name.starts_with("yaru") {
+            Some(if self.dark {
+                ThemeType::YaruDark
+            } else {
+                ThemeType::Yaru
+            })
+        } else if name.starts_with("breeze") {
+            Some(if self.dark {
+                ThemeType::BreezeDark
+            } else {
+                ThemeType::Breeze
+            })
+        } else if self.dark {
+            None
+        } else if name.starts_with("greybird") {
+            Some(ThemeType::Greybird)
+        } else if name.starts_with("elementary") || name.starts_with("io.elementary") {
+            Some(ThemeType::Elementary)
+        } else {
+            None
+        };
+        match known {
+            Some(theme) => (theme, None),
+            None if self.dark => (ThemeType::AdwaitaDark, ColorTheme::from_current_gtk_theme()),
+            None => (ThemeType::Greybird, ColorTheme::from_current_gtk_theme()),
+        }
+    }
+}
+
+/**
+Returns the GTK theme of the desktop, None if it can't be determined (e.g. on Windows and macOS).
+The name is GNOME's `gtk-theme` setting (read with gsettings), falling back to `GTK_THEME`
+and the `gtk-theme-name` of `gtk-3.0/settings.ini`. The desktop is dark if `prefers_dark()` says so
+or if the theme name says so. To blend in:
+```rust,no_run
+use fltk_theme::WidgetTheme;
+if let Some(desktop) = fltk_theme::current_desktop_theme() {
+    let (theme, colors) = desktop.suggest();
+    WidgetTheme::new(theme).apply();
+    if let Some(colors) = colors {
+        colors.apply();
+    }
+}
+```
+*/
+pub fn current_desktop_theme() -> Option<DesktopTheme> {
+    if cfg!(any(target_os = "windows", target_os = "macos")) {
+        return None;
+    }
+    let gsettings = output(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "gtk-theme"],
+    )
+    .map(|name| name.trim().trim_matches('\'').to_string())
+    .filter(|name| !name.is_empty());
+    let (name, variant_dark) = match gsettings {
+        Some(name) => (name, false),
+        None => current_theme_name()?,
+    };
+    let dark = prefers_dark().unwrap_or(false)
+        || variant_dark
+        || name.to_ascii_lowercase().contains("dark");
+    Some(DesktopTheme { name, dark })
+}
@@ -0,0 +1,116 @@
+use crate::{ColorMap, ColorTheme};
+use std::fmt;
+
+/// An index both themes of a `ThemeDiff` set, to different values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedColor {
+    /// The index
+    pub index: u8,
+    /// The value in the first theme
+    pub old: (u8, u8, u8),
+    /// The value in the second theme
+    pub new: (u8, u8, u8),
+}
+
+/// The differences between two themes, returned by `ColorTheme::diff`. Each list is sorted by
+/// index, and an index set twice within a theme is compared by its last value
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThemeDiff {
+    /// The indices only the second theme sets
+    pub added: Vec<ColorMap>,
+    /// The indices only the first theme sets
+    pub removed: Vec<ColorMap>,
+    /// The indices both set, to different values
+    pub changed: Vec<ChangedColor>,
+}
+
+impl ThemeDiff {
+    /// Whether the themes set the same indices to the same values
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// The indices which differ, added, removed or changed, sorted
+    pub fn indices(&self) -> Vec<u8> {
+        let mut indices: Vec<u8> = self
+            .added
+            .iter()
+            .chain(&self.removed)
+            .map(|c| c.index)
+            .chain(self.changed.iter().map(|c| c.index))
+            .collect();
+        indices.sort_unstable();
+        indices
+    }
+}
+
+fn hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// A table with a line per index, `+` for added, `-` for removed and `~` for changed ones
+impl fmt::Display for ThemeDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "no differences");
+        }
+        let mut lines: Vec<(u8, char, String, String)> = vec![];
+        for c in &self.added {
+            lines.push((c.index, '+', String::new(), hex((c.r, c.g, c.b))));
+        }
+        for c in &self.removed {
+            lines.push((c.index, '-', hex((c.r, c.g, c.b)), String::new()));
+        }
+        for c in &self.changed {
+            lines.push((c.index, '~', hex(c.old), hex(c.new)));
+        }
+        lines.sort_by_key(|line| line.0);
+        writeln!(f, "  index  old      new")?;
+        for (index, sign, old, new) in lines {
+            let line = format!("{} {:<6} {:<8} {}", sign, index, old, new);
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+impl ColorTheme {
+    /**
+    Compares the theme to other, e.g. to check that a family of themes only differ in their
+    accents. An index a theme doesn't set is different from one set to FLTK's default value:
+    ```rust
+    use fltk_theme::{cmap_hex, ColorMap, ColorTheme};
+    let base = ColorTheme::from(vec![cmap_hex!(49, "#2e3440"), cmap_hex!(15, "#5e81ac")]);
+    let brand = ColorTheme::from(vec![cmap_hex!(49, "#2e3440"), cmap_hex!(15, "#d08770")]);
+    let diff = base.diff(&brand);
+    assert_eq!(diff.indices(), [15]);
+    print!("{}", diff);
+    ```
+    */
+    pub fn diff(&self, other: &ColorTheme) -> ThemeDiff {
+        let mut diff = ThemeDiff::default();
+        for index in 0..=255 {
+            match (self.value(index), other.value(index)) {
+                (Some(old), Some(new)) if old != new => {
+                    diff.changed.push(ChangedColor { index, old, new })
+                }
+                (None, Some((r, g, b))) => diff.added.push(ColorMap {
+                    index,
+                    r,
+                    g,
+                    b,
+                    a: 255,
+                }),
+                (Some((r, g, b)), None) => diff.removed.push(ColorMap {
+                    index,
+                    r,
+                    g,
+                    b,
+                    a: 255,
+                }),
+                _ => (),
+            }
+        }
+        diff
+    }
+}
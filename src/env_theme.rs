@@ -0,0 +1,123 @@
+use crate::{color_themes, SchemeType, ThemeChoice, ThemeError, ThemeType};
+use std::{env, path::PathBuf};
+
+/// What `from_env` applied, along with what it couldn't
+#[derive(Debug)]
+pub struct AppliedTheme {
+    /// The applied parts, in the order they were applied
+    pub applied: Vec<ThemeChoice>,
+    /// The unknown names and the theme files which couldn't be loaded
+    pub errors: Vec<ThemeError>,
+}
+
+/// Lowercases a name and drops its separators, so `HighContrastDark` matches `high-contrast-dark`
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn find_widget(name: &str) -> Option<ThemeType> {
+    let name = normalize(name);
+    ThemeType::ALL
+        .iter()
+        .find(|t| normalize(&format!("{:?}", t)) == name)
+        .copied()
+}
+
+fn find_scheme(name: &str) -> Option<SchemeType> {
+    let name = normalize(name);
+    SchemeType::ALL
+        .iter()
+        .find(|s| normalize(&format!("{:?}", s)) == name)
+        .copied()
+}
+
+/// Finds a built-in color theme, `nord` matching `NORD_THEME` and `catppuccin-mocha` matching
+/// `CATPPUCCIN_MOCHA`
+fn find_color(name: &str) -> Option<String> {
+    let name = normalize(name);
+    color_themes::NAMED_THEMES
+        .iter()
+        .map(|(n, _)| *n)
+        .find(|n| {
+            let n = normalize(n);
+            n == name || n.strip_suffix("theme") == Some(&name)
+        })
+        .map(str::to_string)
+}
+
+/**
+Applies the theme given by the `FLTK_THEME` environment variable, returning None if it's unset.
+The variable is either the path of a theme file (in any format `ThemeDir` recognizes) or
+`;`-separated `key:name` parts, the keys being `widget` (a `ThemeType`), `scheme` (a `SchemeType`),
+`color` (a theme of `color_themes`) and `file`:
+```sh
+FLTK_THEME="color:nord;widget:dark;scheme:fluent" ./myapp
+FLTK_THEME=/path/to/theme.toml ./myapp
+```
+Names are case-insensitive and may use `-` or `_`, e.g. `high-contrast-dark` or `catppuccin_mocha`.
+Whatever the order of the parts, the widget theme is applied first, then the scheme, the color
+theme and the file, so the colors win over the widget theme's. Call it after applying the app's
+own theme, before building the windows, so the variable wins:
+```rust,no_run
+use fltk_theme::{ThemeType, WidgetTheme};
+let app = fltk::app::App::default();
+WidgetTheme::new(ThemeType::Aero).apply();
+if let Some(theme) = fltk_theme::from_env() {
+    for e in &theme.errors {
+        eprintln!("FLTK_THEME: {}", e);
+    }
+}
+```
+*/
+pub fn from_env() -> Option<AppliedTheme> {
+    let value = env::var("FLTK_THEME").ok()?;
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    let mut choices = vec![];
+    let mut errors = vec![];
+    for part in value.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+        let choice = match part.split_once(':') {
+            Some((key, name)) if matches!(key.trim(), "widget" | "scheme" | "color" | "file") => {
+                let name = name.trim();
+                let choice = match key.trim() {
+                    "widget" => find_widget(name).map(ThemeChoice::Widget),
+                    "scheme" => find_scheme(name).map(ThemeChoice::Scheme),
+                    "color" => find_color(name).map(ThemeChoice::Color),
+                    _ => Some(ThemeChoice::File(PathBuf::from(name))),
+                };
+                choice.ok_or_else(|| ThemeError::UnknownTheme {
+                    name: part.to_string(),
+                })
+            }
+            Some((key, _)) if key.len() > 1 => Err(ThemeError::UnknownTheme {
+                name: part.to_string(),
+            }),
+            // a bare path, which may start with a drive such as `C:\themes\nord.toml`
+            _ => Ok(ThemeChoice::File(PathBuf::from(part))),
+        };
+        match choice {
+            Ok(choice) => choices.push(choice),
+            Err(e) => errors.push(e),
+        }
+    }
+    let rank = |choice: &ThemeChoice| match choice {
+        ThemeChoice::Widget(_) => 0,
+        ThemeChoice::Scheme(_) => 1,
+        ThemeChoice::Color(_) => 2,
+        ThemeChoice::File(_) => 3,
+    };
+    choices.sort_by_key(rank);
+    let mut applied = vec![];
+    for choice in choices {
+        match choice.apply() {
+            Ok(()) => applied.push(choice),
+            Err(e) => errors.push(e),
+        }
+    }
+    Some(AppliedTheme { applied, errors })
+}
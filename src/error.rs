@@ -0,0 +1,119 @@
+use std::{fmt, io};
+
+/// Errors returned when loading or saving a theme
+#[derive(Debug)]
+pub enum ThemeError {
+    /// Reading or writing the theme file failed
+    Io(io::Error),
+    /// A line couldn't be parsed
+    Syntax {
+        /// The 1-based line number
+        line: usize,
+        /// What's wrong with the line
+        message: String,
+    },
+    /// A key is neither a known color name nor an index between 0 and 255
+    InvalidIndex {
+        /// The 1-based line number
+        line: usize,
+        /// The offending key
+        key: String,
+    },
+    /// A value isn't a `#rrggbb` hex string
+    InvalidHex {
+        /// The 1-based line number, 0 for a color not read from a file
+        line: usize,
+        /// The key the value belongs to
+        key: String,
+        /// The offending value
+        value: String,
+    },
+    /// A value isn't a color in the format expected by the theme file
+    InvalidColor {
+        /// The 1-based line number
+        line: usize,
+        /// The key the value belongs to
+        key: String,
+        /// The offending value
+        value: String,
+    },
+    /// A color required by the format is missing
+    MissingColor {
+        /// The name of the missing color
+        key: String,
+    },
+    /// No theme with that name was found
+    UnknownTheme {
+        /// The requested name
+        name: String,
+    },
+    /// Applying was attempted from a thread other than the main one
+    NotMainThread,
+    /// The JSON theme couldn't be deserialized
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ThemeError::Io(e) => write!(f, "{}", e),
+            ThemeError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            ThemeError::InvalidIndex { line, key } => write!(
+                f,
+                "line {}: `{}` is neither a color name nor an index between 0 and 255",
+                line, key
+            ),
+            ThemeError::InvalidHex {
+                line: 0,
+                key,
+                value,
+            } => write!(
+                f,
+                "`{}` has an invalid hex color `{}`, expected #rrggbb",
+                key, value
+            ),
+            ThemeError::InvalidHex { line, key, value } => write!(
+                f,
+                "line {}: `{}` has an invalid hex color `{}`, expected #rrggbb",
+                line, key, value
+            ),
+            ThemeError::InvalidColor { line, key, value } => write!(
+                f,
+                "line {}: `{}` has an invalid color `{}`",
+                line, key, value
+            ),
+            ThemeError::MissingColor { key } => write!(f, "missing color `{}`", key),
+            ThemeError::UnknownTheme { name } => write!(f, "no theme named `{}`", name),
+            ThemeError::NotMainThread => {
+                write!(f, "themes can only be applied from the main thread")
+            }
+            #[cfg(feature = "json")]
+            ThemeError::Json(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeError::Io(e) => Some(e),
+            #[cfg(feature = "json")]
+            ThemeError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ThemeError {
+    fn from(e: io::Error) -> Self {
+        ThemeError::Io(e)
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for ThemeError {
+    fn from(e: serde_json::Error) -> Self {
+        ThemeError::Json(e)
+    }
+}
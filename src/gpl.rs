@@ -0,0 +1,112 @@
+use crate::colors::is_dark;
+use crate::ramp::gray_ramp_through;
+use crate::theme_file::{named_index, ramp_ends};
+use crate::{ColorMap, ColorTheme, ThemeError};
+use std::{fs, path::Path};
+
+impl ColorTheme {
+    /**
+    Builds a theme from a GIMP palette (`.gpl`), as also written by Inkscape and Aseprite.
+    Entries named after a theme color (`Background`, `Foreground`, `Selection`, `Dark3`...,
+    see `from_toml`) set that color, ignoring case. The other entries are stored in file order
+    starting at `ColorTheme::PALETTE_BASE_INDEX`, up to 16 of them.
+    When a background is named, the gray ramp is built around it unless its colors are named too.
+    */
+    pub fn from_gpl(path: &Path) -> Result<ColorTheme, ThemeError> {
+        ColorTheme::from_gpl_str(&fs::read_to_string(path)?)
+    }
+
+    /// Builds a theme from the content of a `.gpl` file, see `from_gpl`
+    pub fn from_gpl_str(s: &str) -> Result<ColorTheme, ThemeError> {
+        let mut lines = s.lines().enumerate();
+        let header = lines
+            .next()
+            .map(|(_, line)| line.trim_start_matches('\u{feff}').trim());
+        if header != Some("GIMP Palette") {
+            return Err(ThemeError::Syntax {
+                line: 1,
+                message: "expected the `GIMP Palette` header".to_string(),
+            });
+        }
+        let mut named = vec![];
+        let mut spare = vec![];
+        for (i, line) in lines {
+            let line_no = i + 1;
+            let line = line.trim();
+            if line.is_empty()
+                || line.starts_with('#')
+                || line.starts_with("Name:")
+                || line.starts_with("Columns:")
+            {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let components: Vec<_> = fields.by_ref().take(3).collect();
+            let name = fields.collect::<Vec<_>>().join(" ");
+            if components.len() < 3 {
+                return Err(ThemeError::Syntax {
+                    line: line_no,
+                    message: format!("expected `R G B name`, found `{}`", line),
+                });
+            }
+            let key = if name.is_empty() {
+                format!("color {}", named.len() + spare.len())
+            } else {
+                name.clone()
+            };
+            let mut rgb = [0u8; 3];
+            for (v, component) in rgb.iter_mut().zip(&components) {
+                *v = component.parse().map_err(|_| ThemeError::InvalidColor {
+                    line: line_no,
+                    key: key.clone(),
+                    value: components.join(" "),
+                })?;
+            }
+            let col = (rgb[0], rgb[1], rgb[2]);
+            match named_index(&name) {
+                Some(index) => named.push((index, col)),
+                None => spare.push(col),
+            }
+        }
+        let mut map: Vec<ColorMap> = named
+            .iter()
+            .map(|&(index, (r, g, b))| ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            })
+            .collect();
+        for (n, (r, g, b)) in spare.into_iter().take(16).enumerate() {
+            map.push(ColorMap {
+                index: ColorTheme::PALETTE_BASE_INDEX + n as u8,
+                r,
+                g,
+                b,
+                a: 255,
+            });
+        }
+        let find = |index: u8| {
+            named
+                .iter()
+                .rev()
+                .find(|(i, _)| *i == index)
+                .map(|(_, col)| *col)
+        };
+        if let Some(bg) = find(49) {
+            let fg = find(0).unwrap_or(if is_dark(bg) {
+                (255, 255, 255)
+            } else {
+                (0, 0, 0)
+            });
+            let (dark, light) = ramp_ends(bg, fg);
+            map.extend(
+                gray_ramp_through(dark, bg, light)
+                    .into_iter()
+                    .filter(|c| find(c.index).is_none()),
+            );
+        }
+        Ok(ColorTheme::from(map))
+    }
+}
@@ -0,0 +1,223 @@
+use crate::ini::Ini;
+use crate::ramp::{dark_end_through, gray_ramp_through};
+use crate::theme_file::{mix, parse_hex, ramp_ends};
+use crate::{ColorMap, ColorTheme};
+use std::{collections::HashMap, env, fs, path::PathBuf};
+
+type Rgb = (u8, u8, u8);
+
+/// Guards against reference cycles such as `@define-color a @b; @define-color b @a;`
+const MAX_DEPTH: usize = 16;
+
+// Adwaita's palette, used for entries missing from the theme or that can't be resolved
+const DEFAULT_BG: Rgb = (0xf6, 0xf5, 0xf4);
+const DEFAULT_FG: Rgb = (0x2e, 0x34, 0x36);
+const DEFAULT_BASE: Rgb = (0xff, 0xff, 0xff);
+const DEFAULT_SELECTED_BG: Rgb = (0x35, 0x84, 0xe4);
+
+/// Splits the arguments of a css function call at the top-level commas
+fn split_args(s: &str) -> Vec<&str> {
+    let mut args = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    args.push(s[start..].trim());
+    args
+}
+
+/// Parses a channel of `rgb()`, either 0-255 or a percentage
+fn channel(s: &str) -> Option<u8> {
+    match s.strip_suffix('%') {
+        Some(p) => Some((p.trim().parse::<f32>().ok()? * 2.55).round() as u8),
+        None => Some(s.parse::<f32>().ok()?.round() as u8),
+    }
+}
+
+fn resolve(value: &str, defines: &HashMap<String, String>, depth: usize) -> Option<Rgb> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    let value = value.trim();
+    if let Some(name) = value.strip_prefix('@') {
+        return resolve(defines.get(name)?, defines, depth + 1);
+    }
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() == 3 {
+            let doubled: String = hex.chars().flat_map(|c| [c, c]).collect();
+            return parse_hex(&doubled);
+        }
+        return parse_hex(hex);
+    }
+    let (func, args) = match value.split_once('(') {
+        Some((func, rest)) => (func.trim(), split_args(rest.strip_suffix(')')?)),
+        None => {
+            return match value {
+                "white" => Some((255, 255, 255)),
+                "black" => Some((0, 0, 0)),
+                _ => None,
+            }
+        }
+    };
+    match (func, args.as_slice()) {
+        ("rgb", [r, g, b]) | ("rgba", [r, g, b, _]) => {
+            Some((channel(r)?, channel(g)?, channel(b)?))
+        }
+        // the alpha can't be represented in FLTK's colormap
+        ("alpha", [c, _]) => resolve(c, defines, depth + 1),
+        ("shade", [c, f]) => {
+            let (r, g, b) = resolve(c, defines, depth + 1)?;
+            let f: f32 = f.parse().ok()?;
+            let scale = |v: u8| (v as f32 * f).round().min(255.0) as u8;
+            Some((scale(r), scale(g), scale(b)))
+        }
+        ("mix", [a, b, f]) => Some(mix(
+            resolve(a, defines, depth + 1)?,
+            resolve(b, defines, depth + 1)?,
+            f.parse().ok()?,
+        )),
+        ("lighter", [c]) => Some(mix(resolve(c, defines, depth + 1)?, (255, 255, 255), 0.3)),
+        ("darker", [c]) => Some(mix(resolve(c, defines, depth + 1)?, (0, 0, 0), 0.3)),
+        _ => None,
+    }
+}
+
+/// Removes `/* */` comments
+fn strip_comments(src: &str) -> String {
+    let mut out = String::new();
+    let mut rest = src;
+    while let Some(start) = rest.find("/*") {
+        out += &rest[..start];
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    out + rest
+}
+
+/// The name of the active GTK theme and whether its dark variant is requested
+pub(crate) fn current_theme_name() -> Option<(String, bool)> {
+    if let Ok(theme) = env::var("GTK_THEME") {
+        return match theme.split_once(':') {
+            Some((name, variant)) => Some((name.to_string(), variant == "dark")),
+            None => Some((theme, false)),
+        };
+    }
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    let ini = Ini::parse(&fs::read_to_string(config.join("gtk-3.0/settings.ini")).ok()?);
+    let name = ini.get("Settings", "gtk-theme-name")?.1.to_string();
+    let dark = matches!(
+        ini.get("Settings", "gtk-application-prefer-dark-theme"),
+        Some((_, v)) if v == "1" || v == "true"
+    );
+    Some((name, dark))
+}
+
+/// The directories which may contain GTK themes, in lookup order
+fn theme_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![];
+    let home = env::var_os("HOME").map(PathBuf::from);
+    if let Some(home) = &home {
+        dirs.push(home.join(".themes"));
+    }
+    match env::var_os("XDG_DATA_HOME") {
+        Some(data) => dirs.push(PathBuf::from(data).join("themes")),
+        None => {
+            if let Some(home) = &home {
+                dirs.push(home.join(".local/share/themes"));
+            }
+        }
+    }
+    let data_dirs =
+        env::var("XDG_DATA_DIRS").unwrap_or_else(|_| "/usr/local/share:/usr/share".into());
+    for dir in data_dirs.split(':').filter(|d| !d.is_empty()) {
+        dirs.push(PathBuf::from(dir).join("themes"));
+    }
+    dirs
+}
+
+impl ColorTheme {
+    /**
+    Builds a theme from the `@define-color` palette of a GTK 3 stylesheet.
+    theme_bg_color, theme_fg_color, theme_base_color (the background of inputs),
+    theme_selected_bg_color and borders (the dark end of the gray ramp) are used.
+    Colors can be written as hex, `rgb()`, `rgba()`, references to other colors (`@name`)
+    and the `alpha()`, `shade()`, `mix()`, `lighter()` and `darker()` functions.
+    Entries that are missing or can't be resolved fall back to Adwaita's colors,
+    or for borders to a ramp derived from the background.
+    theme_selected_fg_color has no counterpart since FLTK picks the text color of selections by contrast.
+    */
+    pub fn from_gtk_css(src: &str) -> ColorTheme {
+        let mut defines = HashMap::new();
+        for statement in strip_comments(src).split(';') {
+            if let Some(rest) = statement.trim().strip_prefix("@define-color") {
+                if let Some((name, value)) = rest.trim().split_once(char::is_whitespace) {
+                    defines.insert(name.to_string(), value.trim().to_string());
+                }
+            }
+        }
+        let color = |name: &str, default: Rgb| {
+            defines
+                .get(name)
+                .and_then(|v| resolve(v, &defines, 0))
+                .unwrap_or(default)
+        };
+        let bg = color("theme_bg_color", DEFAULT_BG);
+        let fg = color("theme_fg_color", DEFAULT_FG);
+        let base = color("theme_base_color", DEFAULT_BASE);
+        let selected_bg = color("theme_selected_bg_color", DEFAULT_SELECTED_BG);
+        let inactive = color("insensitive_fg_color", mix(bg, fg, 0.5));
+        let (dark, light) = ramp_ends(bg, fg);
+        let dark = defines
+            .get("borders")
+            .and_then(|v| resolve(v, &defines, 0))
+            .map_or(dark, |borders| dark_end_through(bg, borders));
+        let mut map = vec![];
+        let mut set = |index: u8, (r, g, b): Rgb| {
+            map.push(ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            })
+        };
+        set(0, fg);
+        set(7, base);
+        set(8, inactive);
+        set(15, selected_bg);
+        map.extend(gray_ramp_through(dark, bg, light));
+        ColorTheme::from(map)
+    }
+
+    /// Builds a theme from the active GTK 3 theme (`GTK_THEME` or the `gtk-theme-name` setting),
+    /// looked up in `~/.themes` and the XDG data directories. See `from_gtk_css`
+    pub fn from_current_gtk_theme() -> Option<ColorTheme> {
+        let (name, dark) = current_theme_name()?;
+        let files: &[&str] = if dark {
+            &["gtk-3.0/gtk-dark.css", "gtk-3.0/gtk.css"]
+        } else {
+            &["gtk-3.0/gtk.css"]
+        };
+        for dir in theme_dirs() {
+            for file in files {
+                if let Ok(src) = fs::read_to_string(dir.join(&name).join(file)) {
+                    return Some(ColorTheme::from_gtk_css(&src));
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{ColorMap, ColorTheme, ThemeError};
+
+const fn digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parses `#rgb` or `#rrggbb`, the # being optional. A const fn so that `cmap_hex!` can check
+/// its color at compile time
+#[doc(hidden)]
+pub const fn hex_rgb(s: &str) -> Option<(u8, u8, u8)> {
+    let s = s.as_bytes();
+    let start = if !s.is_empty() && s[0] == b'#' { 1 } else { 0 };
+    let len = s.len() - start;
+    if len != 3 && len != 6 {
+        return None;
+    }
+    let mut v = [0; 6];
+    let mut i = 0;
+    while i < 6 {
+        // #rgb doubles each digit, #abc being #aabbcc
+        let src = if len == 3 { start + i / 2 } else { start + i };
+        v[i] = match digit(s[src]) {
+            Some(d) => d,
+            None => return None,
+        };
+        i += 1;
+    }
+    Some((v[0] * 16 + v[1], v[2] * 16 + v[3], v[4] * 16 + v[5]))
+}
+
+impl ColorMap {
+    /// Creates a colormap from `#rgb`, `#rrggbb` or `rrggbb`, failing with
+    /// `ThemeError::InvalidHex` (its line being 0) naming the string if it isn't a hex color
+    pub fn from_hex(index: u8, hex: &str) -> Result<ColorMap, ThemeError> {
+        match hex_rgb(hex.trim()) {
+            Some((r, g, b)) => Ok(ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            }),
+            None => Err(ThemeError::InvalidHex {
+                line: 0,
+                key: index.to_string(),
+                value: hex.to_string(),
+            }),
+        }
+    }
+}
+
+impl ColorTheme {
+    /**
+    Creates a theme from (index, hex color) pairs, see `ColorMap::from_hex`:
+    ```rust,no_run
+    use fltk_theme::ColorTheme;
+    let theme = ColorTheme::from_hex_pairs(&[(0, "#d8dee9"), (49, "#2e3440"), (15, "#5e81ac")]);
+    theme.unwrap().apply();
+    ```
+    */
+    pub fn from_hex_pairs(pairs: &[(u8, &str)]) -> Result<ColorTheme, ThemeError> {
+        pairs
+            .iter()
+            .map(|(index, hex)| ColorMap::from_hex(*index, hex))
+            .collect::<Result<Vec<_>, _>>()
+            .map(ColorTheme::from)
+    }
+}
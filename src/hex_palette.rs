@@ -0,0 +1,117 @@
+use crate::colors::relative_luminance;
+use crate::ramp::gray_ramp_through;
+use crate::theme_file::{mix, parse_hex, ramp_ends};
+use crate::{ColorMap, ColorTheme, ThemeError};
+
+/// Chooses which colors of a palette (by their 0-based position) become the background,
+/// foreground and accent of a theme built by `ColorTheme::from_hex_palette`.
+/// Unset entries are picked automatically
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PaletteMapping {
+    /// Defaults to the darkest color
+    pub background: Option<usize>,
+    /// Defaults to the lightest color
+    pub foreground: Option<usize>,
+    /// Defaults to the most saturated color (in HSL)
+    pub accent: Option<usize>,
+}
+
+/// HSL saturation from 0 to 1
+fn saturation((r, g, b): (u8, u8, u8)) -> f32 {
+    let max = r.max(g).max(b) as f32 / 255.0;
+    let min = r.min(g).min(b) as f32 / 255.0;
+    let l = (max + min) / 2.0;
+    if max == min {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+    }
+}
+
+/// Returns the position of the color maximizing key, the first one on ties
+fn position_by<F: Fn((u8, u8, u8)) -> f32>(colors: &[(u8, u8, u8)], key: F) -> usize {
+    let mut best = 0;
+    for (i, col) in colors.iter().enumerate() {
+        if key(*col) > key(colors[best]) {
+            best = i;
+        }
+    }
+    best
+}
+
+impl ColorTheme {
+    /// The index of the first of the palette colors which aren't the background, foreground
+    /// or accent in themes built by `from_hex_palette`. It's FLTK's first free color index,
+    /// and up to 16 colors are kept
+    pub const PALETTE_BASE_INDEX: u8 = 16;
+
+    /**
+    Builds a theme from a plain hex palette, one `rrggbb` (or `#rrggbb`) color per line,
+    as exported by [Lospec](https://lospec.com/palette-list). The gray ramp is built between the
+    background and the foreground, and the accent becomes the selection color. The remaining colors
+    are stored in palette order starting at `PALETTE_BASE_INDEX`.
+    */
+    pub fn from_hex_palette(src: &str, options: PaletteMapping) -> Result<ColorTheme, ThemeError> {
+        let mut colors = vec![];
+        for (i, line) in src.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            colors.push(parse_hex(line).ok_or_else(|| ThemeError::InvalidHex {
+                line: i + 1,
+                key: format!("color {}", colors.len()),
+                value: line.to_string(),
+            })?);
+        }
+        if colors.len() < 2 {
+            return Err(ThemeError::MissingColor {
+                key: "foreground".to_string(),
+            });
+        }
+        let pick = |choice: Option<usize>, name: &str, auto: usize| match choice {
+            Some(i) if i < colors.len() => Ok(i),
+            Some(i) => Err(ThemeError::MissingColor {
+                key: format!("{} (color {})", name, i),
+            }),
+            None => Ok(auto),
+        };
+        let bg_pos = pick(
+            options.background,
+            "background",
+            position_by(&colors, |c| -relative_luminance(c)),
+        )?;
+        let fg_pos = pick(
+            options.foreground,
+            "foreground",
+            position_by(&colors, relative_luminance),
+        )?;
+        let accent_pos = pick(options.accent, "accent", position_by(&colors, saturation))?;
+        let (bg, fg, accent) = (colors[bg_pos], colors[fg_pos], colors[accent_pos]);
+        let mut map = vec![];
+        let mut set = |index: u8, (r, g, b): (u8, u8, u8)| {
+            map.push(ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            })
+        };
+        set(0, fg);
+        set(7, mix(bg, fg, 0.06));
+        set(8, mix(bg, fg, 0.5));
+        set(15, accent);
+        let rest = colors
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| ![bg_pos, fg_pos, accent_pos].contains(i))
+            .take(16);
+        for (n, (_, col)) in rest.enumerate() {
+            set(ColorTheme::PALETTE_BASE_INDEX + n as u8, *col);
+        }
+        let (dark, light) = ramp_ends(bg, fg);
+        map.extend(gray_ramp_through(dark, bg, light));
+        Ok(ColorTheme::from(map))
+    }
+}
@@ -0,0 +1,121 @@
+use fltk::{app, enums::Color, prelude::*, widget::Widget};
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// How often the widget below the mouse is checked, in seconds
+const POLL_INTERVAL: f64 = 0.05;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static HOVERED: RefCell<Option<Widget>> = const { RefCell::new(None) };
+    /// Whether the current scheme draws hovered widgets differently
+    static STYLED: Cell<bool> = const { Cell::new(false) };
+    /// Whether the timer checking the widget below the mouse is running
+    static POLLING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Makes the schemes supporting it (Fluent and Gleam) highlight the widget below the mouse.
+/// FLTK doesn't tell frame drawing about hovering, so the widget below the mouse is checked
+/// every 50 ms and redrawn whenever it changes. The checking only runs while such a scheme is
+/// applied and a window is shown, starting again when a highlighted frame is drawn.
+/// Should be called from the main thread.
+pub fn enable_hover_highlight() {
+    ENABLED.store(true, Ordering::Relaxed);
+    start_polling();
+}
+
+/// Stops highlighting hovered widgets
+pub fn disable_hover_highlight() {
+    ENABLED.store(false, Ordering::Relaxed);
+    clear_hovered();
+}
+
+/// Records whether the scheme being applied draws hovered widgets differently
+pub(crate) fn set_styled(styled: bool) {
+    STYLED.with(|s| s.set(styled));
+    if styled {
+        start_polling();
+    } else {
+        clear_hovered();
+    }
+}
+
+/// Starts checking the widget below the mouse unless it's already checked or nothing would be
+/// highlighted
+fn start_polling() {
+    if !ENABLED.load(Ordering::Relaxed) || !STYLED.with(Cell::get) {
+        return;
+    }
+    if POLLING.with(|polling| polling.replace(true)) {
+        return;
+    }
+    app::add_timeout3(POLL_INTERVAL, |handle| {
+        let shown = app::windows().is_some_and(|windows| windows.iter().any(|w| w.shown()));
+        if !ENABLED.load(Ordering::Relaxed) || !STYLED.with(Cell::get) || !shown {
+            POLLING.with(|polling| polling.set(false));
+            clear_hovered();
+            return;
+        }
+        update_hovered();
+        app::repeat_timeout3(POLL_INTERVAL, handle);
+    });
+}
+
+fn clear_hovered() {
+    HOVERED.with(|hovered| {
+        if let Some(mut old) = hovered.borrow_mut().take() {
+            if !old.was_deleted() {
+                old.redraw();
+            }
+        }
+    });
+}
+
+fn update_hovered() {
+    let below = app::belowmouse::<Widget>().filter(|w| w.active_r());
+    HOVERED.with(|hovered| {
+        let mut hovered = hovered.borrow_mut();
+        let changed = match (&*hovered, &below) {
+            (Some(old), Some(new)) => old.as_widget_ptr() != new.as_widget_ptr(),
+            (None, None) => false,
+            _ => true,
+        };
+        if !changed {
+            return;
+        }
+        if let Some(mut old) = hovered.take() {
+            if !old.was_deleted() {
+                old.redraw();
+            }
+        }
+        if let Some(mut new) = below {
+            new.redraw();
+            *hovered = Some(new);
+        }
+    });
+}
+
+/// Checks whether the frame being drawn belongs to the hovered widget
+pub(crate) fn is_hovered(x: i32, y: i32, w: i32, h: i32) -> bool {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return false;
+    }
+    // a highlighted frame is being drawn, e.g. in a window shown again
+    start_polling();
+    HOVERED.with(|hovered| match &*hovered.borrow() {
+        Some(wid) => {
+            !wid.was_deleted() && wid.x() == x && wid.y() == y && wid.w() == w && wid.h() == h
+        }
+        None => false,
+    })
+}
+
+/// Returns a slightly lightened c if the frame being drawn belongs to the hovered widget
+pub(crate) fn hover_color(x: i32, y: i32, w: i32, h: i32, c: Color) -> Color {
+    if is_hovered(x, y, w, h) {
+        Color::color_average(Color::White, c, 0.15)
+    } else {
+        c
+    }
+}
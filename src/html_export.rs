@@ -0,0 +1,117 @@
+use crate::colors::is_dark;
+use crate::{ColorMap, ColorTheme, ThemeError};
+use std::{fmt::Write, fs, path::Path};
+
+/// The names of FLTK's color constants, as in `fltk::enums::Color`
+const CONSTANTS: &[(u8, &str)] = &[
+    (0, "Foreground"),
+    (7, "Background2"),
+    (8, "Inactive"),
+    (15, "Selection"),
+    (32, "Gray0"),
+    (39, "Dark3"),
+    (45, "Dark2"),
+    (47, "Dark1"),
+    (49, "Background"),
+    (50, "Light1"),
+    (52, "Light2"),
+    (54, "Light3"),
+    (56, "Black"),
+    (60, "DarkGreen"),
+    (63, "Green"),
+    (72, "DarkRed"),
+    (76, "DarkYellow"),
+    (88, "Red"),
+    (95, "Yellow"),
+    (136, "DarkBlue"),
+    (140, "DarkCyan"),
+    (152, "DarkMagenta"),
+    (216, "Blue"),
+    (223, "Cyan"),
+    (248, "Magenta"),
+    (255, "White"),
+];
+
+const STYLE: &str = "body { font-family: sans-serif; background: #eee; }
+.grid { display: flex; flex-wrap: wrap; gap: 8px; }
+.swatch { width: 132px; border: 1px solid #888; background: #fff; font-size: 12px; }
+.color { height: 56px; display: flex; justify-content: space-around; align-items: center; font-size: 18px; }
+.label { padding: 4px; }
+.ramp { display: flex; }
+.ramp div { flex: 1; height: 48px; text-align: center; font-size: 11px; }";
+
+/// Describes an index: its constant, and its letter in the gray ramp (FL_GRAY_RAMP + 'A'...)
+fn describe(index: u8) -> String {
+    let mut names: Vec<String> = CONSTANTS
+        .iter()
+        .filter(|(i, _)| *i == index)
+        .map(|(_, name)| name.to_string())
+        .collect();
+    if (32..=55).contains(&index) {
+        names.push(format!("gray ramp '{}'", (b'A' + index - 32) as char));
+    }
+    names.join(", ")
+}
+
+fn hex(c: &ColorMap) -> String {
+    format!("#{:02x}{:02x}{:02x}", c.r, c.g, c.b)
+}
+
+impl ColorTheme {
+    /// Builds a self-contained HTML page showing a swatch per index of the theme, with its hex
+    /// value, FLTK constant and text samples, followed by the theme's gray ramp (indices 32 to 55)
+    pub fn to_html(&self) -> String {
+        // the last colormap of an index is the one apply() leaves in place
+        let mut colors: Vec<Option<&ColorMap>> = vec![None; 256];
+        for elem in &self.colors {
+            colors[elem.index as usize] = Some(elem);
+        }
+        let mut s = String::new();
+        s += "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Color theme</title>\n";
+        writeln!(s, "<style>\n{}\n</style>\n</head>\n<body>", STYLE).unwrap();
+        s += "<h2>Colors</h2>\n<div class=\"grid\">\n";
+        for c in colors.iter().flatten() {
+            let hex = hex(c);
+            writeln!(
+                s,
+                "<div class=\"swatch\"><div class=\"color\" style=\"background: {hex}\">\
+                 <span style=\"color: #fff\">Aa</span><span style=\"color: #000\">Aa</span></div>\
+                 <div class=\"label\"><b>{}</b> {hex}<br>{}</div></div>",
+                c.index,
+                describe(c.index),
+                hex = hex
+            )
+            .unwrap();
+        }
+        s += "</div>\n";
+        let ramp: Vec<_> = colors[32..=55].iter().flatten().collect();
+        if !ramp.is_empty() {
+            s += "<h2>Gray ramp</h2>\n<div class=\"ramp\">\n";
+            for c in ramp {
+                let color = if is_dark((c.r, c.g, c.b)) {
+                    "#fff"
+                } else {
+                    "#000"
+                };
+                writeln!(
+                    s,
+                    "<div style=\"background: {}; color: {}\">{}<br>{}</div>",
+                    hex(c),
+                    color,
+                    (b'A' + c.index - 32) as char,
+                    c.index
+                )
+                .unwrap();
+            }
+            s += "</div>\n";
+        }
+        s += "</body>\n</html>\n";
+        s
+    }
+
+    /// Writes the page built by `to_html` to a file, to preview a theme in a browser
+    pub fn export_html(&self, path: &Path) -> Result<(), ThemeError> {
+        fs::write(path, self.to_html())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::ColorTheme;
+
+/// Whether a theme is meant to be dark or light, e.g. for a badge in a theme picker
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Variant {
+    /// Light text on a dark background
+    Dark,
+    /// Dark text on a light background, as FLTK's default colors
+    #[default]
+    Light,
+}
+
+impl Variant {
+    /// The variant of a theme, from its background (see `ColorTheme::is_dark`)
+    pub fn of(theme: &ColorTheme) -> Variant {
+        if theme.is_dark() {
+            Variant::Dark
+        } else {
+            Variant::Light
+        }
+    }
+
+    /// Parses `dark` or `light`, ignoring case
+    pub fn parse(s: &str) -> Option<Variant> {
+        if s.eq_ignore_ascii_case("dark") {
+            Some(Variant::Dark)
+        } else if s.eq_ignore_ascii_case("light") {
+            Some(Variant::Light)
+        } else {
+            None
+        }
+    }
+
+    /// `dark` or `light`, as read by `parse`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Variant::Dark => "dark",
+            Variant::Light => "light",
+        }
+    }
+}
+
+/// What a theme is, for labelling it in a theme picker. Read from the `[meta]` table of theme
+/// files (see `ColorTheme::from_toml`) and given to the built-in themes by
+/// `color_themes::builtin_themes`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThemeInfo {
+    /// The name to show, e.g. `Solarized Dark`
+    pub name: String,
+    /// Who made the theme
+    pub author: Option<String>,
+    /// Whether the theme is dark or light
+    pub variant: Variant,
+    /// The version of the theme, in whatever form its author uses
+    pub version: Option<String>,
+    /// A short description
+    pub description: Option<String>,
+}
+
+impl ThemeInfo {
+    /// Creates the info of a theme with only a name and a variant
+    pub fn new(name: &str, variant: Variant) -> ThemeInfo {
+        ThemeInfo {
+            name: name.to_string(),
+            variant,
+            ..Default::default()
+        }
+    }
+}
+
+impl ColorTheme {
+    /**
+    Attaches info to the theme, replacing any it had:
+    ```rust,no_run
+    use fltk_theme::{color_themes, ColorTheme, ThemeInfo, Variant};
+    let theme = ColorTheme::new(color_themes::NORD_THEME)
+        .with_info(ThemeInfo::new("Company Nord", Variant::Dark));
+    assert_eq!(theme.info().unwrap().name, "Company Nord");
+    ```
+    */
+    pub fn with_info(mut self, info: ThemeInfo) -> ColorTheme {
+        self.info = Some(info);
+        self
+    }
+
+    /// The info of the theme, None for themes built from colors alone
+    pub fn info(&self) -> Option<&ThemeInfo> {
+        self.info.as_ref()
+    }
+}
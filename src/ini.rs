@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+/// A minimal INI reader for the `[Section]` and `Key=Value` files used by desktop themes
+pub(crate) struct Ini {
+    sections: HashMap<String, HashMap<String, (usize, String)>>,
+}
+
+impl Ini {
+    /// Parses s, skipping comments (`;` and `#`) and lines that aren't entries.
+    /// Section and key names are matched case-insensitively
+    pub(crate) fn parse(s: &str) -> Ini {
+        let mut sections: HashMap<String, HashMap<String, (usize, String)>> = HashMap::new();
+        let mut current = String::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                current = section.trim().to_lowercase();
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                sections
+                    .entry(current.clone())
+                    .or_default()
+                    .insert(key.trim().to_lowercase(), (i + 1, value.trim().to_string()));
+            }
+        }
+        Ini { sections }
+    }
+
+    /// Returns the line number and value of key in section
+    pub(crate) fn get(&self, section: &str, key: &str) -> Option<(usize, &str)> {
+        self.sections
+            .get(&section.to_lowercase())?
+            .get(&key.to_lowercase())
+            .map(|(line, value)| (*line, value.as_str()))
+    }
+}
@@ -0,0 +1,173 @@
+use crate::ansi::ansi_map;
+use crate::{ColorTheme, ThemeError};
+use std::{collections::HashMap, fs, path::Path};
+
+#[derive(Debug)]
+enum Token {
+    Open(String),
+    Close(String),
+    Text(String),
+}
+
+/// Splits the plist into tags and text, each with its 1-based line number.
+/// Declarations, comments and self-closing tags (`<true/>`) are dropped
+fn tokenize(s: &str) -> Result<Vec<(usize, Token)>, ThemeError> {
+    let mut tokens = vec![];
+    let mut rest = s;
+    let mut line = 1;
+    while let Some(start) = rest.find('<') {
+        let text = rest[..start].trim();
+        if !text.is_empty() {
+            tokens.push((line, Token::Text(text.to_string())));
+        }
+        line += rest[..start].matches('\n').count();
+        let (terminator, skip) = if rest[start..].starts_with("<!--") {
+            ("-->", true)
+        } else {
+            (">", false)
+        };
+        let end = rest[start..]
+            .find(terminator)
+            .ok_or_else(|| ThemeError::Syntax {
+                line,
+                message: "unterminated tag".to_string(),
+            })?
+            + start;
+        let tag = &rest[start + 1..end];
+        if !skip && !tag.starts_with('?') && !tag.starts_with('!') && !tag.ends_with('/') {
+            match tag.strip_prefix('/') {
+                Some(name) => tokens.push((line, Token::Close(name.trim().to_string()))),
+                None => {
+                    let name = tag.split_whitespace().next().unwrap_or_default();
+                    tokens.push((line, Token::Open(name.to_string())))
+                }
+            }
+        }
+        line += tag.matches('\n').count();
+        rest = &rest[end + terminator.len()..];
+    }
+    Ok(tokens)
+}
+
+/// Reads `<name>text</name>` at tokens[i], returning the text and the index after it
+fn element<'a>(tokens: &'a [(usize, Token)], i: usize, name: &str) -> Option<(&'a str, usize)> {
+    match tokens.get(i..i + 3)? {
+        [(_, Token::Open(o)), (_, Token::Text(t)), (_, Token::Close(c))]
+            if o == name && c == name =>
+        {
+            Some((t, i + 3))
+        }
+        [(_, Token::Open(o)), (_, Token::Close(c)), _] if o == name && c == name => {
+            Some(("", i + 2))
+        }
+        _ => None,
+    }
+}
+
+/// Reads a color dict starting after its `<dict>`, returning the components and the index after
+/// its `</dict>`
+fn color_dict(
+    tokens: &[(usize, Token)],
+    mut i: usize,
+) -> (HashMap<String, (usize, String)>, usize) {
+    let mut components = HashMap::new();
+    while i < tokens.len() {
+        if let (_, Token::Close(c)) = &tokens[i] {
+            if c == "dict" {
+                return (components, i + 1);
+            }
+        }
+        if let Some((key, next)) = element(tokens, i, "key") {
+            let value = ["real", "integer", "string"]
+                .iter()
+                .find_map(|name| element(tokens, next, name));
+            if let Some((value, after)) = value {
+                components.insert(key.to_string(), (tokens[next].0, value.to_string()));
+                i = after;
+                continue;
+            }
+            i = next;
+            continue;
+        }
+        i += 1;
+    }
+    (components, i)
+}
+
+fn to_rgb(
+    slot: &str,
+    line: usize,
+    components: &HashMap<String, (usize, String)>,
+) -> Result<(u8, u8, u8), ThemeError> {
+    // the Color Space and Alpha Component keys are ignored
+    let component = |name: &str| -> Result<u8, ThemeError> {
+        let (line, value) = components.get(name).ok_or_else(|| ThemeError::Syntax {
+            line,
+            message: format!("`{}` has no {}", slot, name),
+        })?;
+        match value.parse::<f32>() {
+            Ok(v) if v.is_finite() => Ok((v.clamp(0.0, 1.0) * 255.0).round() as u8),
+            _ => Err(ThemeError::InvalidColor {
+                line: *line,
+                key: format!("{} {}", slot, name),
+                value: value.clone(),
+            }),
+        }
+    };
+    Ok((
+        component("Red Component")?,
+        component("Green Component")?,
+        component("Blue Component")?,
+    ))
+}
+
+impl ColorTheme {
+    /**
+    Builds a theme from an iTerm2 `.itermcolors` file.
+    The Background and Foreground colors are required, the gray ramp is interpolated between them,
+    the Selection Color gives the selection color and the Ansi 0 to 15 colors are mapped as in
+    `from_ansi16`.
+    */
+    pub fn from_iterm(path: &Path) -> Result<ColorTheme, ThemeError> {
+        ColorTheme::from_iterm_str(&fs::read_to_string(path)?)
+    }
+
+    /// Builds a theme from the content of an `.itermcolors` file, see `from_iterm`
+    pub fn from_iterm_str(s: &str) -> Result<ColorTheme, ThemeError> {
+        let tokens = tokenize(s)?;
+        let mut slots = HashMap::new();
+        // the slots are the keys of the top level dict, each followed by a color dict
+        let mut i = 0;
+        while i < tokens.len() {
+            match element(&tokens, i, "key") {
+                Some((slot, next)) => {
+                    if let Some((line, Token::Open(o))) = tokens.get(next) {
+                        if o == "dict" {
+                            let (components, after) = color_dict(&tokens, next + 1);
+                            slots.insert(slot.to_string(), to_rgb(slot, *line, &components)?);
+                            i = after;
+                            continue;
+                        }
+                    }
+                    i = next;
+                }
+                None => i += 1,
+            }
+        }
+        let required = |slot: &str| {
+            slots
+                .get(slot)
+                .copied()
+                .ok_or_else(|| ThemeError::MissingColor {
+                    key: slot.to_string(),
+                })
+        };
+        let bg = required("Background Color")?;
+        let fg = required("Foreground Color")?;
+        let ansi: Vec<_> = (0..16)
+            .map(|n| slots.get(&format!("Ansi {} Color", n)).copied())
+            .collect();
+        let selection = slots.get("Selection Color").copied();
+        Ok(ColorTheme::from(ansi_map(bg, fg, &ansi, selection)))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::ini::Ini;
+use crate::ramp::gray_ramp_through;
+use crate::theme_file::{mix, ramp_ends};
+use crate::{ColorMap, ColorTheme, ThemeError};
+use std::{fs, path::Path};
+
+/// Parses KDE's `r,g,b` (or `r,g,b,a`, the alpha being ignored) triples
+fn parse_triple(s: &str) -> Option<(u8, u8, u8)> {
+    let parts: Vec<_> = s.split(',').map(|p| p.trim().parse::<u8>()).collect();
+    match parts.as_slice() {
+        [Ok(r), Ok(g), Ok(b)] | [Ok(r), Ok(g), Ok(b), Ok(_)] => Some((*r, *g, *b)),
+        _ => None,
+    }
+}
+
+/// Looks up a color, None if it's not defined, an error if it's malformed
+fn color(ini: &Ini, group: &str, key: &str) -> Result<Option<(u8, u8, u8)>, ThemeError> {
+    match ini.get(group, key) {
+        Some((line, value)) => match parse_triple(value) {
+            Some(col) => Ok(Some(col)),
+            None => Err(ThemeError::InvalidColor {
+                line,
+                key: format!("[{}] {}", group, key),
+                value: value.to_string(),
+            }),
+        },
+        None => Ok(None),
+    }
+}
+
+fn required(ini: &Ini, group: &str, key: &str) -> Result<(u8, u8, u8), ThemeError> {
+    color(ini, group, key)?.ok_or_else(|| ThemeError::MissingColor {
+        key: format!("[{}] {}", group, key),
+    })
+}
+
+impl ColorTheme {
+    /**
+    Builds a theme from a KDE color scheme (`.colors`) file, such as the ones found under
+    `/usr/share/color-schemes` and `~/.local/share/color-schemes`.
+    The window colors give the background, foreground and the gray ramp, the button background
+    the light end of the ramp used by button faces, the view background the background of inputs,
+    and the selection background the selection color.
+    */
+    pub fn from_kde_colors(path: &Path) -> Result<ColorTheme, ThemeError> {
+        ColorTheme::from_kde_colors_str(&fs::read_to_string(path)?)
+    }
+
+    /// Builds a theme from the content of a KDE color scheme, see `from_kde_colors`
+    pub fn from_kde_colors_str(s: &str) -> Result<ColorTheme, ThemeError> {
+        let ini = Ini::parse(s);
+        let bg = required(&ini, "Colors:Window", "BackgroundNormal")?;
+        let fg = required(&ini, "Colors:Window", "ForegroundNormal")?;
+        let button = color(&ini, "Colors:Button", "BackgroundNormal")?.unwrap_or(bg);
+        let view = color(&ini, "Colors:View", "BackgroundNormal")?.unwrap_or(bg);
+        let inactive =
+            color(&ini, "Colors:Window", "ForegroundInactive")?.unwrap_or_else(|| mix(bg, fg, 0.5));
+        let selection = match color(&ini, "Colors:Selection", "BackgroundNormal")? {
+            Some(col) => Some(col),
+            None => color(&ini, "Colors:View", "DecorationFocus")?,
+        };
+        let mut map = vec![];
+        let mut set = |index: u8, col: Option<(u8, u8, u8)>| {
+            if let Some((r, g, b)) = col {
+                map.push(ColorMap {
+                    index,
+                    r,
+                    g,
+                    b,
+                    a: 255,
+                });
+            }
+        };
+        set(0, Some(fg));
+        set(7, Some(view));
+        set(8, Some(inactive));
+        set(15, selection);
+        set(63, color(&ini, "Colors:View", "ForegroundPositive")?);
+        set(88, color(&ini, "Colors:View", "ForegroundNegative")?);
+        set(95, color(&ini, "Colors:View", "ForegroundNeutral")?);
+        set(216, color(&ini, "Colors:View", "ForegroundLink")?);
+        let (dark, _) = ramp_ends(bg, fg);
+        let (_, light) = ramp_ends(button, fg);
+        map.extend(gray_ramp_through(dark, bg, light));
+        Ok(ColorTheme::from(map))
+    }
+}
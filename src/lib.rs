@@ -71,18 +71,137 @@ fn main() {
 use fltk::{app, enums::Color};
 pub mod color_themes;
 pub mod colors;
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub mod portal;
 pub mod widget_schemes;
 pub mod widget_themes;
 
-/// Color map struct. (index, r, g, b)
-#[derive(Default, Clone, Debug)]
+mod adjust;
+mod animate;
+mod ansi;
+mod base16;
+mod builder;
+mod current;
+mod defaults;
+mod desktop_theme;
+mod diff;
+mod env_theme;
+mod error;
+mod gpl;
+mod gtk_css;
+mod hex;
+mod hex_palette;
+mod hover;
+mod html_export;
+mod info;
+mod ini;
+#[cfg(feature = "iterm")]
+mod iterm;
+mod kde;
+mod macos_appearance;
+#[cfg(feature = "json")]
+mod pywal;
+mod ramp;
+mod registry;
+mod roles;
+mod saved_theme;
+mod schedule;
+mod seeds;
+mod subtree;
+mod system_theme;
+mod theme;
+mod theme_dir;
+mod theme_file;
+mod titlebar;
+mod validate;
+mod variant;
+#[cfg(feature = "json")]
+mod vscode;
+mod watcher;
+mod window_theme;
+mod windows_theme;
+mod xresources;
+pub use builder::{ColorThemeBuilder, IntoRgb};
+pub use current::{
+    current_color_theme_name, current_widget_scheme, current_widget_theme, on_theme_applied,
+    ThemeEvent,
+};
+pub use defaults::reset_color_theme;
+pub use desktop_theme::{current_desktop_theme, DesktopTheme};
+pub use diff::{ChangedColor, ThemeDiff};
+pub use env_theme::{from_env, AppliedTheme};
+pub use error::ThemeError;
+#[doc(hidden)]
+pub use hex::hex_rgb;
+pub use hex_palette::PaletteMapping;
+pub use hover::{disable_hover_highlight, enable_hover_highlight};
+pub use info::{ThemeInfo, Variant};
+pub use macos_appearance::set_macos_appearance;
+pub use ramp::{gray_ramp, gray_ramp_curve, GRAY_RAMP_END, GRAY_RAMP_START};
+pub use registry::{RegisteredTheme, ThemeRegistry};
+pub use saved_theme::{SavedTheme, ThemeChoice};
+pub use schedule::{Schedule, ScheduleHandle, ScheduledTheme};
+pub use system_theme::{
+    on_system_theme_change, prefers_dark, selection_color_for, system_accent_color,
+    system_highlight_color, SystemThemeWatch,
+};
+pub use theme::Theme;
+pub use theme_dir::ThemeDir;
+pub use titlebar::set_dark_titlebar;
+pub use validate::ThemeIssue;
+pub use watcher::ThemeWatcher;
+pub use window_theme::WindowTheme;
+
+/// Color map struct. (index, r, g, b, a)
+/// An alpha below 255 makes `apply()` blend the color over the one currently set for the index
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorMap {
     pub index: u8,
     pub r: u8,
     pub g: u8,
     pub b: u8,
+    #[cfg_attr(feature = "serde", serde(default = "opaque"))]
+    pub a: u8,
+}
+
+#[cfg(feature = "serde")]
+fn opaque() -> u8 {
+    255
+}
+
+impl Default for ColorMap {
+    fn default() -> ColorMap {
+        ColorMap {
+            index: 0,
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 255,
+        }
+    }
 }
 
+impl ColorMap {
+    /// The color to set for the index: the colormap itself if opaque,
+    /// otherwise blended over `current`
+    pub(crate) fn over(&self, current: (u8, u8, u8)) -> (u8, u8, u8) {
+        if self.a == 255 {
+            return (self.r, self.g, self.b);
+        }
+        let a = self.a as u32;
+        let blend =
+            |top: u8, bottom: u8| ((top as u32 * a + bottom as u32 * (255 - a) + 127) / 255) as u8;
+        (
+            blend(self.r, current.0),
+            blend(self.g, current.1),
+            blend(self.b, current.2),
+        )
+    }
+}
+
+/// Creates a `ColorMap` from an index and either r, g and b or an rgb triple, e.g. one of
+/// `colors::tailwind`
 #[macro_export]
 macro_rules! cmap {
     ($i:tt, $r:tt, $g:tt, $b:tt) => {
@@ -91,32 +210,244 @@ macro_rules! cmap {
             r: $r,
             g: $g,
             b: $b,
+            a: 255,
+        }
+    };
+    ($i:tt, $rgb:expr) => {{
+        let rgb: (u8, u8, u8) = $rgb;
+        ColorMap {
+            index: $i,
+            r: rgb.0,
+            g: rgb.1,
+            b: rgb.2,
+            a: 255,
+        }
+    }};
+}
+
+/**
+Like `cmap!`, with an alpha: `apply()` blends the color over the one currently set for the index,
+e.g. a 15% black layer darkening whatever theme is active:
+```rust,no_run
+use fltk_theme::{cmap_a, ColorMap, ColorTheme};
+const SHADE: &[ColorMap] = &[cmap_a!(7, 0, 0, 0, 38), cmap_a!(49, 0, 0, 0, 38)];
+ColorTheme::new(SHADE).apply();
+```
+*/
+#[macro_export]
+macro_rules! cmap_a {
+    ($i:tt, $r:tt, $g:tt, $b:tt, $a:tt) => {
+        ColorMap {
+            index: $i,
+            r: $r,
+            g: $g,
+            b: $b,
+            a: $a,
         }
     };
 }
 
-/// A theme is just a Vec of colormaps
+/**
+Like `cmap!`, with the color given as a `#rgb`, `#rrggbb` or `rrggbb` literal, which is checked at
+compile time:
+```rust
+use fltk_theme::{cmap_hex, ColorMap};
+const NORD: &[ColorMap] = &[cmap_hex!(0, "#d8dee9"), cmap_hex!(49, "#2e3440"), cmap_hex!(8, "#4c5")];
+assert_eq!(NORD[1], ColorMap { index: 49, r: 0x2e, g: 0x34, b: 0x40, a: 255 });
+```
+*/
+#[macro_export]
+macro_rules! cmap_hex {
+    ($i:tt, $hex:expr) => {{
+        const RGB: (u8, u8, u8) = match $crate::hex_rgb($hex) {
+            ::core::option::Option::Some(rgb) => rgb,
+            ::core::option::Option::None => panic!("invalid hex color, expected #rgb or #rrggbb"),
+        };
+        ColorMap {
+            index: $i,
+            r: RGB.0,
+            g: RGB.1,
+            b: RGB.2,
+            a: 255,
+        }
+    }};
+}
+
+/**
+A theme is just a Vec of colormaps, with optional info naming it (see `with_info` and `info`).
+It serializes as its colormaps alone.
+
+The colormaps are the named field `colors`, which replaced the tuple field `.0` of earlier
+versions, and a theme is built with `new`, `from` or `collect` rather than `ColorTheme(vec)`:
+```rust,no_run
+use fltk_theme::{color_themes, ColorMap, ColorTheme};
+let mut theme = ColorTheme::new(color_themes::NORD_THEME);
+theme.colors.retain(|elem| elem.index != 15);
+let inverted: ColorTheme = theme
+    .colors
+    .iter()
+    .map(|elem| ColorMap { r: 255 - elem.r, g: 255 - elem.g, b: 255 - elem.b, ..*elem })
+    .collect();
+```
+*/
 #[derive(Debug, Clone)]
-pub struct ColorTheme(pub Vec<ColorMap>);
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<ColorMap>", into = "Vec<ColorMap>")
+)]
+pub struct ColorTheme {
+    /// The colormaps, applied in order
+    pub colors: Vec<ColorMap>,
+    info: Option<ThemeInfo>,
+}
+
+impl From<Vec<ColorMap>> for ColorTheme {
+    fn from(map: Vec<ColorMap>) -> ColorTheme {
+        ColorTheme {
+            colors: map,
+            info: None,
+        }
+    }
+}
+
+impl std::iter::FromIterator<ColorMap> for ColorTheme {
+    fn from_iter<I: IntoIterator<Item = ColorMap>>(iter: I) -> ColorTheme {
+        ColorTheme::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl From<ColorTheme> for Vec<ColorMap> {
+    fn from(theme: ColorTheme) -> Vec<ColorMap> {
+        theme.colors
+    }
+}
 
 impl ColorTheme {
     /// Load from a color map
     pub fn from_colormap(map: &[ColorMap]) -> ColorTheme {
-        ColorTheme(map.to_vec())
+        ColorTheme::from(map.to_vec())
     }
 
     /// Load from a color map
     pub fn new(map: &[ColorMap]) -> ColorTheme {
-        ColorTheme(map.to_vec())
+        ColorTheme::from(map.to_vec())
     }
 
-    /// apply() the theme
+    /// apply() the theme. Translucent colormaps are blended over the colors currently set
     pub fn apply(&self) {
-        for elem in &self.0 {
-            app::set_color(Color::by_index(elem.index), elem.r, elem.g, elem.b);
+        animate::cancel();
+        defaults::record_defaults();
+        for elem in &self.colors {
+            let color = Color::by_index(elem.index);
+            if elem.a == 255 {
+                app::set_color(color, elem.r, elem.g, elem.b);
+            } else {
+                let (r, g, b) = elem.over(app::get_color(color));
+                app::set_color(color, r, g, b);
+            }
         }
+        current::set_color_theme(self);
         app::redraw();
     }
+
+    /**
+    Applies the theme like `apply()`, returning the colors it replaced: the values of the indices
+    it sets, as they were right before. Applying the returned theme reverts the change, e.g. to
+    preview a theme in a preferences dialog and revert on Cancel:
+    ```rust,no_run
+    use fltk_theme::{color_themes, ColorTheme};
+    let undo = ColorTheme::new(color_themes::DARK_THEME).apply_with_undo();
+    // Cancel
+    undo.apply();
+    ```
+    */
+    pub fn apply_with_undo(&self) -> ColorTheme {
+        animate::cancel();
+        defaults::record_defaults();
+        let mut undo = Vec::with_capacity(self.colors.len());
+        for elem in &self.colors {
+            let color = Color::by_index(elem.index);
+            let (r, g, b) = color.to_rgb();
+            undo.push(ColorMap {
+                index: elem.index,
+                r,
+                g,
+                b,
+                a: 255,
+            });
+            let (r, g, b) = elem.over((r, g, b));
+            app::set_color(color, r, g, b);
+        }
+        current::set_color_theme(self);
+        app::redraw();
+        // an index set twice gets its value from before the first time
+        undo.reverse();
+        ColorTheme::from(undo)
+    }
+
+    /// Snapshots the 256 colors currently set in FLTK, applying the snapshot restores them
+    pub fn from_app() -> ColorTheme {
+        (0..=255)
+            .map(|index| {
+                let (r, g, b) = Color::by_index(index).to_rgb();
+                ColorMap {
+                    index,
+                    r,
+                    g,
+                    b,
+                    a: 255,
+                }
+            })
+            .collect()
+    }
+
+    /// Snapshots the colors currently set in FLTK which differ from baseline,
+    /// e.g. a `from_app()` taken at startup. Indices the baseline doesn't set are all kept
+    pub fn from_app_diff(baseline: &ColorTheme) -> ColorTheme {
+        let current = ColorTheme::from_app();
+        current
+            .colors
+            .into_iter()
+            .filter(|elem| {
+                // the last colormap of an index is the one apply() leaves in place
+                let base = baseline.colors.iter().rev().find(|b| b.index == elem.index);
+                !matches!(base, Some(b) if (b.r, b.g, b.b) == (elem.r, elem.g, elem.b))
+            })
+            .collect()
+    }
+
+    /**
+    Returns base with overlay on top: the colormaps of overlay replace those of base with the same
+    index, keeping their place, and the indices base doesn't set are appended in the order of
+    overlay. An index set twice within one theme takes its last value (the one `apply()` leaves in
+    place), at the place of its first colormap, `validate()` catching such mistakes:
+    ```rust,no_run
+    use fltk_theme::{color_themes, ColorTheme};
+    let overlay = ColorTheme::from_toml(r##"
+    [colors]
+    selection = "#d08770"
+    red = "#bf616a"
+    "##).unwrap();
+    ColorTheme::merged(&ColorTheme::new(color_themes::NORD_THEME), &overlay).apply();
+    ```
+    */
+    pub fn merged(base: &ColorTheme, overlay: &ColorTheme) -> ColorTheme {
+        let mut theme = base.clone();
+        theme.merge_from(overlay);
+        theme
+    }
+
+    /// Merges overlay on top of the theme in place, see `merged`
+    pub fn merge_from(&mut self, overlay: &ColorTheme) {
+        let entries = std::mem::take(&mut self.colors);
+        for elem in entries.iter().chain(&overlay.colors) {
+            match self.colors.iter_mut().find(|e| e.index == elem.index) {
+                Some(e) => *e = elem.clone(),
+                None => self.colors.push(elem.clone()),
+            }
+        }
+    }
 }
 
 pub(crate) fn activated_color(c: Color) -> Color {
@@ -128,7 +459,8 @@ pub(crate) fn activated_color(c: Color) -> Color {
 }
 
 /// Lists supported themes
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ThemeType {
     /// Windows classic
     Classic,
@@ -146,22 +478,140 @@ pub enum ThemeType {
     Dark,
     /// High Contrast
     HighContrast,
+    /// Windows 11
+    Win11,
+    /// Modern MacOS
+    Aqua,
+    /// GNOME Adwaita
+    Adwaita,
+    /// GNOME Adwaita dark
+    AdwaitaDark,
+    /// KDE Breeze
+    Breeze,
+    /// KDE Breeze dark
+    BreezeDark,
+    /// Ubuntu Yaru
+    Yaru,
+    /// Ubuntu Yaru dark
+    YaruDark,
+    /// Motif / CDE
+    Motif,
+    /// Haiku / BeOS
+    Haiku,
+    /// Windows XP Luna
+    Luna,
+    /// High Contrast Black
+    HighContrastDark,
+    /// elementary OS
+    Elementary,
+    /// Windows 10/11 dark mode
+    FluentDark,
+}
+
+impl ThemeType {
+    /// All the widget themes, e.g. to fill a menu
+    pub const ALL: &[ThemeType] = &[
+        ThemeType::Classic,
+        ThemeType::Aero,
+        ThemeType::Metro,
+        ThemeType::AquaClassic,
+        ThemeType::Greybird,
+        ThemeType::Blue,
+        ThemeType::Dark,
+        ThemeType::HighContrast,
+        ThemeType::Win11,
+        ThemeType::Aqua,
+        ThemeType::Adwaita,
+        ThemeType::AdwaitaDark,
+        ThemeType::Breeze,
+        ThemeType::BreezeDark,
+        ThemeType::Yaru,
+        ThemeType::YaruDark,
+        ThemeType::Motif,
+        ThemeType::Haiku,
+        ThemeType::Luna,
+        ThemeType::HighContrastDark,
+        ThemeType::Elementary,
+        ThemeType::FluentDark,
+    ];
 }
 
 /// A widget theme is a scheme + a set of default colors
 #[derive(Debug, Clone, Copy)]
 pub struct WidgetTheme {
     theme: ThemeType,
+    accent: Option<(u8, u8, u8)>,
+    native_appearance: bool,
+    system_colors: bool,
 }
 
 impl WidgetTheme {
     /// Create a Widget theme object
     pub fn new(theme: ThemeType) -> Self {
-        Self { theme }
+        Self {
+            theme,
+            accent: None,
+            native_appearance: false,
+            system_colors: false,
+        }
     }
 
-    /// Apply the widget theme
+    /// Uses an accent color (e.g. `system_accent_color()`) for the selection color (see
+    /// `selection_color_for`) and, in the Win11 and AquaClassic themes, the default buttons
+    pub fn with_accent(mut self, accent: (u8, u8, u8)) -> Self {
+        self.accent = Some(accent);
+        self
+    }
+
+    /// Also switches the native parts of the app on macOS (menus, title bars, dialogs) to the dark
+    /// or light appearance, after the theme's background (see `set_macos_appearance`)
+    pub fn with_native_appearance(mut self, native: bool) -> Self {
+        self.native_appearance = native;
+        self
+    }
+
+    /// Also applies the live system colors on Windows (see
+    /// `ColorTheme::from_windows_system_colors`), meant for `ThemeType::Classic`.
+    /// Does nothing on other platforms
+    pub fn with_system_colors(mut self, system: bool) -> Self {
+        self.system_colors = system;
+        self
+    }
+
+    /**
+    Apply the widget theme. The frame drawing and scheme left by a previously applied theme are
+    reset to FLTK's first, and the colors it set are put back as they were before it, so that
+    switching themes at runtime gives the same result as applying the new theme alone:
+    ```rust
+    use fltk_theme::{ColorTheme, ThemeType, WidgetTheme};
+    for &theme in ThemeType::ALL {
+        WidgetTheme::new(theme).apply();
+    }
+    let switched = ColorTheme::from_app();
+    WidgetTheme::reset();
+    WidgetTheme::new(*ThemeType::ALL.last().unwrap()).apply();
+    assert_eq!(ColorTheme::from_app().colors, switched.colors);
+    ```
+    The other colors are left as they are, so a `ColorTheme` applied before the widget theme
+    keeps the indices the widget theme doesn't set. Colors a `ColorTheme` sets after the widget
+    theme are left in place when switching to another one
+    */
     pub fn apply(&self) {
+        defaults::record_defaults();
+        defaults::restore_drawing();
+        defaults::undo_widget_colors();
+        defaults::record_widget_colors(|| self.set_colors());
+        current::set_widget_theme(Some(self.theme));
+        current::notify(ThemeEvent::WidgetTheme(self.theme));
+        app::redraw();
+    }
+
+    /// Sets the drawing and colors of the theme, see `apply`
+    fn set_colors(&self) {
+        match self.accent {
+            Some((r, g, b)) => widget_schemes::set_accent_color(r, g, b),
+            None => widget_schemes::reset_accent_color(),
+        }
         match self.theme {
             ThemeType::Classic => widget_themes::classic::use_classic_theme(),
             ThemeType::Aero => widget_themes::aero::use_aero_theme(),
@@ -171,23 +621,76 @@ impl WidgetTheme {
             ThemeType::Blue => widget_themes::blue::use_blue_theme(),
             ThemeType::Metro => widget_themes::metro::use_metro_theme(),
             ThemeType::Greybird => widget_themes::greybird::use_greybird_theme(),
+            ThemeType::Win11 => widget_themes::win11::use_win11_theme(),
+            ThemeType::Aqua => widget_themes::aqua::use_aqua_theme(),
+            ThemeType::Adwaita => widget_themes::adwaita::use_adwaita_theme(),
+            ThemeType::AdwaitaDark => widget_themes::adwaita::use_adwaita_dark_theme(),
+            ThemeType::Breeze => widget_themes::breeze::use_breeze_theme(),
+            ThemeType::BreezeDark => widget_themes::breeze::use_breeze_dark_theme(),
+            ThemeType::Yaru => widget_themes::yaru::use_yaru_theme(),
+            ThemeType::YaruDark => widget_themes::yaru::use_yaru_dark_theme(),
+            ThemeType::Motif => widget_themes::motif::use_motif_theme(),
+            ThemeType::Haiku => widget_themes::haiku::use_haiku_theme(),
+            ThemeType::Luna => widget_themes::luna::use_luna_theme(),
+            ThemeType::HighContrastDark => {
+                widget_themes::high_contrast::use_high_contrast_dark_theme()
+            }
+            ThemeType::Elementary => widget_themes::elementary::use_elementary_theme(),
+            ThemeType::FluentDark => widget_themes::fluent_dark::use_fluent_dark_theme(),
+        }
+        if self.system_colors {
+            current::quiet(windows_theme::apply_system_colors);
+        }
+        if let Some(accent) = self.accent {
+            let (r, g, b) = selection_color_for(accent);
+            app::set_color(Color::Selection, r, g, b);
+        }
+        if self.native_appearance {
+            set_macos_appearance(colors::is_dark(Color::Background.to_rgb()));
         }
     }
+
+    /**
+    Undoes the widget themes applied so far: FLTK's scheme, frame drawing, colors and tooltip
+    settings are restored to what they were before the crate first changed them, so that widgets
+    draw as if no theme had been applied. Colors applied by a `ColorTheme` are reset too
+    */
+    pub fn reset() {
+        defaults::restore_drawing();
+        widget_schemes::reset_accent_color();
+        defaults::undo_widget_colors();
+        current::quiet(reset_color_theme);
+        current::set_widget_theme(None);
+        current::notify(ThemeEvent::Reset);
+    }
 }
 
 /// Lists supported schemes
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SchemeType {
     /// A scheme mimicking modern Aqua
     Aqua,
+    /// Windows 95 bevels derived from the widget color
+    Classic95,
     /// Taken from the NTK fork
     Clean,
     /// Taken from the NTK fork
     Crystal,
+    /// Cards with a soft drop shadow drawn inside the widget bounds
+    Elevated,
+    /// No bevels, flat fills with thin borders
+    Flat,
     /// Windows 10
     Fluent,
     /// Taken from the NTK fork, a modification of the FLTK Gleam scheme
     Gleam,
+    /// iOS style pill buttons and grouped lists
+    Ios,
+    /// Material Design
+    Material,
+    /// Soft UI with blended light and dark shadows
+    Neumorphic,
     /**
     Draws the following FrameTypes using scalable vector graphics:
     - RoundedFrame
@@ -196,31 +699,124 @@ pub enum SchemeType {
     - OvalBox
     - OvalFrame
     - OFlatFrame
+    - ShadowBox, RShadowBox and OShadowBox
+    - DiamondUpBox and DiamondDownBox
+    - The Plastic and Gtk FrameTypes
+
+    Most of the `widget_themes::OS_*` FrameTypes are aliases of the Diamond, Plastic and Gtk
+    ones, so this scheme redraws widgets using them, replacing the look the widget theme gave
+    them. A FrameType whose image can't be created is drawn as FLTK draws it by default
     */
     SvgBased,
 }
 
+impl SchemeType {
+    /// All the widget schemes, e.g. to fill a menu
+    pub const ALL: &[SchemeType] = &[
+        SchemeType::Aqua,
+        SchemeType::Classic95,
+        SchemeType::Clean,
+        SchemeType::Crystal,
+        SchemeType::Elevated,
+        SchemeType::Flat,
+        SchemeType::Fluent,
+        SchemeType::Gleam,
+        SchemeType::Ios,
+        SchemeType::Material,
+        SchemeType::Neumorphic,
+        SchemeType::SvgBased,
+    ];
+}
+
 /// A widget scheme sets the style of drawing a widget without interfering with coloring
 #[derive(Debug, Clone, Copy)]
 pub struct WidgetScheme {
     scheme: SchemeType,
+    corner_radius: Option<u8>,
+    intensity: Option<f32>,
+    accent: Option<(u8, u8, u8)>,
 }
 
 impl WidgetScheme {
     /// Create a Widget theme object
     pub fn new(scheme: SchemeType) -> Self {
-        Self { scheme }
+        Self {
+            scheme,
+            corner_radius: None,
+            intensity: None,
+            accent: None,
+        }
+    }
+
+    /// Overrides the corner radius of the scheme's rounded frame types, 0 gives square corners
+    pub fn with_corner_radius(mut self, radius: u8) -> Self {
+        self.corner_radius = Some(radius);
+        self
+    }
+
+    /// Sets the gradient intensity of the Gleam and Crystal schemes, from 0.0 (flat) to 1.0 (default)
+    pub fn with_intensity(mut self, intensity: f32) -> Self {
+        self.intensity = Some(intensity);
+        self
+    }
+
+    /// Uses an accent color (e.g. `system_accent_color()`) for the selection color (see
+    /// `selection_color_for`), the default buttons of the Fluent and Aqua schemes and the focus
+    /// underline of Fluent's text fields
+    pub fn with_accent(mut self, accent: (u8, u8, u8)) -> Self {
+        self.accent = Some(accent);
+        self
     }
 
     /// Apply the widget theme
     pub fn apply(&self) {
+        defaults::record_defaults();
+        if let Some(radius) = self.corner_radius {
+            widget_schemes::set_corner_radius(radius);
+        }
+        match self.accent {
+            Some((r, g, b)) => {
+                widget_schemes::set_accent_color(r, g, b);
+                let (r, g, b) = selection_color_for((r, g, b));
+                app::set_color(Color::Selection, r, g, b);
+            }
+            None => widget_schemes::reset_accent_color(),
+        }
+        if let Some(intensity) = self.intensity {
+            match self.scheme {
+                SchemeType::Crystal => widget_schemes::crystal::set_gradient_intensity(intensity),
+                SchemeType::Gleam => widget_schemes::gleam::set_gradient_intensity(intensity),
+                _ => (),
+            }
+        }
+        // set again by the schemes which highlight hovered widgets
+        hover::set_styled(false);
         match self.scheme {
             SchemeType::Aqua => widget_schemes::aqua::use_aqua_scheme(),
+            SchemeType::Classic95 => widget_schemes::classic95::use_classic95_scheme(),
             SchemeType::Clean => widget_schemes::clean::use_clean_scheme(),
             SchemeType::Crystal => widget_schemes::crystal::use_crystal_scheme(),
+            SchemeType::Elevated => widget_schemes::elevated::use_elevated_scheme(),
+            SchemeType::Flat => widget_schemes::flat::use_flat_scheme(),
             SchemeType::Fluent => widget_schemes::fluent::use_fluent_scheme(),
             SchemeType::Gleam => widget_schemes::gleam::use_gleam_scheme(),
+            SchemeType::Ios => widget_schemes::ios::use_ios_scheme(),
+            SchemeType::Material => widget_schemes::material::use_material_scheme(),
+            SchemeType::Neumorphic => widget_schemes::neumorphic::use_neumorphic_scheme(),
             SchemeType::SvgBased => widget_schemes::svg_based::use_svg_based_scheme(),
         }
+        current::set_widget_scheme(Some(self.scheme));
+        current::notify(ThemeEvent::WidgetScheme(self.scheme));
+    }
+
+    /// Undoes the widget schemes applied so far, restoring FLTK's scheme and frame drawing to what
+    /// they were before the crate first changed them. Colors are left as they are, see
+    /// `reset_color_theme`
+    pub fn reset() {
+        defaults::restore_drawing();
+        widget_schemes::reset_accent_color();
+        widget_schemes::reset_corner_radius();
+        current::set_widget_scheme(None);
+        current::notify(ThemeEvent::Reset);
     }
 }
@@ -0,0 +1,63 @@
+#[cfg(target_os = "macos")]
+mod appkit {
+    use std::os::raw::{c_char, c_void};
+
+    pub type Id = *mut c_void;
+
+    #[link(name = "objc")]
+    extern "C" {
+        pub fn objc_getClass(name: *const c_char) -> Id;
+        pub fn sel_registerName(name: *const c_char) -> Id;
+        pub fn objc_msgSend();
+    }
+
+    #[link(name = "AppKit", kind = "framework")]
+    extern "C" {
+        pub static NSAppearanceNameAqua: Id;
+        pub static NSAppearanceNameDarkAqua: Id;
+    }
+
+    pub fn sel(name: &[u8]) -> Id {
+        unsafe { sel_registerName(name.as_ptr() as *const c_char) }
+    }
+}
+
+/**
+Sets the appearance of the native parts of the app (menu bar menus, title bars, dialogs) on macOS,
+dark (`NSAppearanceNameDarkAqua`) or light (`NSAppearanceNameAqua`), for macOS 10.14 and later.
+Can be called before or after windows are shown, from the main thread.
+Does nothing on other platforms
+*/
+pub fn set_macos_appearance(dark: bool) {
+    #[cfg(target_os = "macos")]
+    unsafe {
+        use appkit::*;
+        use std::mem::transmute;
+        use std::os::raw::c_char;
+        // objc_msgSend has to be called through a pointer of the right signature
+        let msg = objc_msgSend as unsafe extern "C" fn();
+        let send: unsafe extern "C" fn(Id, Id) -> Id = transmute(msg);
+        let send_id: unsafe extern "C" fn(Id, Id, Id) -> Id = transmute(msg);
+        let responds: unsafe extern "C" fn(Id, Id, Id) -> i8 = transmute(msg);
+        let app_class = objc_getClass(b"NSApplication\0".as_ptr() as *const c_char);
+        let appearance_class = objc_getClass(b"NSAppearance\0".as_ptr() as *const c_char);
+        if app_class.is_null() || appearance_class.is_null() {
+            return;
+        }
+        // creates the application object if FLTK hasn't yet
+        let app = send(app_class, sel(b"sharedApplication\0"));
+        let set_appearance = sel(b"setAppearance:\0");
+        if app.is_null() || responds(app, sel(b"respondsToSelector:\0"), set_appearance) == 0 {
+            return;
+        }
+        let name = if dark {
+            NSAppearanceNameDarkAqua
+        } else {
+            NSAppearanceNameAqua
+        };
+        let appearance = send_id(appearance_class, sel(b"appearanceNamed:\0"), name);
+        send_id(app, set_appearance, appearance);
+    }
+    #[cfg(not(target_os = "macos"))]
+    let _ = dark;
+}
@@ -0,0 +1,89 @@
+/*!
+Reads the appearance settings of the freedesktop settings portal (the `org.freedesktop.appearance`
+namespace), which GNOME, KDE and other desktops implement and which is the only way to read them
+from inside a Flatpak sandbox. The portal is called through the `gdbus` tool of GLib, so every
+function returns None if `gdbus` or the portal is missing, or if the desktop doesn't set the key.
+```rust,no_run
+use fltk_theme::{portal, ThemeType, WidgetTheme};
+let theme = match portal::read_color_scheme() {
+    Some(portal::ColorScheme::PreferDark) => ThemeType::AdwaitaDark,
+    _ => ThemeType::Adwaita,
+};
+let theme = WidgetTheme::new(theme);
+match portal::read_accent_color() {
+    Some(accent) => theme.with_accent(accent).apply(),
+    None => theme.apply(),
+}
+```
+*/
+
+use crate::system_theme::output;
+
+/// The `color-scheme` preference of the desktop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    /// No preference, usually meaning light
+    NoPreference,
+    /// Dark
+    PreferDark,
+    /// Light
+    PreferLight,
+}
+
+/// Reads a key of `org.freedesktop.appearance`, returning the numbers of the reply.
+/// gdbus prints e.g. `(<<uint32 1>>,)` or `(<<(0.21, 0.52, 0.89)>>,)`
+fn read(key: &str) -> Option<Vec<f64>> {
+    let out = output(
+        "gdbus",
+        &[
+            "call",
+            "--session",
+            "--dest",
+            "org.freedesktop.portal.Desktop",
+            "--object-path",
+            "/org/freedesktop/portal/desktop",
+            "--method",
+            "org.freedesktop.portal.Settings.Read",
+            "org.freedesktop.appearance",
+            key,
+        ],
+    )?;
+    let numbers = out
+        .replace("uint32", "")
+        .split(|c: char| matches!(c, '(' | ')' | '<' | '>' | ',') || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<f64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    if numbers.is_empty() {
+        None
+    } else {
+        Some(numbers)
+    }
+}
+
+/// Reads the `color-scheme` setting
+pub fn read_color_scheme() -> Option<ColorScheme> {
+    match read("color-scheme")?.first()? {
+        v if *v == 1.0 => Some(ColorScheme::PreferDark),
+        v if *v == 2.0 => Some(ColorScheme::PreferLight),
+        _ => Some(ColorScheme::NoPreference),
+    }
+}
+
+/// Reads the `contrast` setting, true if the user asked for higher contrast
+/// (e.g. to pick `ThemeType::HighContrast`)
+pub fn read_contrast() -> Option<bool> {
+    read("contrast")?.first().map(|v| *v == 1.0)
+}
+
+/// Reads the `accent-color` setting, None if the desktop doesn't set an accent.
+/// It can be passed to `WidgetScheme::with_accent` or `WidgetTheme::with_accent`
+pub fn read_accent_color() -> Option<(u8, u8, u8)> {
+    let rgb = read("accent-color")?;
+    // components out of 0..1 mean no accent
+    if rgb.len() != 3 || rgb.iter().any(|c| !(0.0..=1.0).contains(c)) {
+        return None;
+    }
+    let c = |i: usize| (rgb[i] * 255.0).round() as u8;
+    Some((c(0), c(1), c(2)))
+}
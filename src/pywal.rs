@@ -0,0 +1,37 @@
+use crate::ansi::ansi_map;
+use crate::theme_file::parse_hex;
+use crate::ColorTheme;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+impl ColorTheme {
+    /**
+    Builds a theme from the palette pywal generates into `~/.cache/wal/colors.json`
+    (`$XDG_CACHE_HOME/wal` if set), returning None if the file is absent or unreadable.
+    The gray ramp is interpolated between `special.background` and `special.foreground`,
+    color4 (falling back to the cursor color) gives the selection color and color0 to color15
+    are mapped as in `from_ansi16`.
+    */
+    pub fn from_pywal() -> Option<ColorTheme> {
+        let cache = env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+        ColorTheme::from_pywal_file(&cache.join("wal/colors.json"))
+    }
+
+    /// Builds a theme from a pywal colors.json file, see `from_pywal`
+    pub fn from_pywal_file(path: &Path) -> Option<ColorTheme> {
+        let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+        let hex =
+            |section: &str, key: &str| json.get(section)?.get(key)?.as_str().and_then(parse_hex);
+        let bg = hex("special", "background")?;
+        let fg = hex("special", "foreground")?;
+        let ansi: Vec<_> = (0..16)
+            .map(|n| hex("colors", &format!("color{}", n)))
+            .collect();
+        let selection = ansi[4].or_else(|| hex("special", "cursor"));
+        Some(ColorTheme::from(ansi_map(bg, fg, &ansi, selection)))
+    }
+}
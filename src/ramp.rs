@@ -0,0 +1,100 @@
+use crate::seeds::{from_oklab_in_gamut, mix_oklab, to_oklab};
+use crate::ColorMap;
+
+/// The first index of FLTK's gray ramp
+pub const GRAY_RAMP_START: u8 = 32;
+
+/// The last index of FLTK's gray ramp
+pub const GRAY_RAMP_END: u8 = 55;
+
+/// The background, where the importers anchor their ramps
+const BACKGROUND: u8 = 49;
+
+/// FL_DARK3, the darkest shadow of bevels
+const DARK3: u8 = 39;
+
+/**
+Fills FLTK's gray ramp, going from dark to light with even steps of perceived lightness.
+Same as `gray_ramp_curve(dark, light, 1.0)`.
+
+The ramp is the 24 indices from 32 to 55, which boxes and frames address as the letters 'A' to
+'X' (`Color::gray_ramp`), e.g. `"AAWWMMTT"` for the up box of the base scheme. Some of them have
+names of their own:
+- 39 (`Color::Dark3`, 'H'), 45 (`Color::Dark2`, 'N') and 47 (`Color::Dark1`, 'P'): the shadows
+  of bevels and the borders
+- 49 (`Color::Background`, 'R'): the background of windows and buttons
+- 50 (`Color::Light1`, 'S'), 52 (`Color::Light2`, 'U') and 54 (`Color::Light3`, 'W'): the
+  highlights of bevels
+
+The background sits 17 of the 23 steps up the ramp, so a theme wanting a given background should
+set index 49 after the ramp:
+```rust,no_run
+use fltk_theme::{cmap_hex, gray_ramp, ColorMap, ColorTheme};
+let mut theme = ColorTheme::from(gray_ramp((0x11, 0x11, 0x1b), (0xcd, 0xd6, 0xf4)));
+theme.colors.push(cmap_hex!(49, "#1e1e2e"));
+theme.apply();
+```
+*/
+pub fn gray_ramp(dark: (u8, u8, u8), light: (u8, u8, u8)) -> Vec<ColorMap> {
+    gray_ramp_curve(dark, light, 1.0)
+}
+
+/// Like `gray_ramp`, with the steps following `t.powf(gamma)`: a gamma above 1 crowds the ramp
+/// toward dark, one below 1 toward light, which keeps the bevels of low-contrast themes subtle.
+/// A gamma which isn't a positive number counts as 1
+pub fn gray_ramp_curve(dark: (u8, u8, u8), light: (u8, u8, u8), gamma: f32) -> Vec<ColorMap> {
+    let gamma = if gamma > 0.0 && gamma.is_finite() {
+        gamma
+    } else {
+        1.0
+    };
+    let steps = (GRAY_RAMP_END - GRAY_RAMP_START) as f32;
+    (GRAY_RAMP_START..=GRAY_RAMP_END)
+        .map(|index| {
+            let t = ((index - GRAY_RAMP_START) as f32 / steps).powf(gamma);
+            let (r, g, b) = mix_oklab(dark, light, t);
+            ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            }
+        })
+        .collect()
+}
+
+/// Like `gray_ramp`, but going through bg at the background (49): from dark to bg, then on to
+/// light, so that themes giving a background keep the shadows below it and the highlights above
+pub(crate) fn gray_ramp_through(
+    dark: (u8, u8, u8),
+    bg: (u8, u8, u8),
+    light: (u8, u8, u8),
+) -> Vec<ColorMap> {
+    let below = (BACKGROUND - GRAY_RAMP_START) as f32;
+    let above = (GRAY_RAMP_END - BACKGROUND) as f32;
+    (GRAY_RAMP_START..=GRAY_RAMP_END)
+        .map(|index| {
+            let (r, g, b) = if index <= BACKGROUND {
+                mix_oklab(dark, bg, (index - GRAY_RAMP_START) as f32 / below)
+            } else {
+                mix_oklab(bg, light, (index - BACKGROUND) as f32 / above)
+            };
+            ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            }
+        })
+        .collect()
+}
+
+/// The dark end of a `gray_ramp_through` bg which puts dark3 on FL_DARK3 (39)
+pub(crate) fn dark_end_through(bg: (u8, u8, u8), dark3: (u8, u8, u8)) -> (u8, u8, u8) {
+    let t = (BACKGROUND - GRAY_RAMP_START) as f32 / (BACKGROUND - DARK3) as f32;
+    let (bg, dark3) = (to_oklab(bg), to_oklab(dark3));
+    let [l, a, b] = [0, 1, 2].map(|i| bg[i] + (dark3[i] - bg[i]) * t);
+    from_oklab_in_gamut([l.max(0.0), a, b])
+}
@@ -0,0 +1,113 @@
+use crate::{color_themes, defaults, ColorTheme, Theme, ThemeError};
+use std::sync::Mutex;
+
+/// A theme registered in `ThemeRegistry`
+#[derive(Debug, Clone)]
+pub enum RegisteredTheme {
+    /// A color theme
+    Colors(ColorTheme),
+    /// A full theme
+    Theme(Theme),
+}
+
+impl RegisteredTheme {
+    fn apply(&self) {
+        match self {
+            RegisteredTheme::Colors(colors) => colors.apply(),
+            RegisteredTheme::Theme(theme) => theme.apply(),
+        }
+    }
+}
+
+impl From<ColorTheme> for RegisteredTheme {
+    fn from(colors: ColorTheme) -> Self {
+        RegisteredTheme::Colors(colors)
+    }
+}
+
+impl From<Theme> for RegisteredTheme {
+    fn from(theme: Theme) -> Self {
+        RegisteredTheme::Theme(theme)
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref THEMES: Mutex<Vec<(String, RegisteredTheme)>> = Mutex::new(
+        color_themes::builtin_themes()
+            .iter()
+            .map(|(info, colors)| {
+                (info.name.clone(), ColorTheme::new(colors).with_info(info.clone()).into())
+            })
+            .collect()
+    );
+}
+
+/**
+Themes by name, shared by the whole app, e.g. for plugins to contribute themes to a menu.
+The built-in color themes are registered from the start under names like `Solarized Dark` for
+`color_themes::SOLARIZED_DARK_THEME`. Themes can be registered from any thread, but applying
+has to be done from the main thread:
+```rust,no_run
+use fltk_theme::{ThemeRegistry, Theme, ThemeType};
+ThemeRegistry::register("My Dark", Theme::new().with_widget_theme(ThemeType::Dark));
+for name in ThemeRegistry::names() {
+    println!("{}", name);
+}
+ThemeRegistry::apply_by_name("Solarized Dark").unwrap();
+```
+*/
+pub struct ThemeRegistry;
+
+impl ThemeRegistry {
+    /// Registers a color theme or a full theme, replacing the one registered under that name
+    pub fn register<T: Into<RegisteredTheme>>(name: &str, theme: T) {
+        let theme = theme.into();
+        let mut themes = THEMES.lock().unwrap();
+        match themes.iter_mut().find(|(n, _)| n == name) {
+            Some((_, old)) => *old = theme,
+            None => themes.push((name.to_string(), theme)),
+        }
+    }
+
+    /// Removes a theme, returning it
+    pub fn unregister(name: &str) -> Option<RegisteredTheme> {
+        let mut themes = THEMES.lock().unwrap();
+        let pos = themes.iter().position(|(n, _)| n == name)?;
+        Some(themes.remove(pos).1)
+    }
+
+    /// The registered names in registration order, the built-in themes first
+    pub fn names() -> Vec<String> {
+        THEMES
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Returns the theme registered under name
+    pub fn get(name: &str) -> Option<RegisteredTheme> {
+        THEMES
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, theme)| theme.clone())
+    }
+
+    /// Applies the theme registered under name. Fails with `ThemeError::NotMainThread` when
+    /// called from a thread other than the one which applied the first theme (the main one
+    /// before any was applied), since FLTK's colors and frame types can only be changed from
+    /// the UI thread
+    pub fn apply_by_name(name: &str) -> Result<(), ThemeError> {
+        if !defaults::on_ui_thread() {
+            return Err(ThemeError::NotMainThread);
+        }
+        let theme = ThemeRegistry::get(name).ok_or_else(|| ThemeError::UnknownTheme {
+            name: name.to_string(),
+        })?;
+        theme.apply();
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use crate::colors::is_dark;
+use crate::theme_file::mix;
+use crate::ColorTheme;
+
+type Rgb = (u8, u8, u8);
+
+/// The indices FLTK draws on others, as (drawn, drawn on). The text of selections isn't an index
+/// and is paired with the selection (15) through `selection_text`
+pub(crate) const PAIRINGS: &[(u8, u8)] = &[
+    // labels on windows and buttons
+    (0, 49),
+    // text in inputs, browsers and menus
+    (0, 7),
+    // labels of deactivated widgets
+    (8, 49),
+    // the outer edge of boxes, 'A' in the strings of `Color::gray_ramp`
+    (32, 49),
+    // FL_DARK3, the border of thin boxes and frames ('H')
+    (39, 49),
+];
+
+/// The lightness (0 to 255) the legacy `fl_contrast` of FLTK compares, not a perceptual measure
+/// (see `colors::relative_luminance` for that), only used to predict what FLTK draws
+fn fl_lightness((r, g, b): Rgb) -> u32 {
+    (r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000
+}
+
+/// The text FLTK draws on a selection, as its (legacy) `fl_contrast` picks it: the foreground
+/// when the two differ enough in lightness, otherwise black or white
+pub(crate) fn selection_text(fg: Rgb, selection: Rgb, black: Rgb, white: Rgb) -> Rgb {
+    if fl_lightness(fg).abs_diff(fl_lightness(selection)) > 99 {
+        fg
+    } else if fl_lightness(selection) > 127 {
+        black
+    } else {
+        white
+    }
+}
+
+impl ColorTheme {
+    /**
+    The color of the background (49), or the entry of the gray ramp (32 to 55) nearest to it if
+    the theme doesn't set it. E.g. to draw custom widgets in the colors of a theme:
+    ```rust,no_run
+    use fltk_theme::{color_themes, ColorTheme};
+    let theme = ColorTheme::new(color_themes::NORD_THEME);
+    let (r, g, b) = theme.background().unwrap_or((192, 192, 192));
+    fltk::draw::set_draw_color(fltk::enums::Color::from_rgb(r, g, b));
+    ```
+    */
+    pub fn background(&self) -> Option<(u8, u8, u8)> {
+        (0..=17).find_map(|d| {
+            self.value(49 - d)
+                .or_else(|| if d <= 6 { self.value(49 + d) } else { None })
+        })
+    }
+
+    /// The color of text (0). If the theme doesn't set it, the end of the gray ramp contrasting
+    /// most with the background: its darkest entry (from 32) for a light theme and its lightest
+    /// one (from 55) for a dark theme
+    pub fn foreground(&self) -> Option<(u8, u8, u8)> {
+        self.value(0).or_else(|| {
+            if self.is_dark() {
+                (50..=55).rev().find_map(|i| self.value(i))
+            } else {
+                (32..49).find_map(|i| self.value(i))
+            }
+        })
+    }
+
+    /// The background of selections (15)
+    pub fn selection_background(&self) -> Option<(u8, u8, u8)> {
+        self.value(15)
+    }
+
+    /// The color FLTK draws the text of selections in, which the theme can't set directly: the
+    /// foreground when it differs enough in lightness from the selection, otherwise FL_BLACK (56)
+    /// or FL_WHITE (255)
+    pub fn selection_foreground(&self) -> Option<(u8, u8, u8)> {
+        let selection = self.selection_background()?;
+        Some(selection_text(
+            self.foreground()?,
+            selection,
+            self.value(56).unwrap_or((0, 0, 0)),
+            self.value(255).unwrap_or((255, 255, 255)),
+        ))
+    }
+
+    /// The color of deactivated widgets (8), halfway between the foreground and the background
+    /// if the theme doesn't set it
+    pub fn inactive(&self) -> Option<(u8, u8, u8)> {
+        self.value(8).or_else(|| {
+            let bg = self.background()?;
+            Some(mix(self.foreground()?, bg, 0.5))
+        })
+    }
+
+    /// Whether the background of the theme is dark, i.e. white text would contrast more with it
+    /// than black text. False for themes without a background, FLTK's default one being light
+    pub fn is_dark(&self) -> bool {
+        self.background().is_some_and(is_dark)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::theme_dir::load_file;
+use crate::{
+    color_themes, ColorTheme, SchemeType, ThemeError, ThemeType, WidgetScheme, WidgetTheme,
+};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The platform's config directory: `%APPDATA%` on Windows, `~/Library/Application Support`
+/// on macOS and `$XDG_CONFIG_HOME` (`~/.config` if unset) elsewhere
+pub(crate) fn config_dir() -> Option<PathBuf> {
+    let home = || env::var_os("HOME").map(PathBuf::from);
+    if cfg!(target_os = "windows") {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home().map(|home| home.join("Library/Application Support"))
+    } else {
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home().map(|home| home.join(".config")))
+    }
+}
+
+/// A theme picked by the user, as stored by `SavedTheme`
+#[derive(Debug, Clone)]
+pub enum ThemeChoice {
+    /// A widget theme
+    Widget(ThemeType),
+    /// A widget scheme
+    Scheme(SchemeType),
+    /// A built-in color theme, by its name in `color_themes` (e.g. `"NORD_THEME"`)
+    Color(String),
+    /// A theme file, in any format `ThemeDir` recognizes
+    File(PathBuf),
+}
+
+impl ThemeChoice {
+    /// Applies the choice, failing if a theme file can't be loaded
+    pub fn apply(&self) -> Result<(), ThemeError> {
+        match self {
+            ThemeChoice::Widget(theme) => WidgetTheme::new(*theme).apply(),
+            ThemeChoice::Scheme(scheme) => WidgetScheme::new(*scheme).apply(),
+            ThemeChoice::Color(name) => ColorTheme::new(
+                color_themes::by_name(name)
+                    .ok_or_else(|| ThemeError::UnknownTheme { name: name.clone() })?,
+            )
+            .apply(),
+            ThemeChoice::File(path) => load_file(path)?.apply(),
+        }
+        Ok(())
+    }
+
+    fn to_line(&self) -> String {
+        match self {
+            ThemeChoice::Widget(theme) => format!("widget = {:?}", theme),
+            ThemeChoice::Scheme(scheme) => format!("scheme = {:?}", scheme),
+            ThemeChoice::Color(name) => format!("color = {}", name),
+            ThemeChoice::File(path) => format!("file = {}", path.display()),
+        }
+    }
+
+    /// Parses a line written by `to_line`, None if what it refers to no longer exists
+    fn from_line(line: &str) -> Option<ThemeChoice> {
+        let (key, value) = line.split_once('=')?;
+        let value = value.trim();
+        match key.trim() {
+            "widget" => ThemeType::ALL
+                .iter()
+                .find(|t| format!("{:?}", t) == value)
+                .map(|t| ThemeChoice::Widget(*t)),
+            "scheme" => SchemeType::ALL
+                .iter()
+                .find(|s| format!("{:?}", s) == value)
+                .map(|s| ThemeChoice::Scheme(*s)),
+            "color" => color_themes::by_name(value).map(|_| ThemeChoice::Color(value.to_string())),
+            "file" => Some(Path::new(value))
+                .filter(|path| path.is_file())
+                .map(|path| ThemeChoice::File(path.to_path_buf())),
+            _ => None,
+        }
+    }
+}
+
+/**
+Remembers the theme picked by the user across restarts, in `<config dir>/<app_name>/theme.conf`
+where the config dir is `%APPDATA%` on Windows, `~/Library/Application Support` on macOS and
+`~/.config` elsewhere:
+```rust,no_run
+use fltk_theme::{SavedTheme, ThemeChoice, ThemeType};
+let saved = SavedTheme::new("myapp");
+saved.restore().unwrap_or(ThemeChoice::Widget(ThemeType::Dark)).apply().ok();
+// when the user picks a theme
+saved.store(&ThemeChoice::Widget(ThemeType::Aqua)).ok();
+```
+*/
+#[derive(Debug, Clone)]
+pub struct SavedTheme {
+    path: Option<PathBuf>,
+}
+
+impl SavedTheme {
+    /// Creates the store of the given app
+    pub fn new(app_name: &str) -> SavedTheme {
+        SavedTheme {
+            path: config_dir().map(|dir| dir.join(app_name).join("theme.conf")),
+        }
+    }
+
+    /// The file the choice is stored in, None if the platform's config dir is unknown
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Stores the choice, creating the app's config directory if needed
+    pub fn store(&self, choice: &ThemeChoice) -> Result<(), ThemeError> {
+        let path = self.path.as_ref().ok_or_else(|| {
+            ThemeError::Io(io::Error::new(
+                io::ErrorKind::NotFound,
+                "no config directory",
+            ))
+        })?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, choice.to_line() + "\n")?;
+        Ok(())
+    }
+
+    /// Returns the stored choice, None if nothing was stored or if it refers to a theme that's
+    /// gone, such as a deleted theme file
+    pub fn restore(&self) -> Option<ThemeChoice> {
+        let s = fs::read_to_string(self.path.as_ref()?).ok()?;
+        s.lines().find_map(ThemeChoice::from_line)
+    }
+
+    /// Forgets the stored choice
+    pub fn clear(&self) -> Result<(), ThemeError> {
+        match self.path.as_ref().map(fs::remove_file) {
+            Some(Err(e)) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::system_theme::output;
+use crate::Theme;
+use fltk::app;
+use std::{
+    cell::Cell,
+    f64::consts::PI,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The longest wait between two checks, so that clock and time zone changes are caught
+const MAX_WAIT: f64 = 600.0;
+
+const DAY: f64 = 86400.0;
+
+/// When `ScheduledTheme` switches between light and dark
+#[derive(Debug, Clone, Copy)]
+pub enum Schedule {
+    /// Fixed local times, as (hour, minute)
+    Fixed {
+        /// When the light theme starts
+        sunrise: (u8, u8),
+        /// When the dark theme starts
+        sunset: (u8, u8),
+    },
+    /// The sunrise and sunset at a place, computed in-crate
+    SunriseSunset {
+        /// Latitude in degrees, north positive
+        lat: f64,
+        /// Longitude in degrees, east positive
+        lon: f64,
+    },
+}
+
+/// The offset of local time from UTC in seconds, 0 if it can't be determined
+fn local_offset() -> f64 {
+    if cfg!(target_os = "windows") {
+        output(
+            "powershell",
+            &[
+                "-NoProfile",
+                "-Command",
+                "[int][TimeZoneInfo]::Local.GetUtcOffset([DateTime]::Now).TotalSeconds",
+            ],
+        )
+        .and_then(|s| s.trim().parse::<f64>().ok())
+        .unwrap_or(0.0)
+    } else {
+        // e.g. `+0200` or `-0530`
+        let parse = |s: String| -> Option<f64> {
+            let s = s.trim();
+            let sign = if s.starts_with('-') { -1.0 } else { 1.0 };
+            let digits = s.get(1..5)?;
+            let hours: f64 = digits.get(..2)?.parse().ok()?;
+            let minutes: f64 = digits.get(2..)?.parse().ok()?;
+            Some(sign * (hours * 3600.0 + minutes * 60.0))
+        };
+        output("date", &["+%z"]).and_then(parse).unwrap_or(0.0)
+    }
+}
+
+/// Sunrise and sunset (as unix times) of the day holding the unix time `at`, following the
+/// sunrise equation. Err(true) for polar day and Err(false) for polar night
+fn sun_times(at: f64, lat: f64, lon: f64) -> Result<(f64, f64), bool> {
+    let rad = PI / 180.0;
+    let julian = at / DAY + 2440587.5;
+    let n = (julian - 2451545.0 + 0.0008).round();
+    let mean_noon = n - lon / 360.0;
+    let m = (357.5291 + 0.98560028 * mean_noon).rem_euclid(360.0) * rad;
+    let center = 1.9148 * m.sin() + 0.02 * (2.0 * m).sin() + 0.0003 * (3.0 * m).sin();
+    let ecliptic = (m / rad + center + 180.0 + 102.9372).rem_euclid(360.0) * rad;
+    let transit = 2451545.0 + mean_noon + 0.0053 * m.sin() - 0.0069 * (2.0 * ecliptic).sin();
+    let declination = (ecliptic.sin() * (23.4397 * rad).sin()).asin();
+    let cos_hour_angle = ((-0.833 * rad).sin() - (lat * rad).sin() * declination.sin())
+        / ((lat * rad).cos() * declination.cos());
+    if cos_hour_angle < -1.0 {
+        return Err(true);
+    }
+    if cos_hour_angle > 1.0 {
+        return Err(false);
+    }
+    let half_day = cos_hour_angle.acos() / rad / 360.0;
+    let unix = |j: f64| (j - 2440587.5) * DAY;
+    Ok((unix(transit - half_day), unix(transit + half_day)))
+}
+
+impl Schedule {
+    /// Whether it's day at the unix time now, and how many seconds until that changes
+    fn state(&self, now: f64) -> (bool, f64) {
+        match *self {
+            Schedule::Fixed { sunrise, sunset } => {
+                let secs = |(h, m): (u8, u8)| h as f64 * 3600.0 + m as f64 * 60.0;
+                let (rise, set) = (secs(sunrise), secs(sunset));
+                let t = (now + local_offset()).rem_euclid(DAY);
+                let day = if rise <= set {
+                    rise <= t && t < set
+                } else {
+                    t >= rise || t < set
+                };
+                let next = if day { set } else { rise };
+                (day, (next - t).rem_euclid(DAY))
+            }
+            Schedule::SunriseSunset { lat, lon } => {
+                // the events of yesterday, today and tomorrow, as (time, day starts)
+                let mut events = vec![];
+                let mut polar = None;
+                for d in [-1.0, 0.0, 1.0] {
+                    match sun_times(now + d * DAY, lat, lon) {
+                        Ok((rise, set)) => {
+                            events.push((rise, true));
+                            events.push((set, false));
+                        }
+                        Err(day) if d == 0.0 => polar = Some(day),
+                        Err(_) => (),
+                    }
+                }
+                events.sort_by(|a, b| a.0.total_cmp(&b.0));
+                let last = events.iter().rev().find(|(t, _)| *t <= now);
+                let next = events.iter().find(|(t, _)| *t > now);
+                let day = match (polar, last) {
+                    (Some(day), _) => day,
+                    (None, Some((_, day))) => *day,
+                    (None, None) => next.is_some_and(|(_, day)| !day),
+                };
+                (day, next.map_or(MAX_WAIT, |(t, _)| t - now))
+            }
+        }
+    }
+}
+
+/**
+Switches between a light and a dark set of themes with the time of day, checking on the main
+thread through `app::add_timeout3`. The `on_theme_applied` callbacks are called on every switch:
+```rust,no_run
+use fltk_theme::{color_themes, Schedule, ScheduledTheme, Theme, ThemeType};
+let light = Theme::new().with_widget_theme(ThemeType::Greybird);
+let dark = Theme::new()
+    .with_widget_theme(ThemeType::Dark)
+    .with_colors(color_themes::DARK_THEME);
+let _schedule = ScheduledTheme::new(light, dark)
+    .with_schedule(Schedule::SunriseSunset { lat: 48.85, lon: 2.35 })
+    .start();
+```
+*/
+#[derive(Debug, Clone)]
+pub struct ScheduledTheme {
+    light: Theme,
+    dark: Theme,
+    schedule: Schedule,
+}
+
+impl ScheduledTheme {
+    /// Creates a schedule using light from 7:00 and dark from 19:00, local time
+    pub fn new(light: Theme, dark: Theme) -> ScheduledTheme {
+        ScheduledTheme {
+            light,
+            dark,
+            schedule: Schedule::Fixed {
+                sunrise: (7, 0),
+                sunset: (19, 0),
+            },
+        }
+    }
+
+    /// Sets when to switch
+    pub fn with_schedule(mut self, schedule: Schedule) -> ScheduledTheme {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Applies the set for the current time, then switches at each boundary until the returned
+    /// handle is dropped. Should be called from the main thread
+    pub fn start(self) -> ScheduleHandle {
+        let stopped = Rc::new(Cell::new(false));
+        let handle = ScheduleHandle {
+            stopped: stopped.clone(),
+        };
+        let mut applied = None;
+        let mut check = move || -> f64 {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0.0, |d| d.as_secs_f64());
+            let (day, wait) = self.schedule.state(now);
+            if applied != Some(day) {
+                applied = Some(day);
+                if day {
+                    self.light.apply();
+                } else {
+                    self.dark.apply();
+                }
+            }
+            // a second late, so that the boundary is past when checking again
+            (wait + 1.0).min(MAX_WAIT)
+        };
+        let wait = check();
+        app::add_timeout3(wait, move |timeout| {
+            if stopped.get() {
+                return;
+            }
+            app::repeat_timeout3(check(), timeout);
+        });
+        handle
+    }
+}
+
+/// Stops the switching started by `ScheduledTheme::start` when dropped
+pub struct ScheduleHandle {
+    stopped: Rc<Cell<bool>>,
+}
+
+impl ScheduleHandle {
+    /// Stops switching, which dropping the handle also does. The current themes stay applied
+    pub fn stop(&self) {
+        self.stopped.set(true);
+    }
+}
+
+impl Drop for ScheduleHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1,149 @@
+use crate::colors::{contrast_ratio, is_dark, to_linear};
+use crate::ramp::gray_ramp_through;
+use crate::system_theme::selection_color_for;
+use crate::{ColorMap, ColorTheme};
+
+type Rgb = (u8, u8, u8);
+
+/// The contrast ratio of inactive text against the background
+const INACTIVE_CONTRAST: f32 = 3.0;
+
+/// How far (in OKLab lightness) the background of inputs sits from the background
+const SURFACE_OFFSET: f32 = 0.04;
+
+fn from_linear(c: f32) -> u8 {
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// sRGB to OKLab (lightness from 0 to 1, then the a and b axes)
+pub(crate) fn to_oklab((r, g, b): Rgb) -> [f32; 3] {
+    let (r, g, b) = (to_linear(r), to_linear(g), to_linear(b));
+    let l = (0.4122215 * r + 0.5363325 * g + 0.0514460 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806996 * g + 0.107397 * b).cbrt();
+    let s = (0.0883025 * r + 0.2817188 * g + 0.6299787 * b).cbrt();
+    [
+        0.2104543 * l + 0.7936178 * m - 0.0040720 * s,
+        1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+        0.0259040 * l + 0.7827718 * m - 0.8086758 * s,
+    ]
+}
+
+/// OKLab to linear sRGB, whose components are out of 0..1 for colors outside of sRGB
+fn oklab_to_linear([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let l_ = (l + 0.3963378 * a + 0.2158038 * b).powi(3);
+    let m_ = (l - 0.1055613 * a - 0.0638542 * b).powi(3);
+    let s_ = (l - 0.0894842 * a - 1.2914855 * b).powi(3);
+    [
+        4.0767417 * l_ - 3.3077116 * m_ + 0.2309699 * s_,
+        -1.268438 * l_ + 2.6097574 * m_ - 0.3413194 * s_,
+        -0.0041961 * l_ - 0.7034186 * m_ + 1.7076147 * s_,
+    ]
+}
+
+fn from_oklab(lab: [f32; 3]) -> Rgb {
+    let [r, g, b] = oklab_to_linear(lab);
+    (from_linear(r), from_linear(g), from_linear(b))
+}
+
+/// Like `from_oklab`, but lowering the chroma of colors outside of sRGB until they fit, rather
+/// than clipping them, which would shift their hue
+pub(crate) fn from_oklab_in_gamut(lab: [f32; 3]) -> Rgb {
+    let fits = |k: f32| {
+        oklab_to_linear([lab[0], lab[1] * k, lab[2] * k])
+            .iter()
+            .all(|c| (-0.0001..=1.0001).contains(c))
+    };
+    if fits(1.0) {
+        return from_oklab(lab);
+    }
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..16 {
+        let k = (lo + hi) / 2.0;
+        if fits(k) {
+            lo = k;
+        } else {
+            hi = k;
+        }
+    }
+    from_oklab([lab[0], lab[1] * lo, lab[2] * lo])
+}
+
+/// Interpolation from a (t = 0) to b (t = 1) in OKLab, which keeps midtones from going muddy
+pub(crate) fn mix_oklab(a: Rgb, b: Rgb, t: f32) -> Rgb {
+    let (a, b) = (to_oklab(a), to_oklab(b));
+    from_oklab([0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t))
+}
+
+impl ColorTheme {
+    /**
+    Synthesizes a complete theme from a background, a foreground and an accent, e.g. the brand
+    colors of an app:
+    ```rust,no_run
+    use fltk_theme::ColorTheme;
+    ColorTheme::from_seeds((0x10, 0x18, 0x28), (0xe8, 0xec, 0xf2), (0xff, 0x6a, 0x13)).apply();
+    ```
+    The colors are derived as follows:
+    - background (49) and foreground (0): the seeds
+    - the gray ramp (32 to 55): interpolated in OKLab, from the background towards black or white
+      on one side and towards the foreground on the other
+    - background2 (7), the background of inputs: the background moved slightly away from the
+      foreground in lightness
+    - selection (15): the accent, lightened if it's too gray to stand out (see
+      `selection_color_for`). FLTK picks the text drawn on it by contrast, using the foreground
+      or black or white
+    - inactive (8): the mix of the foreground into the background closest to a 3:1 contrast
+      ratio with the background
+    */
+    pub fn from_seeds(
+        background: (u8, u8, u8),
+        foreground: (u8, u8, u8),
+        accent: (u8, u8, u8),
+    ) -> ColorTheme {
+        let bg = background;
+        let fg = foreground;
+        let dark_bg = is_dark(bg);
+        let (dark, light) = if dark_bg {
+            (mix_oklab(bg, (0, 0, 0), 0.4), mix_oklab(bg, fg, 0.3))
+        } else {
+            (mix_oklab(bg, fg, 0.5), mix_oklab(bg, (255, 255, 255), 0.5))
+        };
+        let mut map = gray_ramp_through(dark, bg, light);
+        let mut surface = to_oklab(bg);
+        surface[0] = if dark_bg {
+            surface[0] - SURFACE_OFFSET
+        } else {
+            surface[0] + SURFACE_OFFSET
+        }
+        .clamp(0.0, 1.0);
+        // the mix closest to the inactive contrast, fg itself if even it doesn't reach it
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..16 {
+            let t = (lo + hi) / 2.0;
+            if contrast_ratio(mix_oklab(bg, fg, t), bg) < INACTIVE_CONTRAST {
+                lo = t;
+            } else {
+                hi = t;
+            }
+        }
+        let inactive = mix_oklab(bg, fg, hi);
+        let mut set = |index: u8, (r, g, b): Rgb| {
+            map.push(ColorMap {
+                index,
+                r,
+                g,
+                b,
+                a: 255,
+            })
+        };
+        set(0, fg);
+        set(7, from_oklab(surface));
+        set(8, inactive);
+        set(15, selection_color_for(accent));
+        ColorTheme::from(map)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::ColorTheme;
+use fltk::{enums::Color, prelude::*, widget::Widget};
+
+/// The indices widgets refer to by default: foreground, background2 (the field of inputs),
+/// inactive, selection and background (the face of buttons)
+const SEMANTIC_INDICES: [u8; 5] = [0, 7, 8, 15, 49];
+
+/// The theme's value for an index, the last one if it's set several times
+fn color_of(theme: &ColorTheme, index: u8) -> Option<Color> {
+    let elem = theme.colors.iter().rev().find(|e| e.index == index)?;
+    Some(Color::from_rgb(elem.r, elem.g, elem.b))
+}
+
+/// Replaces a color referring to one of the semantic indices, or set to the previous theme's
+/// value for one, by the theme's value for it
+fn remap(theme: &ColorTheme, previous: Option<&ColorTheme>, c: Color) -> Option<Color> {
+    let index = *SEMANTIC_INDICES.iter().find(|i| {
+        Color::by_index(**i) == c || previous.and_then(|p| color_of(p, **i)) == Some(c)
+    })?;
+    color_of(theme, index).filter(|new| *new != c)
+}
+
+/// Recolors a widget and the widgets below it, redrawing the ones which changed.
+/// Returns the number of widgets visited
+pub(crate) fn recolor(theme: &ColorTheme, previous: Option<&ColorTheme>, w: &mut Widget) -> usize {
+    let mut changed = false;
+    if let Some(c) = remap(theme, previous, w.color()) {
+        w.set_color(c);
+        changed = true;
+    }
+    if let Some(c) = remap(theme, previous, w.selection_color()) {
+        w.set_selection_color(c);
+        changed = true;
+    }
+    if let Some(c) = remap(theme, previous, w.label_color()) {
+        w.set_label_color(c);
+        changed = true;
+    }
+    if changed {
+        w.redraw();
+    }
+    let mut count = 1;
+    if let Some(group) = w.as_group() {
+        for i in 0..group.children() {
+            if let Some(mut child) = group.child(i) {
+                count += recolor(theme, previous, &mut child);
+            }
+        }
+    }
+    count
+}
+
+impl ColorTheme {
+    /**
+    Applies the theme to a group and the widgets below it only, leaving the rest of the app alone.
+    Since FLTK's colors are global, the colors of the widgets which refer to the foreground,
+    background, background2, inactive and selection indices are replaced by the theme's values
+    for them. Each widget keeps the role of its colors: inputs use background2 for their field,
+    buttons background for their face and so on. Colors set to anything else, or which the theme
+    doesn't set, are left as they are, as is frame drawing, which uses the global gray ramp.
+    Returns the number of widgets visited, the group included:
+    ```rust,no_run
+    use fltk::{prelude::*, *};
+    use fltk_theme::{color_themes, ColorTheme};
+    let preview = group::Flex::default_fill();
+    // ...
+    preview.end();
+    let count = ColorTheme::new(color_themes::DARK_THEME).apply_to(&preview);
+    ```
+    */
+    pub fn apply_to<G: GroupExt>(&self, group: &G) -> usize {
+        recolor(self, None, &mut group.as_base_widget())
+    }
+}
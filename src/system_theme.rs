@@ -0,0 +1,559 @@
+use crate::theme_file::mix;
+use crate::{ColorMap, ColorTheme, ThemeType, WidgetTheme};
+use fltk::app;
+#[cfg(target_os = "macos")]
+use std::sync::mpsc;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    process::{Child, Command},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// How often the appearance is checked where changes can't be watched, which is also how long
+/// the watching threads take to notice that they were stopped
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+type Callback = Box<dyn FnMut(bool)>;
+
+thread_local! {
+    static CALLBACKS: RefCell<HashMap<usize, Callback>> = RefCell::new(HashMap::new());
+}
+
+/// The registry key holding the light and dark preferences on Windows
+#[cfg(target_os = "windows")]
+const PERSONALIZE: &str = r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize";
+
+/// Runs a command, returning its standard output if it succeeded
+pub(crate) fn output(cmd: &str, args: &[&str]) -> Option<String> {
+    let mut command = Command::new(cmd);
+    command.args(args);
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        // CREATE_NO_WINDOW, so that console tools don't flash a window over GUI apps
+        command.creation_flags(0x0800_0000);
+    }
+    let out = command.output().ok()?;
+    if out.status.success() {
+        String::from_utf8(out.stdout).ok()
+    } else {
+        None
+    }
+}
+
+/// Reads a REG_DWORD value below HKEY_CURRENT_USER using `reg query`
+#[cfg(target_os = "windows")]
+fn reg_dword(key: &str, value: &str) -> Option<u32> {
+    let out = output("reg", &["query", &format!(r"HKCU\{}", key), "/v", value])?;
+    // AppsUseLightTheme    REG_DWORD    0x0
+    let data = out
+        .lines()
+        .find(|line| line.contains(value))?
+        .split_whitespace()
+        .next_back()?;
+    u32::from_str_radix(data.trim_start_matches("0x"), 16).ok()
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> Option<bool> {
+    let light = reg_dword(PERSONALIZE, "AppsUseLightTheme")?;
+    Some(light == 0)
+}
+
+#[cfg(target_os = "macos")]
+fn detect() -> Option<bool> {
+    // the key only exists in dark mode, `defaults` failing in light mode
+    let style = output("defaults", &["read", "-g", "AppleInterfaceStyle"]);
+    Some(matches!(style.as_deref().map(str::trim), Some("Dark")))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn detect() -> Option<bool> {
+    use crate::portal::{read_color_scheme, ColorScheme};
+    match read_color_scheme() {
+        Some(ColorScheme::PreferDark) => return Some(true),
+        Some(ColorScheme::PreferLight) => return Some(false),
+        _ => (),
+    }
+    let gsettings = |key: &str| output("gsettings", &["get", "org.gnome.desktop.interface", key]);
+    match gsettings("color-scheme").as_deref().map(str::trim) {
+        Some("'prefer-dark'") => return Some(true),
+        Some("'prefer-light'") => return Some(false),
+        _ => (),
+    }
+    gsettings("gtk-theme").map(|theme| theme.to_ascii_lowercase().contains("dark"))
+}
+
+/**
+Detects whether the OS is set to a dark appearance, None if it can't be determined:
+- Windows: the `AppsUseLightTheme` registry value
+- macOS: the `AppleInterfaceStyle` default
+- other platforms: the `org.freedesktop.appearance color-scheme` setting of the settings portal,
+  (see the `portal` module), which also works inside a Flatpak, falling back to GNOME's
+  `color-scheme` then to whether the GTK theme name contains "dark"
+
+The detection runs the platform's command line tools, so it's better not called in a loop.
+*/
+pub fn prefers_dark() -> Option<bool> {
+    detect()
+}
+
+#[cfg(target_os = "windows")]
+fn accent() -> Option<(u8, u8, u8)> {
+    // both values are stored as 0xAABBGGRR
+    let abgr = reg_dword(
+        r"Software\Microsoft\Windows\CurrentVersion\Explorer\Accent",
+        "AccentColorMenu",
+    )
+    .or_else(|| reg_dword(r"Software\Microsoft\Windows\DWM", "AccentColor"))?;
+    let [_, b, g, r] = abgr.to_be_bytes();
+    Some((r, g, b))
+}
+
+#[cfg(target_os = "macos")]
+fn accent() -> Option<(u8, u8, u8)> {
+    // the key is missing for the default (multicolor) accent, which has no single color
+    let value = output("defaults", &["read", "-g", "AppleAccentColor"])?;
+    match value.trim().parse::<i32>().ok()? {
+        -1 => Some((0x8C, 0x8C, 0x8C)), // graphite
+        0 => Some((0xFF, 0x52, 0x57)),  // red
+        1 => Some((0xF7, 0x82, 0x1B)),  // orange
+        2 => Some((0xFF, 0xC6, 0x00)),  // yellow
+        3 => Some((0x62, 0xBA, 0x46)),  // green
+        4 => Some((0x00, 0x7A, 0xFF)),  // blue
+        5 => Some((0xA5, 0x50, 0xA7)),  // purple
+        6 => Some((0xF7, 0x4F, 0x9E)),  // pink
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn highlight() -> Option<(u8, u8, u8)> {
+    // e.g. `0.698039 0.843137 1.000000 Blue`
+    let value = output("defaults", &["read", "-g", "AppleHighlightColor"])?;
+    let mut components = value.split_whitespace().map(|c| c.parse::<f32>());
+    let mut next = || -> Option<u8> {
+        let c = components.next()?.ok()?;
+        Some((c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    };
+    Some((next()?, next()?, next()?))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn accent() -> Option<(u8, u8, u8)> {
+    crate::portal::read_accent_color()
+}
+
+#[cfg(not(target_os = "macos"))]
+fn highlight() -> Option<(u8, u8, u8)> {
+    None
+}
+
+/**
+Returns the accent color picked by the user, None if it can't be read or on platforms without one.
+- Windows: the `AccentColorMenu` registry value, falling back to DWM's `AccentColor`
+- macOS: the `AppleAccentColor` default (blue, purple, pink, red, orange, yellow, green or
+  graphite), None for the multicolor accent, which leaves it unset
+- other platforms: the `org.freedesktop.appearance accent-color` setting of the settings portal
+
+It can be passed to `WidgetScheme::with_accent` or `WidgetTheme::with_accent`.
+*/
+pub fn system_accent_color() -> Option<(u8, u8, u8)> {
+    accent()
+}
+
+/// Returns the text highlight color picked by the user on macOS (the `AppleHighlightColor`
+/// default), None elsewhere. It's lighter than the accent, meant for selected text
+pub fn system_highlight_color() -> Option<(u8, u8, u8)> {
+    highlight()
+}
+
+/**
+Picks the selection color to go with an accent. Gray accents (such as macOS' graphite) are lightened
+so that selected text stays dark, other accents are used as they are:
+```rust
+use fltk_theme::{colors::contrast_ratio, selection_color_for};
+// the black foreground of the Aqua themes keeps an AA contrast on every gray
+for gray in 0..=255 {
+    let selection = selection_color_for((gray, gray, gray));
+    assert!(contrast_ratio((0, 0, 0), selection) >= 4.5);
+}
+let blue = (0x00, 0x7A, 0xFF);
+assert_eq!(selection_color_for(blue), blue);
+```
+*/
+pub fn selection_color_for(accent: (u8, u8, u8)) -> (u8, u8, u8) {
+    let (r, g, b) = accent;
+    let spread = r.max(g).max(b) - r.min(g).min(b);
+    if spread < 24 {
+        mix(accent, (255, 255, 255), 0.6)
+    } else {
+        accent
+    }
+}
+
+/// Stops the watching started by `on_system_theme_change` when dropped
+pub struct SystemThemeWatch {
+    id: usize,
+    stop: Arc<AtomicBool>,
+    child: Arc<Mutex<Option<Child>>>,
+    /// Where the appearance observer sends its notifications, its address identifying the observer
+    #[cfg(target_os = "macos")]
+    changes: Box<Mutex<mpsc::Sender<()>>>,
+}
+
+impl SystemThemeWatch {
+    /// Stops watching, which dropping the handle also does
+    pub fn stop(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(mut child) = self.child.lock().unwrap().take() {
+            child.kill().ok();
+            child.wait().ok();
+        }
+        #[cfg(target_os = "macos")]
+        stop_observing_appearance(&self.changes);
+        CALLBACKS.with(|cbs| cbs.borrow_mut().remove(&self.id));
+    }
+}
+
+impl Drop for SystemThemeWatch {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Follows the settings portal's `SettingChanged` signal through `gdbus monitor`,
+/// returning when the monitor can't be started or exits
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn watch_portal(stop: &AtomicBool, child: &Mutex<Option<Child>>, notify: &mut dyn FnMut()) {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+    let spawned = Command::new("gdbus")
+        .args([
+            "monitor",
+            "--session",
+            "--dest",
+            "org.freedesktop.portal.Desktop",
+            "--object-path",
+            "/org/freedesktop/portal/desktop",
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let mut monitor = match spawned {
+        Ok(monitor) => monitor,
+        Err(_) => return,
+    };
+    let stdout = monitor.stdout.take();
+    *child.lock().unwrap() = Some(monitor);
+    if stop.load(Ordering::Relaxed) {
+        // stopped while spawning, the handle missed the child
+        if let Some(mut monitor) = child.lock().unwrap().take() {
+            monitor.kill().ok();
+            monitor.wait().ok();
+        }
+        return;
+    }
+    for line in BufReader::new(stdout.unwrap()).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        if line.contains("SettingChanged")
+            && line.contains("'org.freedesktop.appearance', 'color-scheme'")
+        {
+            notify();
+        }
+    }
+}
+
+/// Follows changes of the `Personalize` registry key through `RegNotifyChangeKeyValue`, waking up
+/// every `POLL_INTERVAL` to see whether the watch was stopped. Returns when stopped or when the key
+/// can't be watched
+#[cfg(target_os = "windows")]
+fn watch_registry(stop: &AtomicBool, notify: &mut dyn FnMut()) {
+    use std::ffi::c_void;
+    use std::ptr::null;
+    type Handle = isize;
+    const HKEY_CURRENT_USER: Handle = 0x8000_0001u32 as i32 as Handle;
+    const KEY_NOTIFY: u32 = 0x0010;
+    const REG_NOTIFY_CHANGE_LAST_SET: u32 = 0x0004;
+    const WAIT_OBJECT_0: u32 = 0;
+    const WAIT_TIMEOUT: u32 = 0x0102;
+    #[link(name = "advapi32")]
+    extern "system" {
+        fn RegOpenKeyExW(
+            key: Handle,
+            sub_key: *const u16,
+            options: u32,
+            desired: u32,
+            result: *mut Handle,
+        ) -> i32;
+        fn RegNotifyChangeKeyValue(
+            key: Handle,
+            watch_subtree: i32,
+            filter: u32,
+            event: Handle,
+            asynchronous: i32,
+        ) -> i32;
+        fn RegCloseKey(key: Handle) -> i32;
+    }
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn CreateEventW(
+            attributes: *const c_void,
+            manual_reset: i32,
+            initial_state: i32,
+            name: *const u16,
+        ) -> Handle;
+        fn WaitForSingleObject(handle: Handle, millis: u32) -> u32;
+        fn CloseHandle(handle: Handle) -> i32;
+    }
+    let path: Vec<u16> = PERSONALIZE.encode_utf16().chain(Some(0)).collect();
+    let mut key = 0;
+    // SAFETY: the path is nul-terminated and the handles are closed once, after their last use
+    unsafe {
+        if RegOpenKeyExW(HKEY_CURRENT_USER, path.as_ptr(), 0, KEY_NOTIFY, &mut key) != 0 {
+            return;
+        }
+        let event = CreateEventW(null(), 0, 0, null());
+        if event != 0 {
+            'watch: while !stop.load(Ordering::Relaxed) {
+                // the notification fires once, so it's requested again after every change
+                if RegNotifyChangeKeyValue(key, 0, REG_NOTIFY_CHANGE_LAST_SET, event, 1) != 0 {
+                    break;
+                }
+                loop {
+                    match WaitForSingleObject(event, POLL_INTERVAL.as_millis() as u32) {
+                        WAIT_OBJECT_0 => break,
+                        WAIT_TIMEOUT if !stop.load(Ordering::Relaxed) => (),
+                        _ => break 'watch,
+                    }
+                }
+                notify();
+            }
+            CloseHandle(event);
+        }
+        RegCloseKey(key);
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod core_foundation {
+    use std::os::raw::{c_char, c_void};
+
+    pub type Ref = *const c_void;
+    pub type Callback = extern "C" fn(Ref, Ref, Ref, Ref, Ref);
+    pub const STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+    pub const SUSPENSION_DELIVER_IMMEDIATELY: isize = 4;
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        pub fn CFNotificationCenterGetDistributedCenter() -> Ref;
+        pub fn CFNotificationCenterAddObserver(
+            center: Ref,
+            observer: Ref,
+            callback: Callback,
+            name: Ref,
+            object: Ref,
+            suspension_behavior: isize,
+        );
+        pub fn CFNotificationCenterRemoveObserver(
+            center: Ref,
+            observer: Ref,
+            name: Ref,
+            object: Ref,
+        );
+        pub fn CFStringCreateWithCString(alloc: Ref, c_str: *const c_char, encoding: u32) -> Ref;
+        pub fn CFRelease(cf: Ref);
+    }
+}
+
+/// Receives `AppleInterfaceThemeChangedNotification`, the observer being the sender to forward it to
+#[cfg(target_os = "macos")]
+extern "C" fn appearance_changed(
+    _center: core_foundation::Ref,
+    observer: core_foundation::Ref,
+    _name: core_foundation::Ref,
+    _object: core_foundation::Ref,
+    _user_info: core_foundation::Ref,
+) {
+    // SAFETY: the observer is removed before the sender it points to is dropped
+    let changes = unsafe { &*(observer as *const Mutex<mpsc::Sender<()>>) };
+    if let Ok(changes) = changes.lock() {
+        changes.send(()).ok();
+    }
+}
+
+/// Registers with the distributed notification center for `AppleInterfaceThemeChangedNotification`,
+/// which macOS posts when switching between light and dark. The notifications are delivered by the
+/// main thread's run loop, which FLTK runs while waiting for events, and sent through `changes`.
+/// Returns false if the observer couldn't be added
+#[cfg(target_os = "macos")]
+fn observe_appearance(changes: &Mutex<mpsc::Sender<()>>) -> bool {
+    use core_foundation::*;
+    use std::os::raw::c_char;
+    use std::ptr::null;
+    // SAFETY: the name is nul-terminated and released after the center copied it
+    unsafe {
+        let center = CFNotificationCenterGetDistributedCenter();
+        let name = CFStringCreateWithCString(
+            null(),
+            b"AppleInterfaceThemeChangedNotification\0".as_ptr() as *const c_char,
+            STRING_ENCODING_UTF8,
+        );
+        if center.is_null() || name.is_null() {
+            return false;
+        }
+        CFNotificationCenterAddObserver(
+            center,
+            changes as *const _ as Ref,
+            appearance_changed,
+            name,
+            null(),
+            SUSPENSION_DELIVER_IMMEDIATELY,
+        );
+        CFRelease(name);
+        true
+    }
+}
+
+/// Removes the observer added by `observe_appearance`, doing nothing if there's none
+#[cfg(target_os = "macos")]
+fn stop_observing_appearance(changes: &Mutex<mpsc::Sender<()>>) {
+    use core_foundation::*;
+    use std::ptr::null;
+    // SAFETY: a null name and object remove every registration of the observer
+    unsafe {
+        let center = CFNotificationCenterGetDistributedCenter();
+        if !center.is_null() {
+            CFNotificationCenterRemoveObserver(center, changes as *const _ as Ref, null(), null());
+        }
+    }
+}
+
+/// Calls notify for every notification `observe_appearance` forwards, returning when stopped or
+/// when the observer is gone
+#[cfg(target_os = "macos")]
+fn watch_appearance(stop: &AtomicBool, changes: mpsc::Receiver<()>, notify: &mut dyn FnMut()) {
+    while !stop.load(Ordering::Relaxed) {
+        match changes.recv_timeout(POLL_INTERVAL) {
+            Ok(()) => notify(),
+            Err(mpsc::RecvTimeoutError::Timeout) => (),
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+/**
+Calls callback with the new `prefers_dark()` value whenever the OS switches between light and dark,
+on the main thread through `app::awake_callback`. Should be called from the main thread:
+```rust,no_run
+use fltk_theme::{color_themes, ColorTheme};
+let _watch = fltk_theme::on_system_theme_change(|dark| {
+    let theme = if dark { color_themes::DARK_THEME } else { color_themes::GRAY_THEME };
+    ColorTheme::new(theme).apply();
+});
+```
+- Windows: changes of the `Personalize` registry key are waited for using `RegNotifyChangeKeyValue`
+- macOS: the distributed notification center's `AppleInterfaceThemeChangedNotification` is observed,
+  delivered while the FLTK event loop runs
+- other platforms: the settings portal's `SettingChanged` signal is followed using `gdbus monitor`
+
+Where changes can't be watched (no `gdbus`, no portal, an unwatchable registry key), the preference
+is instead read every 2 seconds from a background thread, which runs the same command line tools as
+`prefers_dark()`. Nothing is polled if the preference couldn't be read to begin with, e.g. when those
+tools are missing. Watching, polling included, stops when the returned handle is stopped or dropped.
+*/
+pub fn on_system_theme_change<F: FnMut(bool) + 'static>(callback: F) -> SystemThemeWatch {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    CALLBACKS.with(|cbs| cbs.borrow_mut().insert(id, Box::new(callback)));
+    #[cfg(target_os = "macos")]
+    let (changes, changed) = mpsc::channel();
+    let watch = SystemThemeWatch {
+        id,
+        stop: Arc::new(AtomicBool::new(false)),
+        child: Arc::new(Mutex::new(None)),
+        #[cfg(target_os = "macos")]
+        changes: Box::new(Mutex::new(changes)),
+    };
+    #[cfg(target_os = "macos")]
+    let observing = observe_appearance(&watch.changes);
+    let stop = watch.stop.clone();
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let child = watch.child.clone();
+    thread::spawn(move || {
+        let mut last = detect();
+        let readable = last.is_some();
+        let mut notify = || {
+            let dark = match detect() {
+                Some(dark) if Some(dark) != last => dark,
+                _ => return,
+            };
+            last = Some(dark);
+            let stop = stop.clone();
+            app::awake_callback(move || {
+                // taken out while running, so that the callback can start or stop watches
+                let cb = CALLBACKS.with(|cbs| cbs.borrow_mut().remove(&id));
+                if let Some(mut cb) = cb {
+                    if !stop.load(Ordering::Relaxed) {
+                        cb(dark);
+                        CALLBACKS.with(|cbs| cbs.borrow_mut().insert(id, cb));
+                    }
+                }
+            });
+        };
+        #[cfg(target_os = "windows")]
+        watch_registry(&stop, &mut notify);
+        #[cfg(target_os = "macos")]
+        {
+            if observing {
+                watch_appearance(&stop, changed, &mut notify);
+                return;
+            }
+        }
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        watch_portal(&stop, &child, &mut notify);
+        // polling only makes sense if the preference can be read at all
+        if !readable {
+            return;
+        }
+        while !stop.load(Ordering::Relaxed) {
+            thread::sleep(POLL_INTERVAL);
+            if !stop.load(Ordering::Relaxed) {
+                notify();
+            }
+        }
+    });
+    watch
+}
+
+impl ColorTheme {
+    /// Picks the dark or light colors based on `prefers_dark()`, light if undetectable
+    pub fn auto(dark: &[ColorMap], light: &[ColorMap]) -> ColorTheme {
+        if prefers_dark().unwrap_or(false) {
+            ColorTheme::new(dark)
+        } else {
+            ColorTheme::new(light)
+        }
+    }
+}
+
+impl WidgetTheme {
+    /// Picks the dark or light theme based on `prefers_dark()`, light if undetectable
+    pub fn auto(dark: ThemeType, light: ThemeType) -> WidgetTheme {
+        if prefers_dark().unwrap_or(false) {
+            WidgetTheme::new(dark)
+        } else {
+            WidgetTheme::new(light)
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::current::{self, ThemeEvent};
+use crate::{ColorMap, ColorTheme, SchemeType, ThemeType, WidgetScheme, WidgetTheme};
+use fltk::app;
+
+/**
+A widget theme, a widget scheme and a color theme applied together, in the order which keeps each
+layer from clobbering the next: the widget theme, then the scheme (replacing the theme's frame
+drawing), then the colors:
+```rust,no_run
+use fltk_theme::{color_themes, SchemeType, Theme, ThemeType};
+Theme::new()
+    .with_widget_theme(ThemeType::Dark)
+    .with_scheme(SchemeType::Fluent)
+    .with_colors(color_themes::NORD_THEME)
+    .apply();
+```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    widget: Option<ThemeType>,
+    scheme: Option<SchemeType>,
+    colors: Option<ColorTheme>,
+}
+
+impl Theme {
+    /// Creates an empty theme, applying it changes nothing
+    pub fn new() -> Theme {
+        Theme::default()
+    }
+
+    /// A dark look: the AdwaitaDark widget theme
+    pub fn dark() -> Theme {
+        Theme::new().with_widget_theme(ThemeType::AdwaitaDark)
+    }
+
+    /// A light look: the Adwaita widget theme
+    pub fn light() -> Theme {
+        Theme::new().with_widget_theme(ThemeType::Adwaita)
+    }
+
+    /// Sets the widget theme
+    pub fn with_widget_theme(mut self, theme: ThemeType) -> Theme {
+        self.widget = Some(theme);
+        self
+    }
+
+    /// Sets the widget scheme
+    pub fn with_scheme(mut self, scheme: SchemeType) -> Theme {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    /// Sets the colors, e.g. one of the `color_themes` constants
+    pub fn with_colors(mut self, colors: &[ColorMap]) -> Theme {
+        self.colors = Some(ColorTheme::new(colors));
+        self
+    }
+
+    /// Applies the theme, recording its parts for `current_widget_theme`, `current_widget_scheme`
+    /// and `current_color_theme_name`, and calling the `on_theme_applied` callbacks once with a
+    /// `ThemeEvent::Theme`
+    pub fn apply(&self) {
+        current::quiet(|| {
+            if let Some(theme) = self.widget {
+                WidgetTheme::new(theme).apply();
+            }
+            if let Some(scheme) = self.scheme {
+                WidgetScheme::new(scheme).apply();
+            }
+            if let Some(colors) = &self.colors {
+                colors.apply();
+            }
+        });
+        let color = current::set_combined(self.widget, self.scheme);
+        current::notify(ThemeEvent::Theme {
+            widget: self.widget,
+            scheme: self.scheme,
+            color,
+        });
+        app::redraw();
+    }
+}
@@ -0,0 +1,126 @@
+use crate::saved_theme::config_dir;
+use crate::{ColorTheme, PaletteMapping, ThemeError};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+type Loader = fn(&Path) -> Result<ColorTheme, ThemeError>;
+
+fn load_hex(path: &Path) -> Result<ColorTheme, ThemeError> {
+    ColorTheme::from_hex_palette(&fs::read_to_string(path)?, PaletteMapping::default())
+}
+
+/// The recognized extensions, a theme existing in several formats being loaded from the first one
+const LOADERS: &[(&str, Loader)] = &[
+    ("toml", ColorTheme::load),
+    #[cfg(feature = "json")]
+    ("json", ColorTheme::load),
+    ("yaml", ColorTheme::load_base16),
+    ("yml", ColorTheme::load_base16),
+    ("colors", ColorTheme::from_kde_colors),
+    ("theme", ColorTheme::from_windows_theme),
+    #[cfg(feature = "iterm")]
+    ("itermcolors", ColorTheme::from_iterm),
+    ("gpl", ColorTheme::from_gpl),
+    ("hex", load_hex),
+];
+
+fn priority(path: &Path) -> Option<usize> {
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    LOADERS.iter().position(|(e, _)| *e == ext)
+}
+
+/// Loads a theme file in any recognized format, files with an unknown extension being read as TOML
+pub(crate) fn load_file(path: &Path) -> Result<ColorTheme, ThemeError> {
+    let (_, loader) = LOADERS[priority(path).unwrap_or_default()];
+    loader(path)
+}
+
+/**
+A directory of theme files, the theme name being the file name without its extension:
+```rust,no_run
+use fltk_theme::ThemeDir;
+let dir = ThemeDir::user_dir("myapp");
+for name in dir.names() {
+    println!("{}", name);
+}
+if let Ok(theme) = dir.load("nord") {
+    theme.apply();
+}
+```
+The recognized files are `.toml` (see `ColorTheme::load`), `.json` (with the `json` feature),
+base16 `.yaml`/`.yml`, KDE `.colors`, Windows `.theme`, `.itermcolors` (with the `iterm` feature),
+GIMP `.gpl` and Lospec `.hex` palettes.
+*/
+#[derive(Debug, Clone)]
+pub struct ThemeDir {
+    path: PathBuf,
+    themes: BTreeMap<String, PathBuf>,
+}
+
+impl ThemeDir {
+    /// Scans a directory for theme files. A missing or unreadable directory has no themes
+    pub fn new(path: &Path) -> ThemeDir {
+        let mut dir = ThemeDir {
+            path: path.to_path_buf(),
+            themes: BTreeMap::new(),
+        };
+        dir.rescan();
+        dir
+    }
+
+    /// Scans `<config dir>/<app_name>/themes`, the config dir being `%APPDATA%` on Windows,
+    /// `~/Library/Application Support` on macOS and `~/.config` elsewhere (see `SavedTheme`)
+    pub fn user_dir(app_name: &str) -> ThemeDir {
+        let config = config_dir().unwrap_or_default();
+        ThemeDir::new(&config.join(app_name).join("themes"))
+    }
+
+    /// The scanned directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Scans the directory again, picking up added and removed files
+    pub fn rescan(&mut self) {
+        self.themes.clear();
+        let entries = match fs::read_dir(&self.path) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+            let (prio, name) = match (priority(&path), path.file_stem().and_then(|s| s.to_str())) {
+                (Some(prio), Some(name)) if path.is_file() => (prio, name.to_string()),
+                _ => continue,
+            };
+            match self.themes.get(&name) {
+                Some(existing) if priority(existing) <= Some(prio) => (),
+                _ => {
+                    self.themes.insert(name, path);
+                }
+            }
+        }
+    }
+
+    /// The names of the discovered themes, sorted
+    pub fn names(&self) -> Vec<&str> {
+        self.themes.keys().map(|name| name.as_str()).collect()
+    }
+
+    /// The file a theme would be loaded from
+    pub fn theme_path(&self, name: &str) -> Option<&Path> {
+        self.themes.get(name).map(|path| path.as_path())
+    }
+
+    /// Loads a theme by name
+    pub fn load(&self, name: &str) -> Result<ColorTheme, ThemeError> {
+        let path = self
+            .theme_path(name)
+            .ok_or_else(|| ThemeError::UnknownTheme {
+                name: name.to_string(),
+            })?;
+        load_file(path)
+    }
+}
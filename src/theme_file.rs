@@ -0,0 +1,339 @@
+use crate::colors::is_dark;
+use crate::{ColorMap, ColorTheme, ThemeError, ThemeInfo, Variant};
+use std::{fs, path::Path};
+
+/// Names accepted in place of the FLTK color indices
+const COLOR_NAMES: &[(&str, u8)] = &[
+    ("foreground", 0),
+    ("background2", 7),
+    ("inactive", 8),
+    ("selection", 15),
+    ("dark3", 39),
+    ("dark2", 45),
+    ("dark1", 47),
+    ("background", 49),
+    ("light1", 50),
+    ("light2", 52),
+    ("light3", 54),
+    ("black", 56),
+    ("green", 63),
+    ("red", 88),
+    ("yellow", 95),
+    ("blue", 216),
+    ("cyan", 223),
+    ("magenta", 248),
+    ("white", 255),
+];
+
+fn index_of(key: &str) -> Option<u8> {
+    if let Some((_, idx)) = COLOR_NAMES.iter().find(|(name, _)| *name == key) {
+        return Some(*idx);
+    }
+    key.parse::<u8>().ok()
+}
+
+/// Looks a color name up, ignoring case and whitespace (`Background 2` is `background2`)
+pub(crate) fn named_index(name: &str) -> Option<u8> {
+    let name: String = name
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    COLOR_NAMES
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, idx)| *idx)
+}
+
+#[cfg(feature = "json")]
+fn is_json(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+fn name_of(index: u8) -> Option<&'static str> {
+    COLOR_NAMES
+        .iter()
+        .find(|(_, idx)| *idx == index)
+        .map(|(name, _)| *name)
+}
+
+/// Parses `#rrggbb` (the # is optional)
+pub(crate) fn parse_hex(s: &str) -> Option<(u8, u8, u8)> {
+    let s = s.strip_prefix('#').unwrap_or(s);
+    if s.len() != 6 || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let v = u32::from_str_radix(s, 16).ok()?;
+    Some(((v >> 16) as u8, (v >> 8) as u8, v as u8))
+}
+
+/// Parses `#rrggbb` or `#rrggbbaa` (the # is optional), an alpha of 255 being opaque
+pub(crate) fn parse_hex_alpha(s: &str) -> Option<(u8, u8, u8, u8)> {
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    if hex.len() == 8 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        let v = u32::from_str_radix(hex, 16).ok()?;
+        return Some(((v >> 24) as u8, (v >> 16) as u8, (v >> 8) as u8, v as u8));
+    }
+    let (r, g, b) = parse_hex(s)?;
+    Some((r, g, b, 255))
+}
+
+pub(crate) fn unquote(s: &str) -> Option<&str> {
+    let s = s.trim();
+    if s.len() >= 2
+        && ((s.starts_with('"') && s.ends_with('"')) || (s.starts_with('\'') && s.ends_with('\'')))
+    {
+        Some(&s[1..s.len() - 1])
+    } else {
+        None
+    }
+}
+
+/// Removes a trailing comment, ignoring # signs inside quoted strings
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (c, quote) {
+            ('"', None) | ('\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            ('#', None) => return &line[..i],
+            _ => (),
+        }
+    }
+    line
+}
+
+/// Linear interpolation from a (t = 0) to b (t = 1)
+pub(crate) fn mix(a: (u8, u8, u8), b: (u8, u8, u8), t: f32) -> (u8, u8, u8) {
+    let f = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    (f(a.0, b.0), f(a.1, b.1), f(a.2, b.2))
+}
+
+/// The dark and light ends of a gray ramp around bg, the foreground giving the ramp its tint
+pub(crate) fn ramp_ends(bg: (u8, u8, u8), fg: (u8, u8, u8)) -> ((u8, u8, u8), (u8, u8, u8)) {
+    if is_dark(bg) {
+        (mix(bg, (0, 0, 0), 0.4), mix(bg, fg, 0.3))
+    } else {
+        (mix(bg, fg, 0.5), mix(bg, (255, 255, 255), 0.5))
+    }
+}
+
+impl ColorTheme {
+    /**
+    Parses a theme from a TOML string. Colors are read from the `[colors]` table,
+    keys are either FLTK color indices or names such as `background`, `foreground` and `selection`:
+    ```toml
+    [colors]
+    background = "#2e3440"
+    foreground = "#d8dee9"
+    15 = "#5e81ac"
+    ```
+    Colors may also be written `#rrggbbaa`, giving a translucent colormap (see `ColorMap::a`).
+    An optional `[meta]` table gives the theme its info (see `info()`), the variant being
+    worked out from the background if not given:
+    ```toml
+    [meta]
+    name = "Nord"
+    author = "Arctic Ice Studio"
+    variant = "dark"
+    version = "0.2.0"
+    description = "An arctic, north-bluish color palette"
+    ```
+    Other tables are ignored.
+    */
+    pub fn from_toml(s: &str) -> Result<ColorTheme, ThemeError> {
+        let mut map = vec![];
+        let mut info: Option<ThemeInfo> = None;
+        let mut variant = None;
+        let mut in_colors = false;
+        let mut in_meta = false;
+        for (i, line) in s.lines().enumerate() {
+            let line_no = i + 1;
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with('[') {
+                let table = line
+                    .strip_prefix('[')
+                    .and_then(|l| l.strip_suffix(']'))
+                    .ok_or_else(|| ThemeError::Syntax {
+                        line: line_no,
+                        message: format!("unterminated table header `{}`", line),
+                    })?;
+                in_colors = table.trim() == "colors";
+                in_meta = table.trim() == "meta";
+                if in_meta {
+                    info.get_or_insert_with(ThemeInfo::default);
+                }
+                continue;
+            }
+            if in_meta {
+                let (key, value) = line.split_once('=').ok_or_else(|| ThemeError::Syntax {
+                    line: line_no,
+                    message: format!("expected `key = \"value\"`, found `{}`", line),
+                })?;
+                let key = key.trim();
+                let key = unquote(key).unwrap_or(key);
+                let value = value.trim();
+                let value = unquote(value).ok_or_else(|| ThemeError::Syntax {
+                    line: line_no,
+                    message: format!("expected a quoted string, found `{}`", value),
+                })?;
+                let info = info.get_or_insert_with(ThemeInfo::default);
+                match key {
+                    "name" => info.name = value.to_string(),
+                    "author" => info.author = Some(value.to_string()),
+                    "version" => info.version = Some(value.to_string()),
+                    "description" => info.description = Some(value.to_string()),
+                    "variant" => {
+                        variant = Some(Variant::parse(value).ok_or_else(|| ThemeError::Syntax {
+                            line: line_no,
+                            message: format!(
+                                "expected `dark` or `light` as the variant, found `{}`",
+                                value
+                            ),
+                        })?)
+                    }
+                    _ => (),
+                }
+                continue;
+            }
+            if !in_colors {
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| ThemeError::Syntax {
+                line: line_no,
+                message: format!("expected `key = \"#rrggbb\"`, found `{}`", line),
+            })?;
+            let key = key.trim();
+            let key = unquote(key).unwrap_or(key).to_string();
+            let index = index_of(&key).ok_or_else(|| ThemeError::InvalidIndex {
+                line: line_no,
+                key: key.clone(),
+            })?;
+            let value = value.trim();
+            let (r, g, b, a) =
+                unquote(value)
+                    .and_then(parse_hex_alpha)
+                    .ok_or_else(|| ThemeError::InvalidHex {
+                        line: line_no,
+                        key: key.clone(),
+                        value: value.to_string(),
+                    })?;
+            map.push(ColorMap { index, r, g, b, a });
+        }
+        let mut theme = ColorTheme::from(map);
+        if let Some(mut info) = info {
+            info.variant = variant.unwrap_or_else(|| Variant::of(&theme));
+            theme = theme.with_info(info);
+        }
+        Ok(theme)
+    }
+
+    /**
+    Serializes the theme to the TOML format read by `from_toml`, with a `[meta]` table if it has
+    info. Translucent colormaps are written `#rrggbbaa`, so they load back as they were:
+    ```rust
+    use fltk_theme::{cmap_a, ColorMap, ColorTheme};
+    let theme = ColorTheme::from(vec![cmap_a!(15, 0x5e, 0x81, 0xac, 0x80)]);
+    let toml = theme.to_toml();
+    assert!(toml.contains("\"#5e81ac80\""));
+    assert_eq!(ColorTheme::from_toml(&toml).unwrap().colors[0].a, 0x80);
+    ```
+    */
+    pub fn to_toml(&self) -> String {
+        let mut s = String::new();
+        if let Some(info) = self.info() {
+            s += "[meta]\n";
+            s += &format!("name = \"{}\"\n", info.name);
+            if let Some(author) = &info.author {
+                s += &format!("author = \"{}\"\n", author);
+            }
+            s += &format!("variant = \"{}\"\n", info.variant.as_str());
+            if let Some(version) = &info.version {
+                s += &format!("version = \"{}\"\n", version);
+            }
+            if let Some(description) = &info.description {
+                s += &format!("description = \"{}\"\n", description);
+            }
+            s += "\n";
+        }
+        s += "[colors]\n";
+        for elem in &self.colors {
+            let mut hex = format!("#{:02x}{:02x}{:02x}", elem.r, elem.g, elem.b);
+            if elem.a != 255 {
+                hex += &format!("{:02x}", elem.a);
+            }
+            match name_of(elem.index) {
+                Some(name) => s += &format!("{} = \"{}\"\n", name, hex),
+                None => s += &format!("{} = \"{}\"\n", elem.index, hex),
+            }
+        }
+        s
+    }
+
+    /// Loads a theme from a TOML file, see `from_toml` for the format, a `[meta]` table without
+    /// a name being given the file's. With the `json` feature, `.json` files are read using
+    /// `from_json`
+    pub fn load(path: &Path) -> Result<ColorTheme, ThemeError> {
+        let s = fs::read_to_string(path)?;
+        #[cfg(feature = "json")]
+        if is_json(path) {
+            return ColorTheme::from_json(&s);
+        }
+        let mut theme = ColorTheme::from_toml(&s)?;
+        if let (Some(info), Some(stem)) = (&mut theme.info, path.file_stem()) {
+            if info.name.is_empty() {
+                info.name = stem.to_string_lossy().into_owned();
+            }
+        }
+        Ok(theme)
+    }
+
+    /// Saves the theme to a TOML file which can be read back using `load`.
+    /// With the `json` feature, `.json` files are written using `to_json`
+    pub fn save(&self, path: &Path) -> Result<(), ThemeError> {
+        #[cfg(feature = "json")]
+        if is_json(path) {
+            fs::write(path, self.to_json())?;
+            return Ok(());
+        }
+        fs::write(path, self.to_toml())?;
+        Ok(())
+    }
+
+    /**
+    Parses a theme from JSON, an array of `{"index": 49, "r": 46, "g": 52, "b": 64}` objects,
+    with an optional `"a"` for translucent colormaps (255, opaque, by default).
+    Unknown fields are ignored, such as the `name` written by `to_json`.
+    */
+    #[cfg(feature = "json")]
+    pub fn from_json(s: &str) -> Result<ColorTheme, ThemeError> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Serializes the theme to the JSON format read by `from_json`, adding a `name` to the
+    /// colormaps whose index has one (see `from_toml`)
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        let maps: Vec<_> = self
+            .colors
+            .iter()
+            .map(|elem| {
+                let mut obj = serde_json::Map::new();
+                if let Some(name) = name_of(elem.index) {
+                    obj.insert("name".to_string(), name.into());
+                }
+                obj.insert("index".to_string(), elem.index.into());
+                obj.insert("r".to_string(), elem.r.into());
+                obj.insert("g".to_string(), elem.g.into());
+                obj.insert("b".to_string(), elem.b.into());
+                obj.insert("a".to_string(), elem.a.into());
+                serde_json::Value::Object(obj)
+            })
+            .collect();
+        serde_json::to_string_pretty(&maps).expect("a ColorTheme always serializes")
+    }
+}
@@ -0,0 +1,80 @@
+use crate::colors::is_dark;
+use crate::{ColorTheme, WidgetTheme};
+use fltk::{app, enums::Color, prelude::*};
+
+#[cfg(target_os = "windows")]
+mod dwm {
+    use std::os::raw::c_void;
+
+    /// DWMWA_USE_IMMERSIVE_DARK_MODE, 19 before Windows 10 20H1
+    pub const USE_IMMERSIVE_DARK_MODE: u32 = 20;
+    pub const USE_IMMERSIVE_DARK_MODE_BEFORE_20H1: u32 = 19;
+
+    #[link(name = "dwmapi")]
+    extern "system" {
+        pub fn DwmSetWindowAttribute(
+            hwnd: *mut c_void,
+            attribute: u32,
+            value: *const c_void,
+            size: u32,
+        ) -> i32;
+    }
+}
+
+/**
+Makes the native title bar of a shown window dark or light, on Windows 10 1809 and later.
+Does nothing on other platforms, where the title bar follows the desktop's theme
+*/
+pub fn set_dark_titlebar<W: WindowExt>(win: &W, dark: bool) {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::raw::c_void;
+        let hwnd = win.raw_handle();
+        if hwnd.is_null() {
+            return;
+        }
+        let value: i32 = dark as i32;
+        let set = |attribute| unsafe {
+            dwm::DwmSetWindowAttribute(
+                hwnd,
+                attribute,
+                &value as *const i32 as *const c_void,
+                std::mem::size_of::<i32>() as u32,
+            )
+        };
+        // the attribute was renumbered, a failure (negative HRESULT) meaning an older build
+        if set(dwm::USE_IMMERSIVE_DARK_MODE) < 0 {
+            set(dwm::USE_IMMERSIVE_DARK_MODE_BEFORE_20H1);
+        }
+    }
+    #[cfg(not(target_os = "windows"))]
+    let _ = (win, dark);
+}
+
+/// Updates the title bars of the shown windows after the background color
+fn update_titlebars() {
+    let dark = is_dark(Color::Background.to_rgb());
+    for win in app::windows().unwrap_or_default() {
+        if win.shown() {
+            set_dark_titlebar(&win, dark);
+        }
+    }
+}
+
+impl ColorTheme {
+    /// Applies the theme, then makes the title bars of the shown windows dark if the background
+    /// is dark (see `set_dark_titlebar`)
+    pub fn apply_with_windows(&self) {
+        self.apply();
+        update_titlebars();
+    }
+}
+
+impl WidgetTheme {
+    /// Applies the theme, then makes the title bars of the shown windows dark if the background
+    /// is dark (see `set_dark_titlebar`)
+    pub fn apply_with_windows(&self) {
+        self.apply();
+        update_titlebars();
+    }
+}
@@ -0,0 +1,193 @@
+use crate::colors::contrast_ratio;
+use crate::roles::selection_text;
+use crate::ColorTheme;
+use std::fmt;
+
+/// The minimum contrast ratio of the foreground against the background
+const TEXT_CONTRAST: f32 = 4.5;
+
+/// The minimum contrast ratio of the text of selections against the selection
+const SELECTION_CONTRAST: f32 = 3.0;
+
+/// The indices a theme must set, with their names
+const ESSENTIAL: &[(u8, &str)] = &[
+    (0, "foreground"),
+    (15, "selection"),
+    (32, "the dark end of the gray ramp"),
+    (49, "background"),
+    (55, "the light end of the gray ramp"),
+];
+
+/// A problem found by `ColorTheme::validate`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeIssue {
+    /// An index is set more than once, the last value being the one `apply()` leaves in place
+    DuplicateIndex {
+        /// The index
+        index: u8,
+        /// How many times it's set
+        count: usize,
+        /// The value which wins
+        value: (u8, u8, u8),
+    },
+    /// An index needed for readable widgets isn't set
+    MissingIndex {
+        /// The index
+        index: u8,
+        /// What the index is for
+        name: &'static str,
+    },
+    /// Two colors drawn on each other don't contrast enough
+    LowContrast {
+        /// The color drawn on top, e.g. `foreground`
+        text: &'static str,
+        /// The color drawn under it, e.g. `background`
+        background: &'static str,
+        /// Their WCAG contrast ratio
+        ratio: f32,
+        /// The minimum ratio
+        minimum: f32,
+    },
+}
+
+impl fmt::Display for ThemeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ThemeIssue::DuplicateIndex {
+                index,
+                count,
+                value: (r, g, b),
+            } => write!(
+                f,
+                "index {} is set {} times, the last value #{:02x}{:02x}{:02x} wins",
+                index, count, r, g, b
+            ),
+            ThemeIssue::MissingIndex { index, name } => {
+                write!(f, "index {} ({}) isn't set", index, name)
+            }
+            ThemeIssue::LowContrast {
+                text,
+                background,
+                ratio,
+                minimum,
+            } => write!(
+                f,
+                "{} on {} has a contrast of {:.2}:1, below {}:1",
+                text, background, ratio, minimum
+            ),
+        }
+    }
+}
+
+impl ColorTheme {
+    /**
+    Checks whether the theme gives readable widgets, e.g. before applying a theme file written by
+    the user, failing with every problem found:
+    - indices set more than once
+    - missing essential indices: foreground (0), selection (15), background (49) and the ends of
+      the gray ramp (32 and 55)
+    - the foreground contrasting less than 4.5:1 with the background or background2 (7)
+    - the text of selections contrasting less than 3:1 with the selection. FLTK draws it in the
+      foreground when the two differ enough in lightness, otherwise in FL_BLACK (56) or FL_WHITE
+      (255), which the theme may change too
+    ```rust,no_run
+    use fltk_theme::ColorTheme;
+    let theme = ColorTheme::load(std::path::Path::new("user.toml")).unwrap();
+    match theme.validate() {
+        Ok(()) => theme.apply(),
+        Err(issues) => {
+            for issue in issues {
+                eprintln!("user.toml: {}", issue);
+            }
+        }
+    }
+    ```
+    */
+    pub fn validate(&self) -> Result<(), Vec<ThemeIssue>> {
+        let mut issues = vec![];
+        let mut counts = [0; 256];
+        for elem in &self.colors {
+            counts[elem.index as usize] += 1;
+        }
+        let mut reported = [false; 256];
+        for elem in &self.colors {
+            let i = elem.index as usize;
+            if counts[i] > 1 && !reported[i] {
+                reported[i] = true;
+                issues.push(ThemeIssue::DuplicateIndex {
+                    index: elem.index,
+                    count: counts[i],
+                    value: self.value(elem.index).unwrap_or_default(),
+                });
+            }
+        }
+        for (index, name) in ESSENTIAL {
+            if counts[*index as usize] == 0 {
+                issues.push(ThemeIssue::MissingIndex {
+                    index: *index,
+                    name,
+                });
+            }
+        }
+        let mut check = |text: &'static str, background: &'static str, ratio: f32, minimum| {
+            if ratio < minimum {
+                issues.push(ThemeIssue::LowContrast {
+                    text,
+                    background,
+                    ratio,
+                    minimum,
+                });
+            }
+        };
+        if let Some(fg) = self.value(0) {
+            if let Some(bg) = self.value(49) {
+                check(
+                    "foreground",
+                    "background",
+                    contrast_ratio(fg, bg),
+                    TEXT_CONTRAST,
+                );
+            }
+            if let Some(bg2) = self.value(7) {
+                check(
+                    "foreground",
+                    "background2",
+                    contrast_ratio(fg, bg2),
+                    TEXT_CONTRAST,
+                );
+            }
+            if let Some(selection) = self.value(15) {
+                let black = self.value(56).unwrap_or((0, 0, 0));
+                let white = self.value(255).unwrap_or((255, 255, 255));
+                let text = selection_text(fg, selection, black, white);
+                let name = if text == fg {
+                    "foreground"
+                } else if text == black {
+                    "black (56)"
+                } else {
+                    "white (255)"
+                };
+                check(
+                    name,
+                    "selection",
+                    contrast_ratio(text, selection),
+                    SELECTION_CONTRAST,
+                );
+            }
+        }
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// The value of an index, the last colormap of an index being the one apply() leaves in place
+    pub(crate) fn value(&self, index: u8) -> Option<(u8, u8, u8)> {
+        self.colors
+            .iter()
+            .rev()
+            .find(|c| c.index == index)
+            .map(|c| (c.r, c.g, c.b))
+    }
+}
@@ -0,0 +1,86 @@
+use crate::seeds::{from_oklab_in_gamut, to_oklab};
+use crate::{ColorMap, ColorTheme, Variant};
+
+/// The OKLab lightness white maps to in a dark variant, that of #1e1e1e
+const DARK_BACKGROUND: f32 = 0.2351;
+
+/// The OKLab lightness black maps to in a dark variant, that of #e8e8e8
+const DARK_FOREGROUND: f32 = 0.9313;
+
+/// Colors with less OKLab chroma than this are treated as grays
+const GRAY_CHROMA: f32 = 0.03;
+
+/// How much of the inversion colors (the selection, accents and named colors) get, so that a dark
+/// blue stays a blue rather than turning pale
+const COLOR_INVERSION: f32 = 0.5;
+
+impl ColorTheme {
+    fn map_lightness(&self, f: impl Fn(f32) -> f32) -> ColorTheme {
+        let mut theme = self.clone();
+        theme.colors = self
+            .colors
+            .iter()
+            .map(|c| {
+                // FL_BLACK and FL_WHITE keep their meaning, as in the built-in dark themes
+                if c.index == 56 || c.index == 255 {
+                    return c.clone();
+                }
+                let [l, a, b] = to_oklab((c.r, c.g, c.b));
+                let target = f(l).clamp(0.0, 1.0);
+                let l = if a.hypot(b) < GRAY_CHROMA {
+                    target
+                } else {
+                    l + (target - l) * COLOR_INVERSION
+                };
+                let (r, g, b) = from_oklab_in_gamut([l, a, b]);
+                ColorMap {
+                    index: c.index,
+                    r,
+                    g,
+                    b,
+                    a: c.a,
+                }
+            })
+            .collect();
+        theme
+    }
+
+    /**
+    Derives a dark variant of a light theme, returning a copy of the theme if it's already dark
+    (see `is_dark`). The lightness of every color is inverted in OKLab, keeping its hue and
+    chroma, white going to #1e1e1e and black to #e8e8e8 rather than to the extremes.
+    Colors other than grays (the selection, accents and named colors) only move halfway, so they
+    stay recognizable, and FL_BLACK (56) and FL_WHITE (255) are left as they are:
+    ```rust,no_run
+    use fltk_theme::{color_themes, ColorTheme};
+    ColorTheme::new(color_themes::TAN_THEME).to_dark().apply();
+    ```
+    This won't match a palette designed for the dark, but gives a usable one when only the light
+    theme exists.
+    */
+    pub fn to_dark(&self) -> ColorTheme {
+        if self.is_dark() {
+            return self.clone();
+        }
+        self.map_lightness(|l| DARK_FOREGROUND - l * (DARK_FOREGROUND - DARK_BACKGROUND))
+            .with_variant(Variant::Dark)
+    }
+
+    /// Derives a light variant of a dark theme, the reverse of `to_dark`: #1e1e1e goes to white
+    /// and #e8e8e8 to black. Returns a copy of the theme if it's already light
+    pub fn to_light(&self) -> ColorTheme {
+        if !self.is_dark() {
+            return self.clone();
+        }
+        self.map_lightness(|l| (DARK_FOREGROUND - l) / (DARK_FOREGROUND - DARK_BACKGROUND))
+            .with_variant(Variant::Light)
+    }
+
+    /// Updates the variant of the info the theme keeps from the one it's derived from
+    fn with_variant(mut self, variant: Variant) -> ColorTheme {
+        if let Some(info) = &mut self.info {
+            info.variant = variant;
+        }
+        self
+    }
+}
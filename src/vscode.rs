@@ -0,0 +1,194 @@
+use crate::colors::is_dark;
+use crate::ramp::{dark_end_through, gray_ramp_through};
+use crate::theme_file::{mix, parse_hex, ramp_ends};
+use crate::{ColorMap, ColorTheme, ThemeError};
+use std::{fs, path::Path};
+
+/// Turns JSONC into JSON by removing comments and trailing commas
+fn strip_jsonc(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => (),
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    if c == '\n' {
+                        out.push('\n');
+                    }
+                    last = c;
+                }
+            }
+            (c, _) => out.push(c),
+        }
+    }
+    // with the comments gone, a comma followed by whitespace and a closing bracket is trailing
+    let mut result = String::with_capacity(out.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in out.char_indices() {
+        if in_string {
+            match (escaped, c) {
+                (false, '\\') => escaped = true,
+                (false, '"') => in_string = false,
+                _ => escaped = false,
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let next = out[i + 1..].trim_start().chars().next();
+            if next == Some('}') || next == Some(']') {
+                continue;
+            }
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Parses `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`, pre-blending the alpha against bg
+fn parse_color(s: &str, bg: (u8, u8, u8)) -> Option<(u8, u8, u8)> {
+    let hex = s.strip_prefix('#')?;
+    let hex: String = match hex.len() {
+        3 | 4 => hex.chars().flat_map(|c| [c, c]).collect(),
+        6 | 8 => hex.to_string(),
+        _ => return None,
+    };
+    let col = parse_hex(&hex[..6])?;
+    if hex.len() == 8 {
+        let alpha = u8::from_str_radix(&hex[6..], 16).ok()?;
+        Some(mix(bg, col, alpha as f32 / 255.0))
+    } else {
+        Some(col)
+    }
+}
+
+const ANSI: [&str; 16] = [
+    "terminal.ansiBlack",
+    "terminal.ansiRed",
+    "terminal.ansiGreen",
+    "terminal.ansiYellow",
+    "terminal.ansiBlue",
+    "terminal.ansiMagenta",
+    "terminal.ansiCyan",
+    "terminal.ansiWhite",
+    "terminal.ansiBrightBlack",
+    "terminal.ansiBrightRed",
+    "terminal.ansiBrightGreen",
+    "terminal.ansiBrightYellow",
+    "terminal.ansiBrightBlue",
+    "terminal.ansiBrightMagenta",
+    "terminal.ansiBrightCyan",
+    "terminal.ansiBrightWhite",
+];
+
+impl ColorTheme {
+    /**
+    Builds a theme from a VS Code color theme file, reading its `colors` object (`tokenColors`
+    is ignored). Comments and trailing commas are accepted. The colors are mapped as follows:
+    - editor.background (or sideBar.background): background (49) and the center of the gray ramp
+    - editor.foreground (or foreground): foreground (0)
+    - input.background: background2 (7), the background of inputs
+    - disabledForeground: inactive (8)
+    - editor.selectionBackground, falling back to focusBorder then button.background: selection (15)
+    - contrastBorder (or panel.border): FL_DARK3 (39), where the borders of the gray ramp are
+    - terminal.ansiRed, ansiGreen ... ansiBrightWhite: FLTK's first 16 colors and its named colors
+
+    Colors with an alpha channel are blended against the background.
+    */
+    pub fn from_vscode_theme(path: &Path) -> Result<ColorTheme, ThemeError> {
+        ColorTheme::from_vscode_theme_str(&fs::read_to_string(path)?)
+    }
+
+    /// Builds a theme from the content of a VS Code color theme, see `from_vscode_theme`
+    pub fn from_vscode_theme_str(s: &str) -> Result<ColorTheme, ThemeError> {
+        let json: serde_json::Value = serde_json::from_str(&strip_jsonc(s))?;
+        let colors = json.get("colors");
+        let raw = |key: &str| colors.and_then(|c| c.get(key)).and_then(|v| v.as_str());
+        // a translucent background can only be blended against the black window behind it
+        let bg = ["editor.background", "sideBar.background"]
+            .iter()
+            .find_map(|key| raw(key).and_then(|v| parse_color(v, (0, 0, 0))))
+            .ok_or_else(|| ThemeError::MissingColor {
+                key: "colors.editor.background".to_string(),
+            })?;
+        let color = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| raw(key).and_then(|v| parse_color(v, bg)))
+        };
+        let fg = color(&["editor.foreground", "foreground"]).unwrap_or(if is_dark(bg) {
+            (255, 255, 255)
+        } else {
+            (0, 0, 0)
+        });
+        let ansi: Vec<_> = ANSI.iter().map(|key| color(&[*key])).collect();
+        let mut map = vec![];
+        let mut set = |index: u8, col: Option<(u8, u8, u8)>| {
+            if let Some((r, g, b)) = col {
+                map.push(ColorMap {
+                    index,
+                    r,
+                    g,
+                    b,
+                    a: 255,
+                });
+            }
+        };
+        set(0, Some(fg));
+        for n in (1..7).chain(9..15) {
+            set(n as u8, ansi[n]);
+        }
+        set(7, color(&["input.background"]).or(Some(bg)));
+        set(
+            8,
+            color(&["disabledForeground"]).or_else(|| Some(mix(bg, fg, 0.5))),
+        );
+        set(
+            15,
+            color(&[
+                "editor.selectionBackground",
+                "focusBorder",
+                "button.background",
+            ]),
+        );
+        set(56, ansi[0]);
+        set(63, ansi[2]);
+        set(88, ansi[1]);
+        set(95, ansi[3]);
+        set(216, ansi[4]);
+        set(223, ansi[6]);
+        set(248, ansi[5]);
+        set(255, ansi[15].or(ansi[7]));
+        let (dark, light) = ramp_ends(bg, fg);
+        let dark = color(&["contrastBorder", "panel.border"])
+            .map_or(dark, |border| dark_end_through(bg, border));
+        map.extend(gray_ramp_through(dark, bg, light));
+        Ok(ColorTheme::from(map))
+    }
+}
@@ -0,0 +1,93 @@
+use crate::theme_dir::load_file;
+use crate::ThemeError;
+use fltk::app;
+use std::{
+    fs,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, SystemTime},
+};
+
+type ErrorCallback = Arc<Mutex<Option<Box<dyn FnMut(ThemeError) + Send>>>>;
+
+/// Identifies a version of the file, None while it's missing (editors often replace files)
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+/**
+Reapplies a theme file whenever it changes, for designing themes while the app is running:
+```rust,no_run
+use fltk_theme::ThemeWatcher;
+use std::{path::Path, time::Duration};
+let _watcher = ThemeWatcher::new(Path::new("mytheme.toml"), Duration::from_millis(500))
+    .on_error(|e| eprintln!("mytheme.toml: {}", e));
+```
+The file is polled from a background thread, and loaded and applied on the main thread through
+`app::awake_callback`, followed by `app::redraw()`. Any format recognized by `ThemeDir` is accepted.
+Watching stops when the watcher is dropped.
+*/
+pub struct ThemeWatcher {
+    stop: Arc<AtomicBool>,
+    on_error: ErrorCallback,
+}
+
+impl ThemeWatcher {
+    /// Starts polling path every interval. The theme isn't applied until the file changes
+    pub fn new(path: &Path, interval: Duration) -> ThemeWatcher {
+        let stop = Arc::new(AtomicBool::new(false));
+        let on_error: ErrorCallback = Arc::new(Mutex::new(None));
+        let path = path.to_path_buf();
+        let watcher = ThemeWatcher {
+            stop: stop.clone(),
+            on_error: on_error.clone(),
+        };
+        thread::spawn(move || {
+            let mut last = stamp(&path);
+            while !stop.load(Ordering::Relaxed) {
+                thread::sleep(interval);
+                let current = stamp(&path);
+                if current == last || current.is_none() {
+                    continue;
+                }
+                last = current;
+                let path = path.clone();
+                let on_error = on_error.clone();
+                app::awake_callback(move || match load_file(&path) {
+                    Ok(theme) => {
+                        theme.apply();
+                        app::redraw();
+                    }
+                    Err(e) => {
+                        if let Some(cb) = on_error.lock().unwrap().as_mut() {
+                            cb(e);
+                        }
+                    }
+                });
+            }
+        });
+        watcher
+    }
+
+    /// Sets the callback receiving the errors of the reloads, called on the main thread
+    pub fn on_error<F: FnMut(ThemeError) + Send + 'static>(self, cb: F) -> Self {
+        *self.on_error.lock().unwrap() = Some(Box::new(cb));
+        self
+    }
+
+    /// Stops watching the file, which dropping the watcher also does
+    pub fn stop(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for ThemeWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
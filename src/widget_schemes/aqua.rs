@@ -6,19 +6,59 @@ use fltk::{
 fn up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
     let col1 = c.to_rgb();
     let col = Color::color_average(c, Color::Background, 0.8).to_rgb();
-    draw::draw_rbox(x, y, w, h, 5, true, Color::from_rgb(col.0, col.1, col.2));
+    draw::draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        super::corner_radius(5, w, h),
+        true,
+        Color::from_rgb(col.0, col.1, col.2),
+    );
 }
 
 fn default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
     let col1 = c.to_rgb();
-    let col = Color::color_average(c, Color::Background, 0.8).to_rgb();
-    draw::draw_rbox(x, y, w, h, 5, true, Color::from_rgb(col.0, col.1, col.2));
+    let col = super::configured_accent_color()
+        .unwrap_or_else(|| Color::color_average(c, Color::Background, 0.8))
+        .to_rgb();
+    draw::draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        super::corner_radius(5, w, h),
+        true,
+        Color::from_rgb(col.0, col.1, col.2),
+    );
 }
 
 fn down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
     let col1 = c.to_rgb();
     let col = Color::color_average(c, Color::Background, 0.8).to_rgb();
-    draw::draw_rbox(x, y, w, h, 5, true, Color::from_rgb(col.0, col.1, col.2));
+    draw::draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        super::corner_radius(5, w, h),
+        true,
+        Color::from_rgb(col.0, col.1, col.2),
+    );
+}
+
+fn text_field_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let r = super::corner_radius(3, w, h);
+    draw::draw_rbox(x, y, w, h, r, true, crate::activated_color(c));
+    draw::draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        r,
+        false,
+        crate::activated_color(Color::color_average(Color::Black, Color::Background, 0.3)),
+    );
 }
 
 fn radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
@@ -32,7 +72,7 @@ fn border_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
         y,
         w,
         h,
-        5,
+        super::corner_radius(5, w, h),
         true,
         *crate::colors::aqua::dark::systemBlueColor,
     );
@@ -43,7 +83,8 @@ fn use_scheme() {
     // app::set_menu_linespacing(5);
     app::set_frame_type_cb(FrameType::UpBox, up_box, 2, 2, 4, 4);
     app::set_frame_type_cb(FrameType::DiamondUpBox, default_button_up_box, 2, 2, 4, 4);
-    app::set_frame_type_cb(FrameType::DownBox, down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::DownBox, text_field_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::ThinDownBox, text_field_box, 1, 1, 2, 2);
     app::set_frame_type_cb(FrameType::DiamondDownBox, down_box, 2, 2, 4, 4);
     app::set_frame_type_cb(FrameType::RoundDownBox, radio_round_down_box, 2, 2, 4, 4);
     app::set_frame_type_cb(FrameType::BorderBox, border_box, 2, 2, 4, 4);
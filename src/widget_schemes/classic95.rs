@@ -0,0 +1,122 @@
+use super::*;
+
+/// The four bevel colors of Windows 95 derived from the face color:
+/// highlight, light, shadow and dark shadow
+fn bevel_colors(c: Color) -> (Color, Color, Color, Color) {
+    (
+        activated_color(c.lighter().lighter()),
+        activated_color(c.lighter()),
+        activated_color(c.darker()),
+        activated_color(c.darker().darker()),
+    )
+}
+
+fn edge(x: i32, y: i32, w: i32, h: i32, top_left: Color, bottom_right: Color) {
+    set_draw_color(top_left);
+    draw_xyline(x, y, x + w - 2);
+    draw_yxline(x, y, y + h - 2);
+    set_draw_color(bottom_right);
+    draw_xyline(x, y + h - 1, x + w - 1);
+    draw_yxline(x + w - 1, y, y + h - 1);
+}
+
+fn fill(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x, y, w, h);
+}
+
+fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let (highlight, light, shadow, dark) = bevel_colors(c);
+    edge(x, y, w, h, highlight, dark);
+    edge(x + 1, y + 1, w - 2, h - 2, light, shadow);
+}
+
+fn up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    fill(x + 2, y + 2, w - 4, h - 4, c);
+    up_frame(x, y, w, h, c);
+}
+
+fn down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let (highlight, light, shadow, dark) = bevel_colors(c);
+    edge(x, y, w, h, shadow, highlight);
+    edge(x + 1, y + 1, w - 2, h - 2, dark, light);
+}
+
+fn down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    fill(x + 2, y + 2, w - 4, h - 4, c);
+    down_frame(x, y, w, h, c);
+}
+
+fn thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let (highlight, _, shadow, _) = bevel_colors(c);
+    edge(x, y, w, h, highlight, shadow);
+}
+
+fn thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    fill(x + 1, y + 1, w - 2, h - 2, c);
+    thin_up_frame(x, y, w, h, c);
+}
+
+fn thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let (highlight, _, shadow, _) = bevel_colors(c);
+    edge(x, y, w, h, shadow, highlight);
+}
+
+fn thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    fill(x + 1, y + 1, w - 2, h - 2, c);
+    thin_down_frame(x, y, w, h, c);
+}
+
+fn engraved_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let (highlight, _, shadow, _) = bevel_colors(c);
+    edge(x, y, w - 1, h - 1, shadow, shadow);
+    edge(x + 1, y + 1, w - 1, h - 1, highlight, highlight);
+}
+
+fn engraved_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    fill(x + 2, y + 2, w - 4, h - 4, c);
+    engraved_frame(x, y, w, h, c);
+}
+
+fn default_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // default buttons get an extra dark shadow outline around the bevel
+    let (_, _, _, dark) = bevel_colors(c);
+    set_draw_color(dark);
+    draw_rect(x, y, w, h);
+    up_frame(x + 1, y + 1, w - 2, h - 2, c);
+}
+
+fn default_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    fill(x + 3, y + 3, w - 6, h - 6, c);
+    default_frame(x, y, w, h, c);
+}
+
+fn default_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // pressed default buttons: dark outline with a single shadow line inside
+    let (_, _, shadow, dark) = bevel_colors(c);
+    fill(x + 2, y + 2, w - 4, h - 4, c);
+    set_draw_color(dark);
+    draw_rect(x, y, w, h);
+    set_draw_color(shadow);
+    draw_rect(x + 1, y + 1, w - 2, h - 2);
+}
+
+pub(crate) fn use_classic95_scheme() {
+    use fltk::enums::FrameType::*;
+    app::reload_scheme().ok();
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(UpBox, up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(DownBox, down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(UpFrame, up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(DownFrame, down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(ThinUpBox, thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(ThinDownBox, thin_down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(ThinUpFrame, thin_up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(ThinDownFrame, thin_down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(EngravedBox, engraved_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(EngravedFrame, engraved_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(DiamondUpBox, default_box, 3, 3, 6, 6);
+    app::set_frame_type_cb(DiamondDownBox, default_down_box, 2, 2, 4, 4);
+    // the dotted focus rectangle is part of the look
+    app::set_visible_focus(true);
+}
@@ -1,12 +1,22 @@
 use super::*;
 
 fn rect(x: i32, y: i32, w: i32, h: i32, c: Color) {
-    draw_rect_fill(x, y, w, h, c);
+    let r = corner_radius(0, w, h);
+    if r > 0 {
+        draw_rbox(x, y, w, h, r, false, c);
+    } else {
+        draw_rect_fill(x, y, w, h, c);
+    }
 }
 
 fn rectf(x: i32, y: i32, w: i32, h: i32, c: Color) {
-    set_draw_color(activated_color(c));
-    draw_rectf(x, y, w, h);
+    let r = corner_radius(0, w, h);
+    if r > 0 {
+        draw_rbox(x, y, w, h, r, true, activated_color(c));
+    } else {
+        set_draw_color(activated_color(c));
+        draw_rectf(x, y, w, h);
+    }
 }
 
 fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
@@ -24,7 +34,11 @@ fn down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
 
 fn down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
     rectf(x, y, w, h, c);
-    rect(x, y, w, h, Color::color_average(Color::White, c, 0.2));
+    // soft inset: a faint shadow along the top and left inner edges
+    set_draw_color(activated_color(Color::color_average(Color::Black, c, 0.08)));
+    draw_xyline(x + 1, y + 1, x + w - 2);
+    draw_yxline(x + 1, y + 1, y + h - 2);
+    rect(x, y, w, h, Color::color_average(Color::Black, c, 0.2));
 }
 
 fn border_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
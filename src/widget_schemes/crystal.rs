@@ -1,9 +1,25 @@
 use super::*;
+use std::sync::atomic::AtomicU32;
+
+// 1.0 stored as the bits of an f32
+static INTENSITY: AtomicU32 = AtomicU32::new(0x3F80_0000);
+
+/// Scales how far the shading of the boxes deviates from the base color,
+/// 0.0 gives a flat fill and 1.0 the default look
+pub fn set_gradient_intensity(intensity: f32) {
+    INTENSITY.store(intensity.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+}
 
 fn shade_color(gc: Color, bc: Color) -> Color {
     Color::color_average(gc, bc, 0.25)
 }
 
+/// Like `shade_color` but scaled by the gradient intensity, used for the box interiors
+fn gradient_color(gc: Color, bc: Color) -> Color {
+    let intensity = f32::from_bits(INTENSITY.load(Ordering::Relaxed));
+    Color::color_average(gc, bc, 0.25 * intensity)
+}
+
 fn frame_rect(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
     let b = c.len() / 4 + 1;
     let b = b as i32;
@@ -172,13 +188,13 @@ fn shade_rect(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
             }
 
             // Draw the top line and points...
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(i as usize).unwrap() as i32),
                 bc,
             ));
             draw_xyline(x + 1, y + i, x + w - 2);
 
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(i as usize).unwrap() as i32 - 2),
                 bc,
             ));
@@ -186,13 +202,13 @@ fn shade_rect(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
             draw_point(x + w - 1, y + i + 1);
 
             // Draw the bottom line and points...
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(((clen - i) as usize) as usize).unwrap() as i32),
                 bc,
             ));
             draw_xyline(x + 1, y + h - i, x + w - 2);
 
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(((clen - i) as usize) as usize).unwrap() as i32 - 2),
                 bc,
             ));
@@ -206,7 +222,7 @@ fn shade_rect(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
         set_draw_color(bc);
         draw_rectf(x + 1, y + i, w - 2, h - 2 * i + 1);
 
-        set_draw_color(shade_color(
+        set_draw_color(gradient_color(
             Color::gray_ramp(c.chars().nth(chalf as usize).unwrap() as i32 - 2),
             bc,
         ));
@@ -225,13 +241,13 @@ fn shade_rect(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
                 break;
             }
             // Draw the left line and points...
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(i as usize).unwrap() as i32),
                 bc,
             ));
             draw_yxline(x + i, y + 1, y + h - 1);
 
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(i as usize).unwrap() as i32 - 2),
                 bc,
             ));
@@ -239,13 +255,13 @@ fn shade_rect(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
             draw_point(x + i + 1, y + h);
 
             // Draw the right line and points...
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth((clen - i) as usize).unwrap() as i32),
                 bc,
             ));
             draw_yxline(x + w - 1 - i, y + 1, y + h - 1);
 
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth((clen - i) as usize).unwrap() as i32 - 2),
                 bc,
             ));
@@ -256,13 +272,13 @@ fn shade_rect(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
         // Draw the interiors, top, and bottom...
         i = (chalf / cstep) as i32;
 
-        set_draw_color(shade_color(
+        set_draw_color(gradient_color(
             Color::gray_ramp(c.chars().nth(chalf as usize).unwrap() as i32),
             bc,
         ));
         draw_rectf(x + i, y + 1, w - 2 * i, h - 1);
 
-        set_draw_color(shade_color(
+        set_draw_color(gradient_color(
             Color::gray_ramp(c.chars().nth(chalf as usize).unwrap() as i32 - 2),
             bc,
         ));
@@ -285,14 +301,14 @@ fn shade_round(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
             let y = y + 1;
             let w = w - 2;
             let h = h - 2;
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(i as usize).unwrap() as i32),
                 bc,
             ));
             draw_pie(x, y, h, h, 90.0, 135.0 + (i * na) as f64);
             draw_xyline(x + d, y, x + w - d);
             draw_pie(x + w - h, y, h, h, 45.0 + (i * na) as f64, 90.0);
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(i as usize).unwrap() as i32 - 2),
                 bc,
             ));
@@ -304,14 +320,14 @@ fn shade_round(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
                 315.0 + (i * na) as f64,
                 405.0 + (i * na) as f64,
             );
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth((clen - i) as usize).unwrap() as i32),
                 bc,
             ));
             draw_pie(x + w - h, y, h, h, 270.0, 315.0 + (i * na) as f64);
             draw_xyline(x + d, y + h - 1, x + w - d);
             draw_pie(x, y, h, h, 225.0 + (i * na) as f64, 270.0);
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth((clen - i) as usize).unwrap() as i32 - 2),
                 bc,
             ));
@@ -330,19 +346,19 @@ fn shade_round(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
             let y = y + 1;
             let w = w - 2;
             let h = h - 2;
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(i as usize).unwrap() as i32),
                 bc,
             ));
             draw_pie(x, y, w, w, 45.0 + (i * na) as f64, 135.0 + (i * na) as f64);
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth(i as usize).unwrap() as i32 - 2),
                 bc,
             ));
             draw_pie(x, y, w, w, 0.0, 45.0 + (i * na) as f64);
             draw_yxline(x + w - 1, y + d, y + h - d);
             draw_pie(x, y + h - w, w, w, 315.0 + (i * na) as f64, 360.0);
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth((clen - i) as usize).unwrap() as i32),
                 bc,
             ));
@@ -354,7 +370,7 @@ fn shade_round(x: i32, y: i32, w: i32, h: i32, c: &str, bc: Color) {
                 225.0 + (i * na) as f64,
                 315.0 + (i * na) as f64,
             );
-            set_draw_color(shade_color(
+            set_draw_color(gradient_color(
                 Color::gray_ramp(c.chars().nth((clen - i) as usize).unwrap() as i32 - 2),
                 bc,
             ));
@@ -0,0 +1,105 @@
+use super::*;
+
+/// Margin given up on each side of the box so the shadow stays inside the widget's rect
+const MARGIN: i32 = 3;
+
+/// Shadow color derived from the background (which stands in for the parent's color),
+/// so it stays visible on dark color themes instead of being plain black
+fn shadow_color() -> Color {
+    Color::Background.darker().darker()
+}
+
+fn card(x: i32, y: i32, w: i32, h: i32) -> (i32, i32, i32, i32) {
+    (x + MARGIN, y + MARGIN - 1, w - 2 * MARGIN, h - 2 * MARGIN)
+}
+
+/// Draws `elevation` shadow steps below the card, the outermost being the faintest
+fn shadow(x: i32, y: i32, w: i32, h: i32, elevation: i32) {
+    let (cx, cy, cw, ch) = card(x, y, w, h);
+    set_draw_color(activated_color(Color::Background));
+    draw_rectf(x, y, w, h);
+    for i in (1..=elevation.min(MARGIN)).rev() {
+        let weight = 0.6 / i as f32;
+        let col = Color::color_average(shadow_color(), Color::Background, weight);
+        draw_rbox(
+            cx - i + 1,
+            cy - i + 2,
+            cw + 2 * (i - 1),
+            ch + 2 * i - 1,
+            corner_radius(4, cw, ch) + i - 1,
+            true,
+            activated_color(col),
+        );
+    }
+}
+
+fn draw_card(x: i32, y: i32, w: i32, h: i32, c: Color, fill: bool) {
+    let r = corner_radius(4, w, h);
+    if fill {
+        draw_rbox(x, y, w, h, r, true, activated_color(c));
+    }
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        r,
+        false,
+        activated_color(Color::color_average(shadow_color(), c, 0.25)),
+    );
+}
+
+fn up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    if w <= 2 * MARGIN || h <= 2 * MARGIN {
+        draw_card(x, y, w, h, c, true);
+        return;
+    }
+    shadow(x, y, w, h, 3);
+    let (cx, cy, cw, ch) = card(x, y, w, h);
+    draw_card(cx, cy, cw, ch, c, true);
+}
+
+fn down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    if w <= 2 * MARGIN || h <= 2 * MARGIN {
+        draw_card(x, y, w, h, c, true);
+        return;
+    }
+    // pressed: the card sinks towards the surface, leaving only a thin shadow
+    shadow(x, y, w, h, 1);
+    let (cx, cy, cw, ch) = card(x, y, w, h);
+    draw_card(cx, cy + 1, cw, ch, c, true);
+}
+
+fn thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    if w <= 2 * MARGIN || h <= 2 * MARGIN {
+        draw_card(x, y, w, h, c, true);
+        return;
+    }
+    shadow(x, y, w, h, 1);
+    let (cx, cy, cw, ch) = card(x, y, w, h);
+    draw_card(cx, cy, cw, ch, c, true);
+}
+
+fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let (cx, cy, cw, ch) = card(x, y, w, h);
+    draw_card(cx, cy, cw, ch, c, false);
+}
+
+fn down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let (cx, cy, cw, ch) = card(x, y, w, h);
+    draw_card(cx, cy + 1, cw, ch, c, false);
+}
+
+pub(crate) fn use_elevated_scheme() {
+    use fltk::enums::FrameType::*;
+    app::reload_scheme().ok();
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(UpBox, up_box, 4, 3, 8, 8);
+    app::set_frame_type_cb(DownBox, down_box, 4, 3, 8, 8);
+    app::set_frame_type_cb(ThinUpBox, thin_up_box, 4, 3, 8, 8);
+    app::set_frame_type_cb(ThinDownBox, down_box, 4, 3, 8, 8);
+    app::set_frame_type_cb(UpFrame, up_frame, 4, 3, 8, 8);
+    app::set_frame_type_cb(DownFrame, down_frame, 4, 3, 8, 8);
+    app::set_frame_type_cb(RoundUpBox, up_box, 4, 3, 8, 8);
+    app::set_frame_type_cb(RoundDownBox, down_box, 4, 3, 8, 8);
+}
@@ -0,0 +1,135 @@
+use super::*;
+use crate::widget_themes::*;
+
+fn border(c: Color) -> Color {
+    activated_color(c.darker())
+}
+
+fn fill(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x, y, w, h);
+}
+
+fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(border(c));
+    draw_rect(x, y, w, h);
+}
+
+fn up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    fill(x, y, w, h, c);
+    up_frame(x, y, w, h, c);
+}
+
+fn down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(border(c));
+    draw_rect(x, y, w, h);
+}
+
+fn down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    fill(x, y, w, h, Color::color_average(Color::Black, c, 0.08));
+    down_frame(x, y, w, h, c);
+}
+
+fn thin_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    fill(x, y, w, h, c);
+}
+
+fn round_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    set_draw_color(border(c));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::color_average(Color::Black, c, 0.08)));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    set_draw_color(border(c));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn oval_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, w.min(h) / 2, false, border(c));
+}
+
+fn oval_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, w.min(h) / 2, true, activated_color(c));
+    oval_frame(x, y, w, h, c);
+}
+
+fn oflat_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, w.min(h) / 2, true, activated_color(c));
+}
+
+fn rounded_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, corner_radius(4, w, h), false, border(c));
+}
+
+fn rounded_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, corner_radius(4, w, h), true, activated_color(c));
+    rounded_frame(x, y, w, h, c);
+}
+
+fn rflat_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, corner_radius(4, w, h), true, activated_color(c));
+}
+
+pub(crate) fn use_flat_scheme() {
+    use fltk::enums::FrameType::{self, *};
+    app::reload_scheme().ok();
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(UpBox, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(DownBox, down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(UpFrame, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(DownFrame, down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(ThinUpBox, thin_box, 0, 0, 0, 0);
+    app::set_frame_type_cb(ThinDownBox, thin_box, 0, 0, 0, 0);
+    app::set_frame_type_cb(ThinUpFrame, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(ThinDownFrame, down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(EngravedBox, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(EmbossedBox, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(EngravedFrame, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(EmbossedFrame, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(BorderBox, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(ShadowBox, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(ShadowFrame, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(RoundUpBox, round_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(RoundDownBox, round_down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(RoundedBox, rounded_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(RoundedFrame, rounded_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(RFlatBox, rflat_box, 0, 0, 0, 0);
+    app::set_frame_type_cb(RShadowBox, rounded_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OvalBox, oval_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OvalFrame, oval_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OShadowBox, oval_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(FrameType::OFlatBox, oflat_box, 0, 0, 0, 0);
+    // the widget theme frame types, so mixing with a widget theme leaves no gradients
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, thin_box, 0, 0, 0, 0);
+    app::set_frame_type_cb(OS_SPACER_THIN_DOWN_BOX, thin_box, 0, 0, 0, 0);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_FRAME, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_SPACER_THIN_DOWN_FRAME, down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_RADIO_ROUND_DOWN_BOX, round_down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_BOX, down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_HOVERED_UP_FRAME, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_FRAME, down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_INPUT_THIN_DOWN_BOX, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_INPUT_THIN_DOWN_FRAME, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_MINI_BUTTON_UP_BOX, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_MINI_DEPRESSED_DOWN_BOX, down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_MINI_BUTTON_UP_FRAME, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_MINI_DEPRESSED_DOWN_FRAME, down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_DEFAULT_BUTTON_UP_BOX, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_DEFAULT_HOVERED_UP_BOX, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_DEFAULT_DEPRESSED_DOWN_BOX, down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_TOOLBAR_BUTTON_HOVER_BOX, thin_box, 0, 0, 0, 0);
+    app::set_frame_type_cb(OS_TABS_BOX, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_SWATCH_BOX, up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_SWATCH_FRAME, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_SCROLLBAR_THUMB_BOX, thin_box, 0, 0, 0, 0);
+    app::set_frame_type_cb(OS_MENU_BAR_BOX, thin_box, 0, 0, 0, 0);
+}
@@ -1,13 +1,25 @@
 use super::*;
+use crate::hover::{hover_color, is_hovered};
+use crate::widget_themes::frame_has_focus;
 use fltk::{draw, enums::FrameType};
 
 fn rect(x: i32, y: i32, w: i32, h: i32, c: Color) {
-    draw_rect_fill(x, y, w, h, c);
+    let r = corner_radius(0, w, h);
+    if r > 0 {
+        draw_rbox(x, y, w, h, r, false, c);
+    } else {
+        draw_rect_fill(x, y, w, h, c);
+    }
 }
 
 fn rectf(x: i32, y: i32, w: i32, h: i32, c: Color) {
-    set_draw_color(activated_color(c));
-    draw_rectf(x, y, w, h);
+    let r = corner_radius(0, w, h);
+    if r > 0 {
+        draw_rbox(x, y, w, h, r, true, activated_color(c));
+    } else {
+        set_draw_color(activated_color(c));
+        draw_rectf(x, y, w, h);
+    }
 }
 
 fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
@@ -15,6 +27,9 @@ fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
 }
 
 fn up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    if is_hovered(x, y, w, h) {
+        rectf(x, y, w, h, hover_color(x, y, w, h, c));
+    }
     up_frame(x, y, w, h, c);
 }
 
@@ -24,7 +39,8 @@ fn default_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
         y,
         w,
         h,
-        Color::color_average(Color::Black, Color::White, 0.3),
+        configured_accent_color()
+            .unwrap_or_else(|| Color::color_average(Color::Black, Color::White, 0.3)),
     );
 }
 
@@ -43,6 +59,21 @@ fn down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
     down_frame(x, y, w, h, c);
 }
 
+fn text_field_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // flat field with a bottom line that turns into the accent color while focused
+    rectf(x, y, w, h, c);
+    rect(x, y, w, h, Color::color_average(Color::Black, c, 0.15));
+    if frame_has_focus(x, y, w, h) {
+        let (r, g, b, _) = colors::ACCENT_COLOR;
+        let accent = configured_accent_color().unwrap_or_else(|| Color::from_rgb(r, g, b));
+        set_draw_color(activated_color(accent));
+        draw_rectf(x + 1, y + h - 2, w - 2, 2);
+    } else {
+        set_draw_color(activated_color(Color::color_average(Color::Black, c, 0.45)));
+        draw_xyline(x + 1, y + h - 1, x + w - 2);
+    }
+}
+
 fn border_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
     rectf(x, y, w, h, c);
     rect(x, y, w, h, Color::color_average(Color::White, c, 0.2));
@@ -100,9 +131,9 @@ pub(crate) fn use_fluent_scheme() {
     use fltk::enums::FrameType::*;
     app::set_scheme(app::Scheme::Base);
     app::set_frame_type_cb(UpBox, up_box, 2, 2, 4, 4);
-    app::set_frame_type_cb(DownBox, down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(DownBox, text_field_box, 2, 2, 4, 4);
     app::set_frame_type_cb(ThinUpBox, up_box, 2, 2, 4, 4);
-    app::set_frame_type_cb(ThinDownBox, down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(ThinDownBox, text_field_box, 2, 2, 4, 4);
     app::set_frame_type_cb(UpFrame, up_frame, 2, 2, 4, 4);
     app::set_frame_type_cb(DownFrame, down_frame, 2, 2, 4, 4);
     app::set_frame_type_cb(RoundUpBox, round_box, 2, 2, 4, 4);
@@ -111,7 +142,8 @@ pub(crate) fn use_fluent_scheme() {
     app::set_frame_type2(OS_BUTTON_UP_FRAME, UpFrame);
     app::set_frame_type_cb(OS_DEFAULT_BUTTON_UP_BOX, default_up_box, 2, 2, 4, 4);
     app::set_frame_type2(OS_BUTTON_UP_BOX, UpBox);
-    app::set_frame_type2(OS_CHECK_DOWN_BOX, DownBox);
+    // check boxes keep the plain down box rather than the text field look
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, down_box, 2, 2, 4, 4);
     app::set_frame_type2(OS_CHECK_DOWN_FRAME, DownFrame);
     app::set_frame_type_cb(OS_HOVERED_UP_FRAME, hover_up_frame, 2, 2, 4, 4);
     app::set_frame_type_cb(OS_HOVERED_UP_BOX, hover_up_box, 2, 2, 4, 4);
@@ -122,6 +154,7 @@ pub(crate) fn use_fluent_scheme() {
     app::set_frame_type2(OS_DEFAULT_HOVERED_UP_BOX, OS_HOVERED_UP_BOX);
     app::set_frame_type2(OS_INPUT_THIN_DOWN_FRAME, DownFrame);
     app::set_frame_type2(OS_INPUT_THIN_DOWN_BOX, DownBox);
+    crate::hover::set_styled(true);
 }
 
 pub mod frames {
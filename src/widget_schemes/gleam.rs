@@ -1,4 +1,20 @@
 use super::*;
+use std::sync::atomic::AtomicU32;
+
+// 1.0 stored as the bits of an f32
+static INTENSITY: AtomicU32 = AtomicU32::new(0x3F80_0000);
+
+/// Scales how far the shine and the pressed shadow deviate from the base color,
+/// 0.0 gives a flat fill and 1.0 the default look
+pub fn set_gradient_intensity(intensity: f32) {
+    INTENSITY.store(intensity.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+}
+
+/// Averages bc with target as `Color::color_average` would, scaled by the gradient intensity
+fn shade(bc: Color, target: Color, weight: f32) -> Color {
+    let intensity = f32::from_bits(INTENSITY.load(Ordering::Relaxed));
+    Color::color_average(bc, target, 1.0 - (1.0 - weight) * intensity)
+}
 
 fn frame_rect(x: i32, y: i32, w: i32, h: i32, bc: Color) {
     // Draw the outline around the perimeter of the box
@@ -24,7 +40,7 @@ fn shade_rect_up(x: i32, y: i32, w: i32, h: i32, bc: Color) {
             break;
         }
         j += 1;
-        set_draw_color(activated_color(Color::color_average(bc, Color::White, k)));
+        set_draw_color(activated_color(shade(bc, Color::White, k)));
         draw_line(
             x,
             y + j + third as i32 - 1,
@@ -65,18 +81,10 @@ fn shade_rect_down(x: i32, y: i32, w: i32, h: i32, bc: Color) {
     set_draw_color(activated_color(bc));
     let color = get_color();
     draw_rectf(x, y, w, h);
-    set_draw_color(activated_color(Color::color_average(
-        bc,
-        color.darker(),
-        0.65,
-    )));
+    set_draw_color(activated_color(shade(bc, color.darker(), 0.65)));
     draw_line(x, y + 1, x + w, y + 1);
     draw_line(x, y + 1, x, y + h - 2);
-    set_draw_color(activated_color(Color::color_average(
-        bc,
-        color.darker(),
-        0.85,
-    )));
+    set_draw_color(activated_color(shade(bc, color.darker(), 0.85)));
     draw_line(x + 1, y + 2, x + w, y + 2);
     draw_line(x + 1, y + 2, x + 1, y + h - 2);
 }
@@ -86,16 +94,11 @@ fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
 }
 
 fn up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let c = crate::hover::hover_color(x, y, w, h, c);
     shade_rect_up(x + 1, y, w - 2, h - 1, c);
     frame_rect_up(x, y, w - 1, h - 1, c.darker());
     //draw the inner rect.
-    frame_rect(
-        x + 1,
-        y + 1,
-        w - 3,
-        h - 3,
-        Color::color_average(c, Color::White, 0.25),
-    );
+    frame_rect(x + 1, y + 1, w - 3, h - 3, shade(c, Color::White, 0.25));
 }
 
 fn down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
@@ -130,4 +133,5 @@ pub(crate) fn use_gleam_scheme() {
     app::set_frame_type_cb(RoundUpBox, up_box, 2, 2, 3, 3);
     app::set_frame_type_cb(RoundDownBox, down_box, 2, 2, 3, 3);
     app::set_frame_type_cb(BorderBox, border_box, 1, 1, 2, 2);
+    crate::hover::set_styled(true);
 }
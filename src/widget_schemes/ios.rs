@@ -0,0 +1,87 @@
+use super::*;
+
+fn separator_color(c: Color) -> Color {
+    activated_color(Color::color_average(Color::Foreground, c, 0.2))
+}
+
+/// Radius of a pill, computed per call since it has to follow the widget height
+fn pill_radius(w: i32, h: i32) -> i32 {
+    (h / 2).min(w / 2)
+}
+
+fn up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, pill_radius(w, h), true, activated_color(c));
+}
+
+fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, pill_radius(w, h), false, separator_color(c));
+}
+
+fn down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // switch-like track
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        pill_radius(w, h),
+        true,
+        activated_color(c.darker()),
+    );
+    draw_rbox(
+        x + 1,
+        y + 1,
+        w - 2,
+        h - 2,
+        pill_radius(w - 2, h - 2),
+        true,
+        activated_color(c),
+    );
+}
+
+fn down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        pill_radius(w, h),
+        false,
+        activated_color(c.darker()),
+    );
+}
+
+fn group_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // grouped list cell
+    draw_rbox(x, y, w, h, (h / 2).min(10), true, activated_color(c));
+}
+
+fn hairline_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(separator_color(c));
+    draw_xyline(x + 4, y + h - 1, x + w - 1);
+}
+
+fn round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    set_draw_color(separator_color(c));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+pub(crate) fn use_ios_scheme() {
+    use fltk::enums::FrameType::*;
+    app::reload_scheme().ok();
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(UpBox, up_box, 4, 1, 8, 2);
+    app::set_frame_type_cb(DownBox, down_box, 4, 2, 8, 4);
+    app::set_frame_type_cb(UpFrame, up_frame, 4, 1, 8, 2);
+    app::set_frame_type_cb(DownFrame, down_frame, 4, 1, 8, 2);
+    app::set_frame_type_cb(RoundUpBox, up_box, 4, 1, 8, 2);
+    app::set_frame_type_cb(RoundDownBox, round_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(ThinUpBox, group_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(ThinDownBox, group_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(ThinUpFrame, hairline_frame, 0, 0, 0, 1);
+    app::set_frame_type_cb(ThinDownFrame, hairline_frame, 0, 0, 0, 1);
+    app::set_frame_type_cb(BorderBox, group_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(BorderFrame, hairline_frame, 0, 0, 0, 1);
+}
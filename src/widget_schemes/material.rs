@@ -0,0 +1,160 @@
+use super::*;
+use crate::widget_themes::{frame_has_focus, OS_INPUT_THIN_DOWN_BOX, OS_INPUT_THIN_DOWN_FRAME};
+use fltk::prelude::WidgetExt;
+
+fn shadow_color() -> Color {
+    Color::color_average(Color::Black, Color::Background, 0.22)
+}
+
+fn outline_color() -> Color {
+    Color::color_average(Color::Foreground, Color::Background, 0.38)
+}
+
+fn contained(x: i32, y: i32, w: i32, h: i32, c: Color, elevation: i32) {
+    let r = corner_radius(4, w, h - elevation);
+    // the shadow grows with the elevation of the button
+    for i in (1..=elevation).rev() {
+        let col = Color::color_average(shadow_color(), Color::Background, 1.0 / i as f32);
+        draw_rbox(x, y + i, w, h - elevation, r, true, activated_color(col));
+    }
+    draw_rbox(x, y, w, h - elevation, r, true, activated_color(c));
+}
+
+fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        corner_radius(4, w, h),
+        false,
+        activated_color(outline_color()),
+    );
+}
+
+fn up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    contained(x, y, w, h, c, 2);
+}
+
+fn pressed_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    contained(x, y, w, h, Color::color_average(Color::Black, c, 0.12), 4);
+}
+
+fn thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    contained(x, y, w, h, c, 1);
+}
+
+fn is_pushed(x: i32, y: i32, w: i32, h: i32) -> bool {
+    if let Some(wid) = app::pushed() {
+        wid.x() == x && wid.y() == y && wid.w() == w && wid.h() == h
+    } else {
+        false
+    }
+}
+
+fn underline(x: i32, y: i32, w: i32, h: i32) {
+    let focused = frame_has_focus(x, y, w, h);
+    set_draw_color(activated_color(if focused {
+        Color::Selection
+    } else {
+        outline_color()
+    }));
+    draw_xyline(x, y + h - 1, x + w - 1);
+    if focused {
+        draw_xyline(x, y + h - 2, x + w - 1);
+    }
+}
+
+fn indicator_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, false, activated_color(outline_color()));
+    draw_rbox(
+        x + 1,
+        y + 1,
+        w - 2,
+        h - 2,
+        2,
+        false,
+        activated_color(outline_color()),
+    );
+}
+
+fn down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // small square down frames are check button indicators, not text fields
+    if w == h && w <= 20 {
+        indicator_frame(x, y, w, h, c);
+    } else {
+        underline(x, y, w, h);
+    }
+}
+
+fn down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // FLTK draws pressed buttons with the down box as well
+    if is_pushed(x, y, w, h) {
+        pressed_box(x, y, w, h, c);
+    } else if w == h && w <= 20 {
+        draw_rbox(x, y, w, h, 2, true, activated_color(c));
+        indicator_frame(x, y, w, h, c);
+    } else {
+        // filled text field, only the top corners are rounded
+        draw_rbox(x, y, w, h, corner_radius(4, w, h), true, activated_color(c));
+        set_draw_color(activated_color(c));
+        draw_rectf(x, y + h - 4, w, 4);
+        underline(x, y, w, h);
+    }
+}
+
+fn thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x, y, w, h);
+    underline(x, y, w, h);
+}
+
+fn round_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(shadow_color()));
+    draw_pie(x, y + 1, w, h - 1, 0.0, 360.0);
+    set_draw_color(activated_color(c));
+    draw_pie(x, y, w, h - 1, 0.0, 360.0);
+}
+
+fn round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    set_draw_color(activated_color(outline_color()));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+    draw_arc(x + 1, y + 1, w - 2, h - 2, 0.0, 360.0);
+}
+
+fn border_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, corner_radius(4, w, h), true, activated_color(c));
+    border_frame(x, y, w, h, c);
+}
+
+fn border_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        corner_radius(4, w, h),
+        false,
+        activated_color(outline_color()),
+    );
+}
+
+pub(crate) fn use_material_scheme() {
+    use fltk::enums::FrameType::*;
+    app::reload_scheme().ok();
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(UpBox, up_box, 2, 2, 4, 6);
+    app::set_frame_type_cb(DownBox, down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(ThinUpBox, thin_up_box, 1, 1, 2, 3);
+    app::set_frame_type_cb(ThinDownBox, thin_down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(UpFrame, up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(DownFrame, down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(RoundUpBox, round_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(RoundDownBox, round_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(BorderBox, border_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(BorderFrame, border_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_INPUT_THIN_DOWN_BOX, down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_INPUT_THIN_DOWN_FRAME, down_frame, 2, 2, 4, 4);
+}
@@ -3,10 +3,75 @@
 
 use crate::activated_color;
 use fltk::{app, draw::*, enums::Color};
+use std::sync::atomic::{AtomicI32, AtomicU32, Ordering};
 
 pub mod aqua;
+pub(crate) mod classic95;
 pub(crate) mod clean;
-pub(crate) mod crystal;
+pub mod crystal;
+pub(crate) mod elevated;
+pub(crate) mod flat;
 pub mod fluent;
-pub(crate) mod gleam;
+pub mod gleam;
+pub(crate) mod ios;
+pub(crate) mod material;
+pub(crate) mod neumorphic;
 pub(crate) mod svg_based;
+
+static CORNER_RADIUS: AtomicI32 = AtomicI32::new(-1);
+
+/// Sets the corner radius of the rounded frame types drawn by the schemes, 0 gives square corners
+pub fn set_corner_radius(radius: u8) {
+    CORNER_RADIUS.store(radius as i32, Ordering::Relaxed);
+}
+
+/// Restores the default corner radius of every scheme
+pub fn reset_corner_radius() {
+    CORNER_RADIUS.store(-1, Ordering::Relaxed);
+}
+
+/// Returns the corner radius set using `set_corner_radius`, if any
+pub(crate) fn configured_corner_radius() -> Option<i32> {
+    let r = CORNER_RADIUS.load(Ordering::Relaxed);
+    if r < 0 {
+        None
+    } else {
+        Some(r)
+    }
+}
+
+/// No accent configured, the colors being stored as 0xRRGGBB
+const NO_ACCENT: u32 = u32::MAX;
+
+static ACCENT: AtomicU32 = AtomicU32::new(NO_ACCENT);
+
+/// Sets the accent color used by the schemes and themes supporting it (Fluent, Aqua, Win11 and
+/// AquaClassic)
+pub fn set_accent_color(r: u8, g: u8, b: u8) {
+    ACCENT.store(u32::from_be_bytes([0, r, g, b]), Ordering::Relaxed);
+}
+
+/// Restores the default accent colors
+pub fn reset_accent_color() {
+    ACCENT.store(NO_ACCENT, Ordering::Relaxed);
+}
+
+/// Returns the accent color set using `set_accent_color`, if any
+pub(crate) fn configured_accent_color() -> Option<Color> {
+    match ACCENT.load(Ordering::Relaxed) {
+        NO_ACCENT => None,
+        rgb => {
+            let [_, r, g, b] = rgb.to_be_bytes();
+            Some(Color::from_rgb(r, g, b))
+        }
+    }
+}
+
+/// Returns the configured corner radius or the scheme's default, clamped to the box size
+pub(crate) fn corner_radius(default: i32, w: i32, h: i32) -> i32 {
+    configured_corner_radius()
+        .unwrap_or(default)
+        .min(w / 2)
+        .min(h / 2)
+        .max(0)
+}
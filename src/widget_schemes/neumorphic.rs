@@ -0,0 +1,140 @@
+use super::*;
+use crate::colors::relative_luminance;
+use fltk::{image, prelude::ImageExt};
+
+const RADIUS: i32 = 9;
+const STEPS: i32 = 4;
+
+/// Returns the light and dark shadow colors for c, clamped so both stay visible
+/// on very light and very dark backgrounds
+fn shadows(c: Color) -> (Color, Color) {
+    let l = relative_luminance(c.to_rgb());
+    let light = if l > 0.8 {
+        Color::White
+    } else if l < 0.02 {
+        Color::color_average(Color::White, c, 0.15)
+    } else {
+        c.lighter()
+    };
+    let dark = if l < 0.02 {
+        Color::Black
+    } else if l > 0.8 {
+        Color::color_average(Color::Black, c, 0.15)
+    } else {
+        c.darker()
+    };
+    (activated_color(light), activated_color(dark))
+}
+
+fn rgb(c: Color) -> String {
+    let (r, g, b) = c.to_rgb();
+    format!("rgb({}, {}, {})", r, g, b)
+}
+
+fn soft_svg(w: i32, h: i32, c: Color, radius: i32, inset: bool, fill: bool) -> String {
+    let (light, dark) = shadows(c);
+    // the light edge faces the top left for raised boxes and the bottom right for inset ones
+    let (from, to) = if inset { (dark, light) } else { (light, dark) };
+    let mut svg = format!(
+        "<svg viewBox='0 0 {} {}'>
+  <defs>
+    <linearGradient id='s' x1='0' y1='0' x2='1' y2='1'>
+      <stop offset='0' stop-color='{}' />
+      <stop offset='0.5' stop-color='{}' stop-opacity='0' />
+      <stop offset='1' stop-color='{}' />
+    </linearGradient>
+  </defs>\n",
+        w,
+        h,
+        rgb(from),
+        rgb(c),
+        rgb(to)
+    );
+    if fill {
+        svg += &format!(
+            "  <rect x='{s}' y='{s}' rx='{r}' width='{}' height='{}' fill='{}' />\n",
+            w - 2 * STEPS,
+            h - 2 * STEPS,
+            rgb(activated_color(c)),
+            s = STEPS,
+            r = radius,
+        );
+    }
+    // a few blended steps fading away from the edge instead of a hard line
+    for k in 0..STEPS {
+        let (off, opacity) = if inset {
+            (STEPS + k, 1.0 - k as f32 / STEPS as f32)
+        } else {
+            (STEPS - k, 1.0 - k as f32 / STEPS as f32)
+        };
+        svg += &format!(
+            "  <rect x='{o}' y='{o}' rx='{r}' width='{}' height='{}' fill='none' stroke='url(#s)' stroke-width='1' stroke-opacity='{:.2}' />\n",
+            w - 2 * off,
+            h - 2 * off,
+            opacity * 0.8,
+            o = off,
+            r = (radius - (off - STEPS)).max(1),
+        );
+    }
+    svg += "</svg>";
+    svg
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_soft(x: i32, y: i32, w: i32, h: i32, c: Color, radius: i32, inset: bool, fill: bool) {
+    if w <= 2 * STEPS || h <= 2 * STEPS {
+        set_draw_color(activated_color(c));
+        draw_rectf(x, y, w, h);
+        return;
+    }
+    let svg = soft_svg(w, h, c, radius, inset, fill);
+    if let Ok(mut image) = image::SvgImage::from_data(&svg) {
+        image.draw(x, y, w, h);
+    }
+}
+
+fn up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::Background));
+    draw_rectf(x, y, w, h);
+    draw_soft(x, y, w, h, c, corner_radius(RADIUS, w, h), false, true);
+}
+
+fn down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::Background));
+    draw_rectf(x, y, w, h);
+    draw_soft(x, y, w, h, c, corner_radius(RADIUS, w, h), true, true);
+}
+
+fn up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_soft(x, y, w, h, c, corner_radius(RADIUS, w, h), false, false);
+}
+
+fn down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_soft(x, y, w, h, c, corner_radius(RADIUS, w, h), true, false);
+}
+
+fn round_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::Background));
+    draw_rectf(x, y, w, h);
+    draw_soft(x, y, w, h, c, w.min(h) / 2, false, true);
+}
+
+fn round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::Background));
+    draw_rectf(x, y, w, h);
+    draw_soft(x, y, w, h, c, w.min(h) / 2, true, true);
+}
+
+pub(crate) fn use_neumorphic_scheme() {
+    use fltk::enums::FrameType::*;
+    app::reload_scheme().ok();
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(UpBox, up_box, 5, 5, 10, 10);
+    app::set_frame_type_cb(DownBox, down_box, 5, 5, 10, 10);
+    app::set_frame_type_cb(ThinUpBox, up_box, 5, 5, 10, 10);
+    app::set_frame_type_cb(ThinDownBox, down_box, 5, 5, 10, 10);
+    app::set_frame_type_cb(UpFrame, up_frame, 5, 5, 10, 10);
+    app::set_frame_type_cb(DownFrame, down_frame, 5, 5, 10, 10);
+    app::set_frame_type_cb(RoundUpBox, round_up_box, 5, 5, 10, 10);
+    app::set_frame_type_cb(RoundDownBox, round_down_box, 3, 3, 6, 6);
+}
@@ -1,37 +1,50 @@
 use super::*;
-use fltk::{enums::FrameType, image, prelude::ImageExt};
+use crate::colors::convert::shift_lightness;
+use crate::defaults;
+use fltk::{draw, enums::FrameType, image, prelude::ImageExt};
+
+/// How far the gradient stops and outlines sit from the base color, in HSL lightness
+const SHADE_STEP: f32 = 0.15;
+
+/// The rx attribute of the rounded types, relative to the box unless a corner radius was set
+fn rounded_rx(w: i32, h: i32) -> String {
+    match configured_corner_radius() {
+        Some(_) => corner_radius(0, w, h).to_string(),
+        None => "10%".to_string(),
+    }
+}
 
 fn rounded_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
     let (r, g, b) = c.to_rgb();
+    let rx = rounded_rx(w, h);
     let svg = format!("<svg viewBox='0 0 {} {}'>
-  <rect x='1%' y='1%' stroke-width='2' rx='10%' width='96%' height='96%' stroke='rgb({}, {}, {})' fill='none' />
-</svg>",w, h, r, g, b);
-    let mut image = image::SvgImage::from_data(&svg).unwrap();
-    image.draw(x, y, w, h);
+  <rect x='1%' y='1%' stroke-width='2' rx='{}' width='96%' height='96%' stroke='rgb({}, {}, {})' fill='none' />
+</svg>",w, h, rx, r, g, b);
+    draw_svg(&svg, x, y, w, h, c, FrameType::RoundedFrame);
 }
 
 fn rounded_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
     let (r, g, b) = c.to_rgb();
+    let rx = rounded_rx(w, h);
     let svg = format!(
         "<svg viewBox='0 0 {} {}'>
-    <rect x='1%' y='1%' rx='10%' width='96%' height='96%' fill='rgb({}, {}, {})' />
+    <rect x='1%' y='1%' rx='{}' width='96%' height='96%' fill='rgb({}, {}, {})' />
   </svg>",
-        w, h, r, g, b
+        w, h, rx, r, g, b
     );
-    let mut image = image::SvgImage::from_data(&svg).unwrap();
-    image.draw(x, y, w, h);
+    draw_svg(&svg, x, y, w, h, c, FrameType::RoundedBox);
 }
 
 fn rflat_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
     let (r, g, b) = c.to_rgb();
+    let rx = rounded_rx(w, h);
     let svg = format!(
         "<svg viewBox='0 0 {} {}'>
-    <rect x='1%' y='1%' rx='10%' width='96%' height='96%' fill='rgb({}, {}, {})' />
+    <rect x='1%' y='1%' rx='{}' width='96%' height='96%' fill='rgb({}, {}, {})' />
   </svg>",
-        w, h, r, g, b
+        w, h, rx, r, g, b
     );
-    let mut image = image::SvgImage::from_data(&svg).unwrap();
-    image.draw(x, y, w, h);
+    draw_svg(&svg, x, y, w, h, c, FrameType::RFlatBox);
 }
 
 fn oval_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
@@ -42,8 +55,7 @@ fn oval_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
   </svg>",
         w, h, r, g, b
     );
-    let mut image = image::SvgImage::from_data(&svg).unwrap();
-    image.draw(x, y, w, h);
+    draw_svg(&svg, x, y, w, h, c, FrameType::OvalBox);
 }
 
 fn oval_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
@@ -51,8 +63,7 @@ fn oval_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
     let svg = format!("<svg viewBox='0 0 {} {}'>
     <rect x='1%' y='1%' stroke-width='2' rx='100%' width='96%' height='96%' stroke='rgb({}, {}, {})' fill='none' />
   </svg>",w, h, r, g, b);
-    let mut image = image::SvgImage::from_data(&svg).unwrap();
-    image.draw(x, y, w, h);
+    draw_svg(&svg, x, y, w, h, c, FrameType::OvalFrame);
 }
 
 fn oflat_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
@@ -63,8 +74,244 @@ fn oflat_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
   </svg>",
         w, h, r, g, b
     );
-    let mut image = image::SvgImage::from_data(&svg).unwrap();
-    image.draw(x, y, w, h);
+    draw_svg(&svg, x, y, w, h, c, FrameType::OFlatBox);
+}
+
+/// A lighter shade of c which keeps its hue, where `Color::lighter()` washes saturated colors out
+fn lighter(c: Color) -> Color {
+    shift_lightness(c, SHADE_STEP)
+}
+
+/// A darker shade of c which keeps its hue, where `Color::darker()` muddies saturated colors
+fn darker(c: Color) -> Color {
+    shift_lightness(c, -SHADE_STEP)
+}
+
+fn rgb(c: Color) -> String {
+    let (r, g, b) = c.to_rgb();
+    format!("rgb({}, {}, {})", r, g, b)
+}
+
+/// Draws the svg in place of frame, falling back to FLTK's stock drawing of frame if the image
+/// can't be created
+fn draw_svg(svg: &str, x: i32, y: i32, w: i32, h: i32, c: Color, frame: FrameType) {
+    match image::SvgImage::from_data(svg) {
+        Ok(mut image) => image.draw(x, y, w, h),
+        Err(_) => draw::draw_box(defaults::stock_frame(frame), x, y, w, h, c),
+    }
+}
+
+fn gradient_defs(top: Color, bottom: Color) -> String {
+    format!(
+        "<defs>
+    <linearGradient id='g' x1='0' y1='0' x2='0' y2='1'>
+      <stop offset='0' stop-color='{}' />
+      <stop offset='1' stop-color='{}' />
+    </linearGradient>
+  </defs>",
+        rgb(top),
+        rgb(bottom)
+    )
+}
+
+fn bevel_svg(w: i32, h: i32, c: Color, rx: i32, up: bool, fill: bool) -> String {
+    let (top, bottom) = if up { (lighter(c), c) } else { (darker(c), c) };
+    let fill = if fill { "url(#g)" } else { "none" };
+    format!(
+        "<svg viewBox='0 0 {w} {h}'>
+  {}
+  <rect x='0.5' y='0.5' rx='{rx}' width='{}' height='{}' fill='{}' stroke='{}' stroke-width='1' />
+</svg>",
+        gradient_defs(top, bottom),
+        w - 1,
+        h - 1,
+        fill,
+        rgb(darker(c)),
+        w = w,
+        h = h,
+        rx = rx,
+    )
+}
+
+fn round_svg(w: i32, h: i32, c: Color, up: bool) -> String {
+    let (top, bottom) = if up { (lighter(c), c) } else { (darker(c), c) };
+    format!(
+        "<svg viewBox='0 0 {w} {h}'>
+  {}
+  <ellipse cx='{}' cy='{}' rx='{}' ry='{}' fill='url(#g)' stroke='{}' stroke-width='1' />
+</svg>",
+        gradient_defs(top, bottom),
+        w as f32 / 2.0,
+        h as f32 / 2.0,
+        w as f32 / 2.0 - 0.5,
+        h as f32 / 2.0 - 0.5,
+        rgb(darker(c)),
+        w = w,
+        h = h,
+    )
+}
+
+fn shadow_svg(w: i32, h: i32, c: Color, rx: i32) -> String {
+    let s = 3;
+    let mut svg = format!("<svg viewBox='0 0 {} {}'>\n", w, h);
+    // a real alpha shadow, a few steps wide so it fades out
+    for i in 1..=s {
+        svg += &format!(
+            "  <rect x='{}' y='{}' rx='{}' width='{}' height='{}' fill='black' fill-opacity='{:.2}' />\n",
+            i,
+            i,
+            rx,
+            w - s,
+            h - s,
+            0.3 / i as f32
+        );
+    }
+    svg += &format!(
+        "  <rect x='0.5' y='0.5' rx='{}' width='{}' height='{}' fill='{}' stroke='{}' stroke-width='1' />\n</svg>",
+        rx,
+        w - s - 1,
+        h - s - 1,
+        rgb(c),
+        rgb(darker(c))
+    );
+    svg
+}
+
+fn diamond_svg(w: i32, h: i32, c: Color, up: bool) -> String {
+    let (top, bottom) = if up {
+        (lighter(c), darker(c))
+    } else {
+        (darker(c), lighter(c))
+    };
+    format!(
+        "<svg viewBox='0 0 {w} {h}'>
+  {}
+  <polygon points='{cx},0.5 {},{cy} {cx},{} 0.5,{cy}' fill='url(#g)' stroke='{}' stroke-width='1' />
+</svg>",
+        gradient_defs(top, bottom),
+        w as f32 - 0.5,
+        h as f32 - 0.5,
+        rgb(darker(c)),
+        w = w,
+        h = h,
+        cx = w as f32 / 2.0,
+        cy = h as f32 / 2.0,
+    )
+}
+
+fn shadow_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = shadow_svg(w, h, c, 0);
+    draw_svg(&svg, x, y, w, h, c, FrameType::ShadowBox);
+}
+
+fn rshadow_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = shadow_svg(w, h, c, corner_radius(w.min(h) / 10, w, h));
+    draw_svg(&svg, x, y, w, h, c, FrameType::RShadowBox);
+}
+
+fn oshadow_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = shadow_svg(w, h, c, w.min(h) / 2);
+    draw_svg(&svg, x, y, w, h, c, FrameType::OShadowBox);
+}
+
+fn diamond_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = diamond_svg(w, h, c, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::DiamondUpBox);
+}
+
+fn diamond_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = diamond_svg(w, h, c, false);
+    draw_svg(&svg, x, y, w, h, c, FrameType::DiamondDownBox);
+}
+
+fn plastic_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(4, w, h), true, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::PlasticUpBox);
+}
+
+fn plastic_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(4, w, h), false, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::PlasticDownBox);
+}
+
+fn plastic_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(4, w, h), true, false);
+    draw_svg(&svg, x, y, w, h, c, FrameType::PlasticUpFrame);
+}
+
+fn plastic_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(4, w, h), false, false);
+    draw_svg(&svg, x, y, w, h, c, FrameType::PlasticDownFrame);
+}
+
+fn plastic_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(2, w, h), true, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::PlasticThinUpBox);
+}
+
+fn plastic_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(2, w, h), false, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::PlasticThinDownBox);
+}
+
+fn gtk_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(3, w, h), true, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkUpBox);
+}
+
+fn gtk_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(3, w, h), false, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkDownBox);
+}
+
+fn gtk_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(3, w, h), true, false);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkUpFrame);
+}
+
+fn gtk_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(3, w, h), false, false);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkDownFrame);
+}
+
+fn gtk_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(2, w, h), true, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkThinUpBox);
+}
+
+fn gtk_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(2, w, h), false, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkThinDownBox);
+}
+
+fn gtk_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(2, w, h), true, false);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkThinUpFrame);
+}
+
+fn gtk_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = bevel_svg(w, h, c, corner_radius(2, w, h), false, false);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkThinDownFrame);
+}
+
+fn plastic_round_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = round_svg(w, h, c, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::PlasticRoundUpBox);
+}
+
+fn plastic_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = round_svg(w, h, c, false);
+    draw_svg(&svg, x, y, w, h, c, FrameType::PlasticRoundDownBox);
+}
+
+fn gtk_round_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = round_svg(w, h, c, true);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkRoundUpBox);
+}
+
+fn gtk_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let svg = round_svg(w, h, c, false);
+    draw_svg(&svg, x, y, w, h, c, FrameType::GtkRoundDownBox);
 }
 
 pub(crate) fn use_svg_based_scheme() {
@@ -76,4 +323,48 @@ pub(crate) fn use_svg_based_scheme() {
     app::set_frame_type_cb(FrameType::OvalBox, oval_box, 2, 2, 4, 4);
     app::set_frame_type_cb(FrameType::OvalFrame, oval_frame, 2, 2, 4, 4);
     app::set_frame_type_cb(FrameType::OFlatBox, oflat_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::ShadowBox, shadow_box, 1, 1, 5, 5);
+    app::set_frame_type_cb(FrameType::RShadowBox, rshadow_box, 1, 1, 5, 5);
+    app::set_frame_type_cb(FrameType::OShadowBox, oshadow_box, 1, 1, 5, 5);
+    app::set_frame_type_cb(FrameType::DiamondUpBox, diamond_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::DiamondDownBox, diamond_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::PlasticUpBox, plastic_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::PlasticDownBox, plastic_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::PlasticUpFrame, plastic_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::PlasticDownFrame, plastic_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::PlasticThinUpBox, plastic_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        FrameType::PlasticThinDownBox,
+        plastic_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        FrameType::PlasticRoundUpBox,
+        plastic_round_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        FrameType::PlasticRoundDownBox,
+        plastic_round_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(FrameType::GtkUpBox, gtk_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::GtkDownBox, gtk_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::GtkUpFrame, gtk_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::GtkDownFrame, gtk_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::GtkThinUpBox, gtk_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(FrameType::GtkThinDownBox, gtk_thin_down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(FrameType::GtkThinUpFrame, gtk_thin_up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(FrameType::GtkThinDownFrame, gtk_thin_down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(FrameType::GtkRoundUpBox, gtk_round_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::GtkRoundDownBox, gtk_round_down_box, 2, 2, 4, 4);
 }
@@ -0,0 +1,379 @@
+use super::*;
+use fltk::{app, enums::Color, misc::Tooltip};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DARK: AtomicBool = AtomicBool::new(false);
+
+struct Palette {
+    button_top: Color,
+    button_bottom: Color,
+    border: Color,
+    hovered_top: Color,
+    hovered_bottom: Color,
+    pressed: Color,
+    field: Color,
+    field_border: Color,
+    check: Color,
+    thumb: Color,
+}
+
+const LIGHT: Palette = Palette {
+    button_top: Color::from_rgb(0xF6, 0xF5, 0xF4),
+    button_bottom: Color::from_rgb(0xED, 0xEB, 0xE9),
+    border: Color::from_rgb(0xCD, 0xC7, 0xC2),
+    hovered_top: Color::from_rgb(0xF9, 0xF9, 0xF8),
+    hovered_bottom: Color::from_rgb(0xF3, 0xF2, 0xF1),
+    pressed: Color::from_rgb(0xD6, 0xD1, 0xCD),
+    field: Color::from_rgb(0xFF, 0xFF, 0xFF),
+    field_border: Color::from_rgb(0xCD, 0xC7, 0xC2),
+    check: Color::from_rgb(0xFF, 0xFF, 0xFF),
+    thumb: Color::from_rgb(0x92, 0x95, 0x95),
+};
+
+const DARK_PALETTE: Palette = Palette {
+    button_top: Color::from_rgb(0x3A, 0x3A, 0x3A),
+    button_bottom: Color::from_rgb(0x34, 0x34, 0x34),
+    border: Color::from_rgb(0x1B, 0x1B, 0x1B),
+    hovered_top: Color::from_rgb(0x42, 0x42, 0x42),
+    hovered_bottom: Color::from_rgb(0x3C, 0x3C, 0x3C),
+    pressed: Color::from_rgb(0x1E, 0x1E, 0x1E),
+    field: Color::from_rgb(0x2D, 0x2D, 0x2D),
+    field_border: Color::from_rgb(0x1B, 0x1B, 0x1B),
+    check: Color::from_rgb(0x2D, 0x2D, 0x2D),
+    thumb: Color::from_rgb(0xA4, 0xA4, 0xA3),
+};
+
+const ACCENT: Color = Color::from_rgb(0x35, 0x84, 0xE4);
+const ACCENT_BORDER: Color = Color::from_rgb(0x1A, 0x5F, 0xB4);
+
+fn palette() -> &'static Palette {
+    if DARK.load(Ordering::Relaxed) {
+        &DARK_PALETTE
+    } else {
+        &LIGHT
+    }
+}
+
+fn adwaita_gradient_box(x: i32, y: i32, w: i32, h: i32, top: Color, bottom: Color) {
+    draw_rbox(x, y, w, h, 5, true, activated_color(bottom));
+    vertical_gradient(
+        x + 2,
+        y + 1,
+        x + w - 3,
+        y + h - 2,
+        activated_color(top),
+        activated_color(bottom),
+    );
+}
+
+fn adwaita_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 5, false, activated_color(palette().border));
+}
+
+fn adwaita_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let p = palette();
+    adwaita_gradient_box(x, y, w, h, p.button_top, p.button_bottom);
+    adwaita_button_up_frame(x, y, w, h, c);
+}
+
+fn adwaita_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, false, activated_color(palette().border));
+}
+
+fn adwaita_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(palette().check));
+    adwaita_check_down_frame(x, y, w, h, c);
+}
+
+fn adwaita_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().border));
+    draw_rect(x, y, w, h);
+}
+
+fn adwaita_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    adwaita_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn adwaita_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().border));
+    draw_xyline(x, y + h - 1, x + w - 1);
+}
+
+fn adwaita_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x, y, w, h - 1);
+    adwaita_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn adwaita_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().border));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn adwaita_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().check));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    adwaita_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn adwaita_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let p = palette();
+    adwaita_gradient_box(x, y, w, h, p.hovered_top, p.hovered_bottom);
+    adwaita_button_up_frame(x, y, w, h, c);
+}
+
+fn adwaita_depressed_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 5, false, activated_color(palette().border));
+}
+
+fn adwaita_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 5, true, activated_color(palette().pressed));
+    adwaita_depressed_down_frame(x, y, w, h, c);
+}
+
+fn adwaita_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    if frame_has_focus(x, y, w, h) {
+        draw_rbox(x, y, w, h, 5, false, activated_color(ACCENT));
+        draw_rbox(
+            x + 1,
+            y + 1,
+            w - 2,
+            h - 2,
+            4,
+            false,
+            activated_color(ACCENT),
+        );
+    } else {
+        draw_rbox(
+            x,
+            y,
+            w,
+            h,
+            5,
+            false,
+            activated_color(palette().field_border),
+        );
+    }
+}
+
+fn adwaita_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 5, true, activated_color(palette().field));
+    adwaita_input_thin_down_frame(x, y, w, h, c);
+}
+
+fn adwaita_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 5, false, activated_color(ACCENT_BORDER));
+}
+
+fn adwaita_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    adwaita_gradient_box(x, y, w, h, Color::from_rgb(0x3D, 0x8E, 0xEC), ACCENT);
+    adwaita_default_button_up_frame(x, y, w, h, c);
+}
+
+fn adwaita_default_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    adwaita_gradient_box(
+        x,
+        y,
+        w,
+        h,
+        Color::from_rgb(0x4A, 0x96, 0xEE),
+        Color::from_rgb(0x3C, 0x8B, 0xE8),
+    );
+    adwaita_default_button_up_frame(x, y, w, h, c);
+}
+
+fn adwaita_default_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        5,
+        true,
+        activated_color(Color::from_rgb(0x1C, 0x71, 0xD8)),
+    );
+    adwaita_default_button_up_frame(x, y, w, h, c);
+}
+
+fn adwaita_tabs_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().border));
+    draw_rect(x, y, w, h);
+}
+
+fn adwaita_tabs_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    adwaita_tabs_frame(x, y, w, h, c);
+}
+
+fn adwaita_swatch_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(c));
+    adwaita_check_down_frame(x, y, w, h, c);
+}
+
+fn adwaita_scrollbar_thumb_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let col = activated_color(palette().thumb);
+    if w >= h {
+        let t = (h / 2).max(3);
+        draw_rbox(x + 1, y + (h - t) / 2, w - 2, t, t / 2, true, col);
+    } else {
+        let t = (w / 2).max(3);
+        draw_rbox(x + (w - t) / 2, y + 1, t, h - 2, t / 2, true, col);
+    }
+}
+
+fn use_adwaita_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, adwaita_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, adwaita_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, adwaita_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, adwaita_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, adwaita_panel_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        adwaita_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_PANEL_THIN_UP_FRAME,
+        adwaita_panel_thin_up_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        adwaita_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        adwaita_radio_round_down_box,
+        3,
+        3,
+        6,
+        6,
+    );
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, adwaita_hovered_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_BOX,
+        adwaita_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_HOVERED_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        adwaita_depressed_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_BOX,
+        adwaita_input_thin_down_box,
+        3,
+        3,
+        6,
+        6,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        adwaita_input_thin_down_frame,
+        3,
+        3,
+        6,
+        6,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        adwaita_default_button_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_HOVERED_UP_BOX,
+        adwaita_default_hovered_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_DEPRESSED_DOWN_BOX,
+        adwaita_default_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_HOVERED_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, adwaita_tabs_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_SWATCH_BOX, adwaita_swatch_box, 2, 2, 4, 4);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type_cb(
+        OS_SCROLLBAR_THUMB_BOX,
+        adwaita_scrollbar_thumb_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_INPUT_THIN_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_adwaita_colors() {
+    app::background(0xFA, 0xFA, 0xFA);
+    app::background2(0xFF, 0xFF, 0xFF);
+    app::foreground(0x2E, 0x34, 0x36);
+    app::set_color(Color::Inactive, 0x92, 0x95, 0x95);
+    app::set_color(Color::Selection, 0x35, 0x84, 0xE4);
+    app::set_color(Color::Free, 0xFA, 0xFA, 0xFA);
+    Tooltip::set_color(Color::from_rgb(0x2E, 0x34, 0x36));
+    Tooltip::set_text_color(Color::from_rgb(0xFF, 0xFF, 0xFF));
+}
+
+fn use_adwaita_dark_colors() {
+    app::background(0x24, 0x24, 0x24);
+    app::background2(0x2D, 0x2D, 0x2D);
+    app::foreground(0xFF, 0xFF, 0xFF);
+    app::set_color(Color::Inactive, 0x91, 0x91, 0x90);
+    app::set_color(Color::Selection, 0x35, 0x84, 0xE4);
+    app::set_color(Color::Free, 0x24, 0x24, 0x24);
+    Tooltip::set_color(Color::from_rgb(0x38, 0x38, 0x38));
+    Tooltip::set_text_color(Color::from_rgb(0xFF, 0xFF, 0xFF));
+}
+
+pub(crate) fn use_adwaita_theme() {
+    DARK.store(false, Ordering::Relaxed);
+    use_adwaita_scheme();
+    use_adwaita_colors();
+    use_native_settings();
+}
+
+pub(crate) fn use_adwaita_dark_theme() {
+    DARK.store(true, Ordering::Relaxed);
+    use_adwaita_scheme();
+    use_adwaita_dark_colors();
+    use_native_settings();
+}
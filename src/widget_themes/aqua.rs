@@ -0,0 +1,373 @@
+use super::*;
+use fltk::{app, enums::Color, image, misc::Tooltip, prelude::ImageExt};
+
+#[allow(clippy::too_many_arguments)]
+fn svg_rounded_rect(
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+    radius: i32,
+    fill: Option<Color>,
+    stroke: Option<Color>,
+    shadow: bool,
+) {
+    if w <= 0 || h <= 0 {
+        return;
+    }
+    let fill = match fill {
+        Some(c) => {
+            let (r, g, b) = activated_color(c).to_rgb();
+            format!("rgb({}, {}, {})", r, g, b)
+        }
+        None => "none".to_string(),
+    };
+    let stroke = match stroke {
+        Some(c) => {
+            let (r, g, b) = activated_color(c).to_rgb();
+            format!("stroke='rgb({}, {}, {})' stroke-width='1'", r, g, b)
+        }
+        None => String::new(),
+    };
+    let bh = if shadow { h - 2 } else { h - 1 };
+    let shadow = if shadow {
+        format!(
+            "<rect x='0.5' y='1.5' rx='{r}' width='{}' height='{}' fill='rgb(0, 0, 0)' fill-opacity='0.12' />",
+            w - 1,
+            h - 2,
+            r = radius
+        )
+    } else {
+        String::new()
+    };
+    let svg = format!(
+        "<svg width='{w}' height='{h}' viewBox='0 0 {w} {h}'>
+    {}
+    <rect x='0.5' y='0.5' rx='{r}' width='{}' height='{}' fill='{}' {} />
+  </svg>",
+        shadow,
+        w - 1,
+        bh,
+        fill,
+        stroke,
+        w = w,
+        h = h,
+        r = radius
+    );
+    if let Ok(mut image) = image::SvgImage::from_data(&svg) {
+        image.draw(x, y, w, h);
+    }
+}
+
+fn svg_circle(x: i32, y: i32, w: i32, h: i32, fill: Color, stroke: Color) {
+    if w <= 0 || h <= 0 {
+        return;
+    }
+    let (fr, fg, fb) = activated_color(fill).to_rgb();
+    let (sr, sg, sb) = activated_color(stroke).to_rgb();
+    let svg = format!(
+        "<svg width='{w}' height='{h}' viewBox='0 0 {w} {h}'>
+    <ellipse cx='{}' cy='{}' rx='{}' ry='{}' fill='rgb({}, {}, {})' stroke='rgb({}, {}, {})' stroke-width='1' />
+  </svg>",
+        w as f32 / 2.0,
+        h as f32 / 2.0,
+        w as f32 / 2.0 - 0.5,
+        h as f32 / 2.0 - 0.5,
+        fr,
+        fg,
+        fb,
+        sr,
+        sg,
+        sb,
+        w = w,
+        h = h
+    );
+    if let Ok(mut image) = image::SvgImage::from_data(&svg) {
+        image.draw(x, y, w, h);
+    }
+}
+
+fn aqua_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        6,
+        None,
+        Some(Color::from_rgb(0xC8, 0xC8, 0xC8)),
+        false,
+    );
+}
+
+fn aqua_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        6,
+        Some(Color::from_rgb(0xFF, 0xFF, 0xFF)),
+        Some(Color::from_rgb(0xC8, 0xC8, 0xC8)),
+        true,
+    );
+}
+
+fn aqua_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        3,
+        None,
+        Some(Color::from_rgb(0xB4, 0xB4, 0xB4)),
+        false,
+    );
+}
+
+fn aqua_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        3,
+        Some(Color::from_rgb(0xFF, 0xFF, 0xFF)),
+        Some(Color::from_rgb(0xB4, 0xB4, 0xB4)),
+        true,
+    );
+}
+
+fn aqua_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xD5, 0xD5, 0xD5)));
+    draw_rect(x, y, w, h);
+}
+
+fn aqua_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    aqua_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn aqua_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xC8, 0xC8, 0xC8)));
+    draw_rect(x, y, w, h);
+}
+
+fn aqua_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    aqua_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn aqua_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_circle(
+        x,
+        y,
+        w,
+        h,
+        Color::from_rgb(0xFF, 0xFF, 0xFF),
+        Color::from_rgb(0xB4, 0xB4, 0xB4),
+    );
+}
+
+fn aqua_depressed_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        6,
+        None,
+        Some(Color::from_rgb(0xB4, 0xB4, 0xB4)),
+        false,
+    );
+}
+
+fn aqua_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        6,
+        Some(Color::from_rgb(0xE6, 0xE6, 0xE6)),
+        Some(Color::from_rgb(0xB4, 0xB4, 0xB4)),
+        true,
+    );
+}
+
+fn aqua_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xBF, 0xBF, 0xBF)));
+    draw_rect(x, y, w, h);
+    if frame_has_focus(x, y, w, h) {
+        // focus ring
+        set_draw_color(activated_color(Color::from_rgb(0x7F, 0xB4, 0xF5)));
+        draw_rect(x + 1, y + 1, w - 2, h - 2);
+        draw_rect(x + 2, y + 2, w - 4, h - 4);
+    }
+}
+
+fn aqua_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xFF, 0xFF, 0xFF)));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    aqua_input_thin_down_frame(x, y, w, h, c);
+}
+
+fn aqua_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        6,
+        Some(Color::from_rgb(0x00, 0x7A, 0xFF)),
+        Some(Color::from_rgb(0x00, 0x6C, 0xE6)),
+        true,
+    );
+}
+
+fn aqua_default_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        6,
+        Some(Color::from_rgb(0x00, 0x5F, 0xD0)),
+        Some(Color::from_rgb(0x00, 0x52, 0xB8)),
+        true,
+    );
+}
+
+fn aqua_tabs_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // segmented control
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        5,
+        Some(Color::from_rgb(0xE3, 0xE3, 0xE3)),
+        Some(Color::from_rgb(0xC8, 0xC8, 0xC8)),
+        false,
+    );
+}
+
+fn aqua_swatch_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    aqua_check_down_frame(x, y, w, h, c);
+}
+
+fn aqua_swatch_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    svg_rounded_rect(
+        x,
+        y,
+        w,
+        h,
+        3,
+        Some(c),
+        Some(Color::from_rgb(0xB4, 0xB4, 0xB4)),
+        false,
+    );
+}
+
+fn use_aqua_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, aqua_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, aqua_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, aqua_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, aqua_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, aqua_panel_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        aqua_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_FRAME, aqua_panel_thin_up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        aqua_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        aqua_radio_round_down_box,
+        3,
+        3,
+        6,
+        6,
+    );
+    app::set_frame_type2(OS_HOVERED_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_BOX, aqua_depressed_down_box, 2, 2, 4, 4);
+    app::set_frame_type2(OS_HOVERED_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        aqua_depressed_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(OS_INPUT_THIN_DOWN_BOX, aqua_input_thin_down_box, 3, 3, 6, 6);
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        aqua_input_thin_down_frame,
+        3,
+        3,
+        6,
+        6,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        aqua_default_button_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_DEFAULT_HOVERED_UP_BOX, OS_DEFAULT_BUTTON_UP_BOX);
+    app::set_frame_type_cb(
+        OS_DEFAULT_DEPRESSED_DOWN_BOX,
+        aqua_default_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, aqua_tabs_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_SWATCH_BOX, aqua_swatch_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_SWATCH_FRAME, aqua_swatch_frame, 2, 2, 4, 4);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_CHECK_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_aqua_colors() {
+    app::background(0xEC, 0xEC, 0xEC);
+    app::background2(0xFF, 0xFF, 0xFF);
+    app::foreground(0x00, 0x00, 0x00);
+    app::set_color(Color::Inactive, 0x8C, 0x8C, 0x8C);
+    app::set_color(Color::Selection, 0x00, 0x7A, 0xFF);
+    app::set_color(Color::Free, 0xFF, 0xFF, 0xFF);
+    Tooltip::set_color(Color::from_rgb(0xF6, 0xF6, 0xF6));
+    Tooltip::set_text_color(Color::Foreground);
+}
+
+pub(crate) fn use_aqua_theme() {
+    use_aqua_scheme();
+    use_aqua_colors();
+    use_native_settings();
+}
@@ -1,4 +1,7 @@
 use super::*;
+use crate::colors::relative_luminance;
+use crate::theme_file::mix;
+use crate::widget_schemes::configured_accent_color;
 use fltk::{app, enums::Color, misc::Tooltip};
 
 fn aqua_classic_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
@@ -202,34 +205,58 @@ fn aqua_classic_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
     aqua_classic_input_thin_down_frame(x, y, w, h, c);
 }
 
+/// Recolors a color of the default button with the hue of the configured accent, if any,
+/// keeping its luminance
+fn accent_tint(r: u8, g: u8, b: u8) -> Color {
+    let accent = match configured_accent_color() {
+        Some(accent) => accent.to_rgb(),
+        None => return Color::from_rgb(r, g, b),
+    };
+    let target = relative_luminance((r, g, b));
+    let lighter = target >= relative_luminance(accent);
+    let end = if lighter { (255, 255, 255) } else { (0, 0, 0) };
+    // the luminance changes monotonically while mixing towards white or black
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..16 {
+        let t = (lo + hi) / 2.0;
+        if (relative_luminance(mix(accent, end, t)) < target) == lighter {
+            lo = t;
+        } else {
+            hi = t;
+        }
+    }
+    let (r, g, b) = mix(accent, end, (lo + hi) / 2.0);
+    Color::from_rgb(r, g, b)
+}
+
 fn aqua_classic_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
     // top outer border
-    set_draw_color(activated_color(Color::from_rgb(0x4E, 0x59, 0xA6)));
+    set_draw_color(activated_color(accent_tint(0x4E, 0x59, 0xA6)));
     draw_xyline(x + 3, y, x + w - 4);
     // side outer borders
-    set_draw_color(activated_color(Color::from_rgb(0x4C, 0x52, 0x89)));
+    set_draw_color(activated_color(accent_tint(0x4C, 0x52, 0x89)));
     draw_yxline(x, y + 3, y + h - 4);
     draw_yxline(x + w - 1, y + 3, y + h - 4);
     // bottom outer border
-    set_draw_color(activated_color(Color::from_rgb(0x48, 0x4F, 0x69)));
+    set_draw_color(activated_color(accent_tint(0x48, 0x4F, 0x69)));
     draw_xyline(x + 3, y + h - 1, x + w - 4);
     // top inner border
-    set_draw_color(activated_color(Color::from_rgb(0xD0, 0xEA, 0xF6)));
+    set_draw_color(activated_color(accent_tint(0xD0, 0xEA, 0xF6)));
     draw_xyline(x + 3, y + 1, x + w - 4);
     // side top inner borders
-    set_draw_color(activated_color(Color::from_rgb(0x7A, 0xBF, 0xEF)));
+    set_draw_color(activated_color(accent_tint(0x7A, 0xBF, 0xEF)));
     draw_yxline(x + 1, y + 3, y + h / 2 - 1);
     draw_yxline(x + w - 2, y + 3, y + h / 2 - 1);
     // side bottom inner borders
-    set_draw_color(activated_color(Color::from_rgb(0x53, 0xAF, 0xEF)));
+    set_draw_color(activated_color(accent_tint(0x53, 0xAF, 0xEF)));
     draw_yxline(x + 1, y + h / 2, y + h - 4);
     draw_yxline(x + w - 2, y + h / 2, y + h - 4);
     // top corners
-    set_draw_color(activated_color(Color::from_rgb(0x76, 0x80, 0xB5)));
+    set_draw_color(activated_color(accent_tint(0x76, 0x80, 0xB5)));
     draw_arc(x, y, 8, 8, 90.0, 180.0);
     draw_arc(x + w - 8, y, 8, 8, 0.0, 90.0);
     // bottom corners
-    set_draw_color(activated_color(Color::from_rgb(0x6F, 0x75, 0x89)));
+    set_draw_color(activated_color(accent_tint(0x6F, 0x75, 0x89)));
     draw_arc(x, y + h - 8, 8, 8, 180.0, 270.0);
     draw_arc(x + w - 8, y + h - 8, 8, 8, 270.0, 360.0);
 }
@@ -241,8 +268,8 @@ fn aqua_classic_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color)
         y + 2,
         x + w - 3,
         y + h / 2 - 1,
-        Color::from_rgb(0xBF, 0xDC, 0xF7),
-        Color::from_rgb(0x84, 0xC4, 0xF1),
+        accent_tint(0xBF, 0xDC, 0xF7),
+        accent_tint(0x84, 0xC4, 0xF1),
     );
     // bottom gradient
     vertical_gradient(
@@ -250,8 +277,8 @@ fn aqua_classic_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color)
         y + h / 2,
         x + w - 3,
         y + h - 2,
-        Color::from_rgb(0x59, 0xB5, 0xF1),
-        Color::from_rgb(0xBA, 0xE9, 0xF7),
+        accent_tint(0x59, 0xB5, 0xF1),
+        accent_tint(0xBA, 0xE9, 0xF7),
     );
     aqua_classic_default_button_up_frame(x, y, w, h, c);
 }
@@ -0,0 +1,330 @@
+use super::*;
+use fltk::{app, enums::Color, misc::Tooltip};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DARK: AtomicBool = AtomicBool::new(false);
+
+struct Palette {
+    button: Color,
+    border: Color,
+    pressed: Color,
+    field: Color,
+    field_border: Color,
+    separator: Color,
+}
+
+const LIGHT: Palette = Palette {
+    button: Color::from_rgb(0xFC, 0xFC, 0xFC),
+    border: Color::from_rgb(0xBD, 0xC3, 0xC7),
+    pressed: Color::from_rgb(0x93, 0xCE, 0xE9),
+    field: Color::from_rgb(0xFC, 0xFC, 0xFC),
+    field_border: Color::from_rgb(0xBD, 0xC3, 0xC7),
+    separator: Color::from_rgb(0xD1, 0xD5, 0xD9),
+};
+
+const DARK_PALETTE: Palette = Palette {
+    button: Color::from_rgb(0x3B, 0x40, 0x45),
+    border: Color::from_rgb(0x61, 0x65, 0x69),
+    pressed: Color::from_rgb(0x2A, 0x6A, 0x8C),
+    field: Color::from_rgb(0x23, 0x26, 0x29),
+    field_border: Color::from_rgb(0x61, 0x65, 0x69),
+    separator: Color::from_rgb(0x4D, 0x52, 0x57),
+};
+
+const ACCENT: Color = Color::from_rgb(0x3D, 0xAE, 0xE9);
+
+fn palette() -> &'static Palette {
+    if DARK.load(Ordering::Relaxed) {
+        &DARK_PALETTE
+    } else {
+        &LIGHT
+    }
+}
+
+// Breeze communicates focus and hover through the accent colored border only
+fn border_color(x: i32, y: i32, w: i32, h: i32, normal: Color) -> Color {
+    if frame_has_focus(x, y, w, h) || frame_is_hovered(x, y, w, h) {
+        activated_color(ACCENT)
+    } else {
+        activated_color(normal)
+    }
+}
+
+fn breeze_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        3,
+        false,
+        border_color(x, y, w, h, palette().border),
+    );
+}
+
+fn breeze_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(palette().button));
+    breeze_button_up_frame(x, y, w, h, c);
+}
+
+fn breeze_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        2,
+        false,
+        border_color(x, y, w, h, palette().border),
+    );
+}
+
+fn breeze_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(palette().field));
+    breeze_check_down_frame(x, y, w, h, c);
+}
+
+fn breeze_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().separator));
+    draw_rect(x, y, w, h);
+}
+
+fn breeze_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    breeze_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn breeze_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().separator));
+    draw_rect(x, y, w, h);
+}
+
+fn breeze_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    breeze_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn breeze_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(border_color(x, y, w, h, palette().border));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn breeze_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().field));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    breeze_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn breeze_hovered_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, false, activated_color(ACCENT));
+}
+
+fn breeze_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(palette().button));
+    breeze_hovered_up_frame(x, y, w, h, c);
+}
+
+fn breeze_depressed_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, false, activated_color(ACCENT));
+}
+
+fn breeze_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(palette().pressed));
+    breeze_depressed_down_frame(x, y, w, h, c);
+}
+
+fn breeze_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let border = border_color(x, y, w, h, palette().field_border);
+    draw_rbox(x, y, w, h, 3, false, border);
+    // sunken line edit
+    set_draw_color(activated_color(devalued(palette().field, 0.1)));
+    draw_xyline(x + 2, y + 1, x + w - 3);
+}
+
+fn breeze_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(palette().field));
+    breeze_input_thin_down_frame(x, y, w, h, c);
+}
+
+fn breeze_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, false, activated_color(ACCENT));
+}
+
+fn breeze_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        3,
+        true,
+        activated_color(Color::color_average(ACCENT, palette().button, 0.15)),
+    );
+    breeze_default_button_up_frame(x, y, w, h, c);
+}
+
+fn breeze_tabs_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().border));
+    draw_rect(x, y, w, h);
+}
+
+fn breeze_tabs_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    breeze_tabs_frame(x, y, w, h, c);
+}
+
+fn breeze_swatch_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(c));
+    breeze_check_down_frame(x, y, w, h, c);
+}
+
+fn breeze_scrollbar_thumb_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let col = if frame_is_hovered(x, y, w, h) {
+        activated_color(ACCENT)
+    } else {
+        activated_color(palette().border)
+    };
+    if w >= h {
+        let t = (h / 2).max(3);
+        draw_rbox(x + 1, y + (h - t) / 2, w - 2, t, t / 2, true, col);
+    } else {
+        let t = (w / 2).max(3);
+        draw_rbox(x + (w - t) / 2, y + 1, t, h - 2, t / 2, true, col);
+    }
+}
+
+fn use_breeze_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, breeze_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, breeze_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, breeze_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, breeze_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, breeze_panel_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        breeze_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_PANEL_THIN_UP_FRAME,
+        breeze_panel_thin_up_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        breeze_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        breeze_radio_round_down_box,
+        3,
+        3,
+        6,
+        6,
+    );
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, breeze_hovered_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_BOX, breeze_depressed_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_HOVERED_UP_FRAME, breeze_hovered_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        breeze_depressed_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_BOX,
+        breeze_input_thin_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        breeze_input_thin_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        breeze_default_button_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_DEFAULT_HOVERED_UP_BOX, OS_DEFAULT_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_DEFAULT_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_HOVERED_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, breeze_tabs_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_SWATCH_BOX, breeze_swatch_box, 2, 2, 4, 4);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type_cb(
+        OS_SCROLLBAR_THUMB_BOX,
+        breeze_scrollbar_thumb_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_INPUT_THIN_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_breeze_colors() {
+    app::background(0xEF, 0xF0, 0xF1);
+    app::background2(0xFC, 0xFC, 0xFC);
+    app::foreground(0x23, 0x26, 0x29);
+    app::set_color(Color::Inactive, 0x7F, 0x8C, 0x8D);
+    app::set_color(Color::Selection, 0x3D, 0xAE, 0xE9);
+    app::set_color(Color::Free, 0xEF, 0xF0, 0xF1);
+    Tooltip::set_color(Color::from_rgb(0xF7, 0xF7, 0xF7));
+    Tooltip::set_text_color(Color::Foreground);
+}
+
+fn use_breeze_dark_colors() {
+    app::background(0x31, 0x36, 0x3B);
+    app::background2(0x23, 0x26, 0x29);
+    app::foreground(0xEF, 0xF0, 0xF1);
+    app::set_color(Color::Inactive, 0x7F, 0x8C, 0x8D);
+    app::set_color(Color::Selection, 0x3D, 0xAE, 0xE9);
+    app::set_color(Color::Free, 0x31, 0x36, 0x3B);
+    Tooltip::set_color(Color::from_rgb(0x31, 0x36, 0x3B));
+    Tooltip::set_text_color(Color::Foreground);
+}
+
+pub(crate) fn use_breeze_theme() {
+    DARK.store(false, Ordering::Relaxed);
+    use_breeze_scheme();
+    use_breeze_colors();
+    use_native_settings();
+}
+
+pub(crate) fn use_breeze_dark_theme() {
+    DARK.store(true, Ordering::Relaxed);
+    use_breeze_scheme();
+    use_breeze_dark_colors();
+    use_native_settings();
+}
@@ -0,0 +1,319 @@
+use super::*;
+use fltk::{app, enums::Color, misc::Tooltip};
+
+const ACCENT: Color = Color::from_rgb(0x36, 0x89, 0xE6);
+const BORDER: Color = Color::from_rgb(0xC4, 0xC4, 0xC4);
+const BORDER_BOTTOM: Color = Color::from_rgb(0xAE, 0xAE, 0xAE);
+
+fn elementary_gradient_box(x: i32, y: i32, w: i32, h: i32, top: Color, bottom: Color) {
+    draw_rbox(x, y, w, h, 4, true, activated_color(bottom));
+    vertical_gradient(x + 2, y + 1, x + w - 3, y + h - 2, top, bottom);
+}
+
+fn elementary_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 4, false, activated_color(BORDER));
+    // slightly darker bottom edge
+    set_draw_color(activated_color(BORDER_BOTTOM));
+    draw_xyline(x + 3, y + h - 1, x + w - 4);
+}
+
+fn elementary_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    elementary_gradient_box(
+        x,
+        y,
+        w,
+        h,
+        Color::from_rgb(0xFD, 0xFD, 0xFD),
+        Color::from_rgb(0xF0, 0xF0, 0xF0),
+    );
+    elementary_button_up_frame(x, y, w, h, c);
+}
+
+fn elementary_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, false, activated_color(BORDER_BOTTOM));
+}
+
+fn elementary_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(Color::White));
+    elementary_check_down_frame(x, y, w, h, c);
+}
+
+fn elementary_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(BORDER));
+    draw_rect(x, y, w, h);
+}
+
+fn elementary_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    elementary_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn elementary_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(BORDER));
+    draw_rect(x, y, w, h);
+}
+
+fn elementary_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    elementary_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn elementary_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(BORDER_BOTTOM));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn elementary_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::White));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    elementary_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn elementary_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    elementary_gradient_box(
+        x,
+        y,
+        w,
+        h,
+        Color::from_rgb(0xFF, 0xFF, 0xFF),
+        Color::from_rgb(0xF6, 0xF6, 0xF6),
+    );
+    elementary_button_up_frame(x, y, w, h, c);
+}
+
+fn elementary_depressed_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 4, false, activated_color(BORDER_BOTTOM));
+}
+
+fn elementary_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // pressed buttons get a flat darker fill, not an inverted bevel
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        true,
+        activated_color(Color::from_rgb(0xE0, 0xE0, 0xE0)),
+    );
+    elementary_depressed_down_frame(x, y, w, h, c);
+}
+
+fn elementary_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // search-entry style, fully rounded ends
+    let r = (h / 2).min(w / 2);
+    if frame_has_focus(x, y, w, h) {
+        draw_rbox(x, y, w, h, r, false, activated_color(ACCENT));
+    } else {
+        draw_rbox(x, y, w, h, r, false, activated_color(BORDER));
+    }
+}
+
+fn elementary_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let r = (h / 2).min(w / 2);
+    draw_rbox(x, y, w, h, r, true, activated_color(c));
+    elementary_input_thin_down_frame(x, y, w, h, c);
+}
+
+fn elementary_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 4, false, activated_color(devalued(ACCENT, 0.2)));
+}
+
+fn elementary_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    elementary_gradient_box(
+        x,
+        y,
+        w,
+        h,
+        Color::color_average(Color::White, ACCENT, 0.15),
+        ACCENT,
+    );
+    elementary_default_button_up_frame(x, y, w, h, c);
+}
+
+fn elementary_default_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    elementary_gradient_box(
+        x,
+        y,
+        w,
+        h,
+        Color::color_average(Color::White, ACCENT, 0.25),
+        Color::color_average(Color::White, ACCENT, 0.1),
+    );
+    elementary_default_button_up_frame(x, y, w, h, c);
+}
+
+fn elementary_default_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 4, true, activated_color(devalued(ACCENT, 0.15)));
+    elementary_default_button_up_frame(x, y, w, h, c);
+}
+
+fn elementary_tabs_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 4, false, activated_color(BORDER));
+}
+
+fn elementary_tabs_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 4, true, activated_color(c));
+    elementary_tabs_frame(x, y, w, h, c);
+}
+
+fn elementary_swatch_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 4, true, activated_color(c));
+    draw_rbox(x, y, w, h, 4, false, activated_color(BORDER_BOTTOM));
+}
+
+fn elementary_scrollbar_thumb_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let col = activated_color(Color::from_rgb(0xA0, 0xA0, 0xA0));
+    if w >= h {
+        let t = (h / 2).max(3);
+        draw_rbox(x + 1, y + (h - t) / 2, w - 2, t, t / 2, true, col);
+    } else {
+        let t = (w / 2).max(3);
+        draw_rbox(x + (w - t) / 2, y + 1, t, h - 2, t / 2, true, col);
+    }
+}
+
+fn use_elementary_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, elementary_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, elementary_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, elementary_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, elementary_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_PANEL_THIN_UP_BOX,
+        elementary_panel_thin_up_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        elementary_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_PANEL_THIN_UP_FRAME,
+        elementary_panel_thin_up_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        elementary_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        elementary_radio_round_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, elementary_hovered_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_BOX,
+        elementary_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_HOVERED_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        elementary_depressed_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_BOX,
+        elementary_input_thin_down_box,
+        6,
+        2,
+        12,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        elementary_input_thin_down_frame,
+        6,
+        2,
+        12,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        elementary_default_button_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_HOVERED_UP_BOX,
+        elementary_default_hovered_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_DEPRESSED_DOWN_BOX,
+        elementary_default_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_HOVERED_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, elementary_tabs_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_SWATCH_BOX, elementary_swatch_box, 2, 2, 4, 4);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type2(OS_MENU_BAR_BOX, OS_PANEL_THIN_UP_BOX);
+    app::set_frame_type_cb(
+        OS_SCROLLBAR_THUMB_BOX,
+        elementary_scrollbar_thumb_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_CHECK_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_elementary_colors() {
+    app::background(0xFA, 0xFA, 0xFA);
+    app::background2(0xFF, 0xFF, 0xFF);
+    app::foreground(0x33, 0x33, 0x33);
+    app::set_color(Color::Inactive, 0x8C, 0x8C, 0x8C);
+    app::set_color(Color::Selection, 0x36, 0x89, 0xE6);
+    app::set_color(Color::Free, 0xFA, 0xFA, 0xFA);
+    Tooltip::set_color(Color::from_rgb(0x33, 0x33, 0x33));
+    Tooltip::set_text_color(Color::from_rgb(0xFF, 0xFF, 0xFF));
+}
+
+pub(crate) fn use_elementary_theme() {
+    use_elementary_scheme();
+    use_elementary_colors();
+    use_native_settings();
+}
@@ -0,0 +1,301 @@
+use super::*;
+use fltk::{app, enums::Color, misc::Tooltip};
+
+const CONTROL: Color = Color::from_rgb(0x2D, 0x2D, 0x2D);
+const CONTROL_HOVER: Color = Color::from_rgb(0x32, 0x32, 0x32);
+const CONTROL_PRESSED: Color = Color::from_rgb(0x27, 0x27, 0x27);
+const BORDER: Color = Color::from_rgb(0x3B, 0x3B, 0x3B);
+const FIELD_BORDER: Color = Color::from_rgb(0x9A, 0x9A, 0x9A);
+const ACCENT: Color = Color::from_rgb(0x00, 0x78, 0xD4);
+const ACCENT_LIGHT: Color = Color::from_rgb(0x60, 0xCD, 0xFF);
+
+fn fluent_dark_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, false, activated_color(BORDER));
+}
+
+fn fluent_dark_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(CONTROL));
+    fluent_dark_button_up_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, false, activated_color(FIELD_BORDER));
+}
+
+fn fluent_dark_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        2,
+        true,
+        activated_color(Color::from_rgb(0x1D, 0x1D, 0x1D)),
+    );
+    fluent_dark_check_down_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(BORDER));
+    draw_rect(x, y, w, h);
+}
+
+fn fluent_dark_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    fluent_dark_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(BORDER));
+    draw_rect(x, y, w, h);
+}
+
+fn fluent_dark_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    fluent_dark_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(FIELD_BORDER));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn fluent_dark_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0x1D, 0x1D, 0x1D)));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    fluent_dark_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(CONTROL_HOVER));
+    fluent_dark_button_up_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_depressed_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, false, activated_color(BORDER));
+}
+
+fn fluent_dark_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(CONTROL_PRESSED));
+    fluent_dark_depressed_down_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, false, activated_color(BORDER));
+    // bottom underline, turns into the accent color when focused
+    if frame_has_focus(x, y, w, h) {
+        set_draw_color(activated_color(ACCENT_LIGHT));
+        draw_xyline(x + 1, y + h - 2, x + w - 2);
+        draw_xyline(x + 1, y + h - 1, x + w - 2);
+    } else {
+        set_draw_color(activated_color(FIELD_BORDER));
+        draw_xyline(x + 1, y + h - 1, x + w - 2);
+    }
+}
+
+fn fluent_dark_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(c));
+    fluent_dark_input_thin_down_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, false, activated_color(devalued(ACCENT, 0.1)));
+}
+
+fn fluent_dark_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(ACCENT));
+    fluent_dark_default_button_up_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_default_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        2,
+        true,
+        activated_color(Color::color_average(ACCENT_LIGHT, ACCENT, 0.2)),
+    );
+    fluent_dark_default_button_up_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_default_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(devalued(ACCENT, 0.2)));
+    fluent_dark_default_button_up_frame(x, y, w, h, c);
+}
+
+fn fluent_dark_tabs_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x, y, w, h);
+    set_draw_color(activated_color(BORDER));
+    draw_rect(x, y, w, h);
+}
+
+fn fluent_dark_swatch_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(c));
+    draw_rbox(x, y, w, h, 2, false, activated_color(FIELD_BORDER));
+}
+
+fn fluent_dark_scrollbar_thumb_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let col = activated_color(Color::from_rgb(0x9F, 0x9F, 0x9F));
+    if w >= h {
+        let t = (h / 3).max(2);
+        draw_rbox(x + 1, y + (h - t) / 2, w - 2, t, t / 2, true, col);
+    } else {
+        let t = (w / 3).max(2);
+        draw_rbox(x + (w - t) / 2, y + 1, t, h - 2, t / 2, true, col);
+    }
+}
+
+fn use_fluent_dark_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, fluent_dark_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, fluent_dark_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, fluent_dark_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_CHECK_DOWN_FRAME,
+        fluent_dark_check_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_PANEL_THIN_UP_BOX,
+        fluent_dark_panel_thin_up_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        fluent_dark_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_PANEL_THIN_UP_FRAME,
+        fluent_dark_panel_thin_up_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        fluent_dark_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        fluent_dark_radio_round_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, fluent_dark_hovered_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_BOX,
+        fluent_dark_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_HOVERED_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        fluent_dark_depressed_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_BOX,
+        fluent_dark_input_thin_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        fluent_dark_input_thin_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        fluent_dark_default_button_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_HOVERED_UP_BOX,
+        fluent_dark_default_hovered_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_DEPRESSED_DOWN_BOX,
+        fluent_dark_default_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_HOVERED_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, fluent_dark_tabs_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_SWATCH_BOX, fluent_dark_swatch_box, 2, 2, 4, 4);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type2(OS_MENU_BAR_BOX, FrameType::FlatBox);
+    app::set_frame_type_cb(
+        OS_SCROLLBAR_THUMB_BOX,
+        fluent_dark_scrollbar_thumb_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_CHECK_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_fluent_dark_colors() {
+    app::background(0x20, 0x20, 0x20);
+    app::background2(0x2D, 0x2D, 0x2D);
+    app::foreground(0xFF, 0xFF, 0xFF);
+    app::set_color(Color::Inactive, 0x78, 0x78, 0x78);
+    app::set_color(Color::Selection, 0x00, 0x78, 0xD4);
+    app::set_color(Color::Free, 0x2D, 0x2D, 0x2D);
+    Tooltip::set_color(Color::from_rgb(0x2B, 0x2B, 0x2B));
+    Tooltip::set_text_color(Color::Foreground);
+}
+
+pub(crate) fn use_fluent_dark_theme() {
+    use_fluent_dark_scheme();
+    use_fluent_dark_colors();
+    use_native_settings();
+}
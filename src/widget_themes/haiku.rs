@@ -0,0 +1,340 @@
+use super::*;
+use fltk::{app, enums::Color, misc::Tooltip};
+
+const BORDER: Color = Color::from_rgb(0x77, 0x77, 0x77);
+const TAB_YELLOW: Color = Color::from_rgb(0xFF, 0xCB, 0x00);
+const FOCUS_BLUE: Color = Color::from_rgb(0x00, 0x00, 0xE5);
+
+fn haiku_gradient_box(x: i32, y: i32, w: i32, h: i32, top: Color, bottom: Color) {
+    draw_rbox(x, y, w, h, 2, true, activated_color(bottom));
+    vertical_gradient(x + 1, y + 1, x + w - 2, y + h - 2, top, bottom);
+}
+
+fn haiku_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, false, activated_color(BORDER));
+}
+
+fn haiku_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    haiku_gradient_box(
+        x,
+        y,
+        w,
+        h,
+        Color::color_average(Color::White, c, 0.6),
+        devalued(c, 0.04),
+    );
+    haiku_button_up_frame(x, y, w, h, c);
+}
+
+fn haiku_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // inset border, darker on the top and left
+    set_draw_color(activated_color(BORDER));
+    draw_xyline(x, y, x + w - 1);
+    draw_yxline(x, y, y + h - 1);
+    set_draw_color(activated_color(Color::from_rgb(0xB8, 0xB8, 0xB8)));
+    draw_xyline(x + 1, y + h - 1, x + w - 1);
+    draw_yxline(x + w - 1, y + 1, y + h - 1);
+    set_draw_color(activated_color(Color::from_rgb(0xFF, 0xFF, 0xFF)));
+    draw_xyline(x + 1, y + h - 2, x + w - 2);
+    draw_yxline(x + w - 2, y + 1, y + h - 2);
+}
+
+fn haiku_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::White));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    haiku_check_down_frame(x, y, w, h, c);
+}
+
+fn haiku_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::color_average(Color::White, c, 0.5)));
+    draw_xyline(x, y, x + w - 2);
+    draw_yxline(x, y, y + h - 2);
+    set_draw_color(activated_color(devalued(c, 0.2)));
+    draw_xyline(x, y + h - 1, x + w - 1);
+    draw_yxline(x + w - 1, y, y + h - 1);
+}
+
+fn haiku_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    haiku_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn haiku_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(devalued(c, 0.2)));
+    draw_xyline(x, y, x + w - 2);
+    draw_yxline(x, y, y + h - 2);
+    set_draw_color(activated_color(Color::color_average(Color::White, c, 0.5)));
+    draw_xyline(x, y + h - 1, x + w - 1);
+    draw_yxline(x + w - 1, y, y + h - 1);
+}
+
+fn haiku_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    haiku_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn haiku_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(BORDER));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn haiku_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::White));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    haiku_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn haiku_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    haiku_gradient_box(
+        x,
+        y,
+        w,
+        h,
+        Color::White,
+        Color::color_average(Color::White, c, 0.3),
+    );
+    haiku_button_up_frame(x, y, w, h, c);
+}
+
+fn haiku_depressed_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 2, false, activated_color(devalued(BORDER, 0.2)));
+}
+
+fn haiku_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    haiku_gradient_box(x, y, w, h, devalued(c, 0.15), devalued(c, 0.05));
+    haiku_depressed_down_frame(x, y, w, h, c);
+}
+
+fn haiku_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    if frame_has_focus(x, y, w, h) {
+        set_draw_color(activated_color(FOCUS_BLUE));
+        draw_rect(x, y, w, h);
+    } else {
+        haiku_check_down_frame(x, y, w, h, c);
+    }
+}
+
+fn haiku_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    haiku_input_thin_down_frame(x, y, w, h, c);
+}
+
+fn haiku_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // default buttons get a second, sunken ring around the regular border
+    draw_rbox(x, y, w, h, 3, false, activated_color(devalued(c, 0.15)));
+    draw_rbox(
+        x + 2,
+        y + 2,
+        w - 4,
+        h - 4,
+        2,
+        false,
+        activated_color(BORDER),
+    );
+}
+
+fn haiku_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    haiku_gradient_box(
+        x + 2,
+        y + 2,
+        w - 4,
+        h - 4,
+        Color::color_average(Color::White, c, 0.6),
+        devalued(c, 0.04),
+    );
+    haiku_default_button_up_frame(x, y, w, h, c);
+}
+
+fn haiku_default_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    haiku_gradient_box(
+        x + 2,
+        y + 2,
+        w - 4,
+        h - 4,
+        Color::White,
+        Color::color_average(Color::White, c, 0.3),
+    );
+    haiku_default_button_up_frame(x, y, w, h, c);
+}
+
+fn haiku_default_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    haiku_gradient_box(
+        x + 2,
+        y + 2,
+        w - 4,
+        h - 4,
+        devalued(c, 0.15),
+        devalued(c, 0.05),
+    );
+    haiku_default_button_up_frame(x, y, w, h, c);
+}
+
+fn haiku_tabs_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x, y, w, h);
+    set_draw_color(activated_color(BORDER));
+    draw_rect(x, y, w, h);
+    // yellow accent along the top edge
+    set_draw_color(activated_color(TAB_YELLOW));
+    draw_xyline(x + 1, y + 1, x + w - 2);
+    draw_xyline(x + 1, y + 2, x + w - 2);
+}
+
+fn haiku_swatch_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    set_draw_color(activated_color(BORDER));
+    draw_rect(x, y, w, h);
+}
+
+fn haiku_scrollbar_thumb_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    haiku_button_up_box(x, y, w, h, c);
+    // dotted grip in the middle of the thumb
+    let dark = activated_color(devalued(c, 0.35));
+    let light = activated_color(Color::White);
+    let cx = x + w / 2;
+    let cy = y + h / 2;
+    for i in -2..=2 {
+        let (px, py) = if w >= h {
+            (cx + i * 3, cy)
+        } else {
+            (cx, cy + i * 3)
+        };
+        set_draw_color(dark);
+        draw_point(px, py);
+        set_draw_color(light);
+        draw_point(px + 1, py + 1);
+    }
+}
+
+fn use_haiku_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, haiku_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, haiku_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, haiku_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, haiku_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, haiku_panel_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        haiku_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_PANEL_THIN_UP_FRAME,
+        haiku_panel_thin_up_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        haiku_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        haiku_radio_round_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, haiku_hovered_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_BOX, haiku_depressed_down_box, 2, 2, 4, 4);
+    app::set_frame_type2(OS_HOVERED_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        haiku_depressed_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_BOX,
+        haiku_input_thin_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        haiku_input_thin_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        haiku_default_button_up_box,
+        4,
+        4,
+        8,
+        8,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_HOVERED_UP_BOX,
+        haiku_default_hovered_up_box,
+        4,
+        4,
+        8,
+        8,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_DEPRESSED_DOWN_BOX,
+        haiku_default_depressed_down_box,
+        4,
+        4,
+        8,
+        8,
+    );
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_HOVERED_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, haiku_tabs_box, 1, 3, 2, 4);
+    app::set_frame_type_cb(OS_SWATCH_BOX, haiku_swatch_box, 1, 1, 2, 2);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type2(OS_MENU_BAR_BOX, OS_PANEL_THIN_UP_BOX);
+    app::set_frame_type_cb(
+        OS_SCROLLBAR_THUMB_BOX,
+        haiku_scrollbar_thumb_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_CHECK_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_haiku_colors() {
+    app::background(0xD8, 0xD8, 0xD8);
+    app::background2(0xFF, 0xFF, 0xFF);
+    app::foreground(0x00, 0x00, 0x00);
+    app::set_color(Color::Inactive, 0x80, 0x80, 0x80);
+    app::set_color(Color::Selection, 0x33, 0x66, 0xCC);
+    app::set_color(Color::Free, 0xD8, 0xD8, 0xD8);
+    Tooltip::set_color(Color::from_rgb(0xFF, 0xFF, 0xC0));
+    Tooltip::set_text_color(Color::Foreground);
+}
+
+pub(crate) fn use_haiku_theme() {
+    use_haiku_scheme();
+    use_haiku_colors();
+    use_native_settings();
+}
@@ -272,3 +272,204 @@ pub(crate) fn use_high_contrast_theme() {
     use_high_contrast_colors();
     use_native_settings();
 }
+
+/// Border color of the black variant, disabled frames keep a 3:1 contrast against black
+/// instead of being blended by `Color::inactive()`
+fn high_contrast_dark_border(x: i32, y: i32, w: i32, h: i32) -> Color {
+    if !app::draw_frame_active() {
+        Color::from_rgb(0x8C, 0x8C, 0x8C)
+    } else if frame_has_focus(x, y, w, h) {
+        Color::from_rgb(0xFF, 0xFF, 0x00)
+    } else {
+        Color::from_rgb(0xFF, 0xFF, 0xFF)
+    }
+}
+
+fn high_contrast_dark_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(high_contrast_dark_border(x, y, w, h));
+    draw_rect(x, y, w, h);
+    draw_rect(x + 1, y + 1, w - 2, h - 2);
+}
+
+fn high_contrast_dark_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(Color::from_rgb(0x00, 0x00, 0x00));
+    draw_rectf(x + 2, y + 2, w - 4, h - 4);
+    high_contrast_dark_frame(x, y, w, h, c);
+}
+
+fn high_contrast_dark_hovered_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    if app::draw_frame_active() {
+        set_draw_color(Color::from_rgb(0xFF, 0xFF, 0x00));
+        draw_rect(x, y, w, h);
+        draw_rect(x + 1, y + 1, w - 2, h - 2);
+    } else {
+        high_contrast_dark_frame(x, y, w, h, c);
+    }
+}
+
+fn high_contrast_dark_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(Color::from_rgb(0x00, 0x00, 0x00));
+    draw_rectf(x + 2, y + 2, w - 4, h - 4);
+    high_contrast_dark_hovered_up_frame(x, y, w, h, c);
+}
+
+fn high_contrast_dark_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(Color::from_rgb(0x1A, 0xEB, 0xFF));
+    draw_rectf(x + 2, y + 2, w - 4, h - 4);
+    high_contrast_dark_frame(x, y, w, h, c);
+}
+
+fn high_contrast_dark_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // an extra inner ring marks the default button without relying on color
+    high_contrast_dark_frame(x, y, w, h, c);
+    draw_rect(x + 3, y + 3, w - 6, h - 6);
+}
+
+fn high_contrast_dark_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(Color::from_rgb(0x00, 0x00, 0x00));
+    draw_rectf(x + 2, y + 2, w - 4, h - 4);
+    high_contrast_dark_default_button_up_frame(x, y, w, h, c);
+}
+
+fn high_contrast_dark_input_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(Color::from_rgb(0x00, 0x00, 0x00));
+    draw_rectf(x + 2, y + 2, w - 4, h - 4);
+    high_contrast_dark_frame(x, y, w, h, c);
+}
+
+fn high_contrast_dark_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(high_contrast_dark_border(x, y, w, h));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+    draw_arc(x + 1, y + 1, w - 2, h - 2, 0.0, 360.0);
+}
+
+fn high_contrast_dark_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(Color::from_rgb(0x00, 0x00, 0x00));
+    draw_pie(x + 2, y + 2, w - 4, h - 4, 0.0, 360.0);
+    high_contrast_dark_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn use_high_contrast_dark_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, high_contrast_dark_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, high_contrast_dark_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, high_contrast_dark_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, high_contrast_dark_frame, 2, 2, 4, 4);
+    // thin frames are drawn at the full 2px width as well
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, high_contrast_dark_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_SPACER_THIN_DOWN_BOX, high_contrast_dark_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_FRAME, high_contrast_dark_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        high_contrast_dark_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        high_contrast_dark_radio_round_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_HOVERED_UP_BOX,
+        high_contrast_dark_hovered_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_BOX,
+        high_contrast_dark_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_HOVERED_UP_FRAME,
+        high_contrast_dark_hovered_up_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        high_contrast_dark_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_BOX,
+        high_contrast_dark_input_box,
+        2,
+        3,
+        4,
+        6,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        high_contrast_dark_frame,
+        2,
+        3,
+        4,
+        6,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        high_contrast_dark_default_button_up_box,
+        4,
+        4,
+        8,
+        8,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_HOVERED_UP_BOX,
+        high_contrast_dark_hovered_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_DEFAULT_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_HOVERED_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, high_contrast_dark_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_SWATCH_BOX, high_contrast_dark_frame, 2, 2, 4, 4);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type2(OS_MENU_BAR_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_SCROLLBAR_THUMB_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_CHECK_DOWN_BOX);
+    app::set_frame_type2(FrameType::ThinUpBox, OS_PANEL_THIN_UP_BOX);
+    app::set_frame_type2(FrameType::ThinDownBox, OS_SPACER_THIN_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_high_contrast_dark_colors() {
+    app::background(0x00, 0x00, 0x00);
+    app::background2(0x00, 0x00, 0x00);
+    app::foreground(0xFF, 0xFF, 0xFF);
+    app::set_color(Color::Inactive, 0x8C, 0x8C, 0x8C);
+    app::set_color(Color::Selection, 0xFF, 0xFF, 0xFF);
+    app::set_color(Color::Free, 0x00, 0x00, 0x00);
+    Tooltip::set_color(Color::from_rgb(0x00, 0x00, 0x00));
+    Tooltip::set_text_color(Color::Foreground);
+}
+
+pub(crate) fn use_high_contrast_dark_theme() {
+    use_high_contrast_dark_scheme();
+    use_high_contrast_dark_colors();
+    use_native_settings();
+}
@@ -0,0 +1,337 @@
+use super::*;
+use fltk::{app, enums::Color, misc::Tooltip};
+
+const BORDER: Color = Color::from_rgb(0x00, 0x3C, 0x74);
+const CHECK_BORDER: Color = Color::from_rgb(0x1C, 0x51, 0x80);
+const FIELD_BORDER: Color = Color::from_rgb(0x7F, 0x9D, 0xB9);
+const FACE_TOP: Color = Color::from_rgb(0xFF, 0xFF, 0xFF);
+const FACE_BOTTOM: Color = Color::from_rgb(0xEC, 0xE9, 0xD8);
+
+fn luna_gradient_box(x: i32, y: i32, w: i32, h: i32, top: Color, bottom: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(bottom));
+    vertical_gradient(x + 2, y + 1, x + w - 3, y + h - 3, top, bottom);
+}
+
+fn luna_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, false, activated_color(BORDER));
+}
+
+fn luna_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    luna_gradient_box(x, y, w, h, FACE_TOP, FACE_BOTTOM);
+    // darker bottom edge
+    set_draw_color(activated_color(Color::from_rgb(0xD6, 0xD0, 0xC5)));
+    draw_xyline(x + 2, y + h - 2, x + w - 3);
+    luna_button_up_frame(x, y, w, h, c);
+}
+
+fn luna_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(CHECK_BORDER));
+    draw_rect(x, y, w, h);
+}
+
+fn luna_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    vertical_gradient(
+        x + 1,
+        y + 1,
+        x + w - 2,
+        y + h - 2,
+        Color::from_rgb(0xDC, 0xDC, 0xD7),
+        Color::from_rgb(0xFF, 0xFF, 0xFF),
+    );
+    luna_check_down_frame(x, y, w, h, c);
+}
+
+fn luna_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::White));
+    draw_xyline(x, y, x + w - 2);
+    draw_yxline(x, y, y + h - 2);
+    set_draw_color(activated_color(Color::from_rgb(0xAC, 0xA8, 0x99)));
+    draw_xyline(x, y + h - 1, x + w - 1);
+    draw_yxline(x + w - 1, y, y + h - 1);
+}
+
+fn luna_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    luna_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn luna_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xAC, 0xA8, 0x99)));
+    draw_xyline(x, y, x + w - 2);
+    draw_yxline(x, y, y + h - 2);
+    set_draw_color(activated_color(Color::White));
+    draw_xyline(x, y + h - 1, x + w - 1);
+    draw_yxline(x + w - 1, y, y + h - 1);
+}
+
+fn luna_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    luna_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn luna_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(CHECK_BORDER));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn luna_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xDC, 0xDC, 0xD7)));
+    draw_pie(x + 1, y + 1, w - 2, h - 2, 0.0, 360.0);
+    set_draw_color(activated_color(Color::White));
+    draw_pie(x + 2, y + 3, w - 4, h - 4, 0.0, 360.0);
+    luna_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn luna_hovered_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    luna_button_up_frame(x, y, w, h, c);
+    // orange hot-tracking glow
+    draw_rbox(
+        x + 1,
+        y + 1,
+        w - 2,
+        h - 2,
+        2,
+        false,
+        activated_color(Color::from_rgb(0xF8, 0xB3, 0x30)),
+    );
+    set_draw_color(activated_color(Color::from_rgb(0xFF, 0xF0, 0xCF)));
+    draw_xyline(x + 3, y + 1, x + w - 4);
+    set_draw_color(activated_color(Color::from_rgb(0xE5, 0x97, 0x00)));
+    draw_xyline(x + 3, y + h - 2, x + w - 4);
+}
+
+fn luna_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    luna_gradient_box(x, y, w, h, FACE_TOP, FACE_BOTTOM);
+    luna_hovered_up_frame(x, y, w, h, c);
+}
+
+fn luna_depressed_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    luna_button_up_frame(x, y, w, h, c);
+}
+
+fn luna_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    luna_gradient_box(
+        x,
+        y,
+        w,
+        h,
+        Color::from_rgb(0xE2, 0xE1, 0xDA),
+        Color::from_rgb(0xF1, 0xF1, 0xEE),
+    );
+    luna_depressed_down_frame(x, y, w, h, c);
+}
+
+fn luna_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(FIELD_BORDER));
+    draw_rect(x, y, w, h);
+}
+
+fn luna_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    luna_input_thin_down_frame(x, y, w, h, c);
+}
+
+fn luna_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    luna_button_up_frame(x, y, w, h, c);
+    // blue inner ring of the default button
+    draw_rbox(
+        x + 1,
+        y + 1,
+        w - 2,
+        h - 2,
+        2,
+        false,
+        activated_color(Color::from_rgb(0x98, 0xB4, 0xEA)),
+    );
+    set_draw_color(activated_color(Color::from_rgb(0xCE, 0xE7, 0xFF)));
+    draw_xyline(x + 3, y + 1, x + w - 4);
+    set_draw_color(activated_color(Color::from_rgb(0x69, 0x82, 0xEE)));
+    draw_xyline(x + 3, y + h - 2, x + w - 4);
+}
+
+fn luna_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    luna_gradient_box(x, y, w, h, FACE_TOP, FACE_BOTTOM);
+    luna_default_button_up_frame(x, y, w, h, c);
+}
+
+fn luna_default_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // warmer face approximating the hover glow
+    luna_gradient_box(
+        x,
+        y,
+        w,
+        h,
+        Color::from_rgb(0xFF, 0xFC, 0xF2),
+        Color::from_rgb(0xF6, 0xD9, 0xA6),
+    );
+    luna_hovered_up_frame(x, y, w, h, c);
+}
+
+fn luna_tabs_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0x91, 0x9B, 0x9C)));
+    draw_rect(x, y, w, h);
+}
+
+fn luna_tabs_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    vertical_gradient(
+        x + 1,
+        y + 1,
+        x + w - 2,
+        y + h - 2,
+        Color::from_rgb(0xFC, 0xFC, 0xFE),
+        Color::from_rgb(0xF4, 0xF3, 0xEE),
+    );
+    luna_tabs_frame(x, y, w, h, c);
+}
+
+fn luna_swatch_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    set_draw_color(activated_color(FIELD_BORDER));
+    draw_rect(x, y, w, h);
+}
+
+fn luna_scrollbar_thumb_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // the arrow buttons share this box, the grip is only drawn on the thumb
+    let (top, bottom) = (
+        Color::from_rgb(0xCA, 0xDA, 0xFC),
+        Color::from_rgb(0xB4, 0xCA, 0xF8),
+    );
+    draw_rbox(x, y, w, h, 3, true, activated_color(bottom));
+    if w >= h {
+        vertical_gradient(x + 1, y + 1, x + w - 2, y + h - 2, top, bottom);
+    } else {
+        horizontal_gradient(x + 1, y + 1, x + w - 2, y + h - 2, top, bottom);
+    }
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        3,
+        false,
+        activated_color(Color::from_rgb(0x98, 0xAE, 0xE4)),
+    );
+    if (w - h).abs() > 4 {
+        let cx = x + w / 2;
+        let cy = y + h / 2;
+        for i in -2..=1 {
+            set_draw_color(activated_color(Color::from_rgb(0xEE, 0xF4, 0xFE)));
+            if w > h {
+                draw_yxline(cx + i * 2, cy - 3, cy + 3);
+            } else {
+                draw_xyline(cx - 3, cy + i * 2, cx + 3);
+            }
+            set_draw_color(activated_color(Color::from_rgb(0x8C, 0xB0, 0xF8)));
+            if w > h {
+                draw_yxline(cx + i * 2 + 1, cy - 2, cy + 4);
+            } else {
+                draw_xyline(cx - 2, cy + i * 2 + 1, cx + 4);
+            }
+        }
+    }
+}
+
+fn use_luna_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, luna_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, luna_check_down_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, luna_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, luna_check_down_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, luna_panel_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        luna_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_FRAME, luna_panel_thin_up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        luna_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        luna_radio_round_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, luna_hovered_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_BOX, luna_depressed_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_HOVERED_UP_FRAME, luna_hovered_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        luna_depressed_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(OS_INPUT_THIN_DOWN_BOX, luna_input_thin_down_box, 1, 2, 2, 4);
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        luna_input_thin_down_frame,
+        1,
+        2,
+        2,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        luna_default_button_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_HOVERED_UP_BOX,
+        luna_default_hovered_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_DEFAULT_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_HOVERED_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, luna_tabs_box, 2, 1, 4, 2);
+    app::set_frame_type_cb(OS_SWATCH_BOX, luna_swatch_box, 1, 1, 2, 2);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type2(OS_MENU_BAR_BOX, OS_PANEL_THIN_UP_BOX);
+    app::set_frame_type_cb(OS_SCROLLBAR_THUMB_BOX, luna_scrollbar_thumb_box, 1, 1, 2, 2);
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_CHECK_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_luna_colors() {
+    app::background(0xEC, 0xE9, 0xD8);
+    app::background2(0xFF, 0xFF, 0xFF);
+    app::foreground(0x00, 0x00, 0x00);
+    app::set_color(Color::Inactive, 0xAC, 0xA8, 0x99);
+    app::set_color(Color::Selection, 0x31, 0x6A, 0xC5);
+    app::set_color(Color::Free, 0xEC, 0xE9, 0xD8);
+    Tooltip::set_color(Color::from_rgb(0xFF, 0xFF, 0xE1));
+    Tooltip::set_text_color(Color::Foreground);
+}
+
+pub(crate) fn use_luna_theme() {
+    use_luna_scheme();
+    use_luna_colors();
+    use_native_settings();
+}
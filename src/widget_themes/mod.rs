@@ -1,3 +1,11 @@
+/*!
+The FrameTypes set by the widget themes, see `WidgetTheme`.
+
+The `OS_*` FrameTypes are aliases of stock ones which each widget theme draws its own way.
+Most are the Diamond, Plastic and Gtk ones, which `SchemeType::SvgBased` redraws too, so
+applying that scheme replaces the look of the widgets using them. Use a widget theme without
+it to keep them as the theme draws them.
+*/
 #![allow(unused_variables)]
 #![allow(clippy::many_single_char_names)]
 
@@ -7,16 +15,27 @@ use fltk::{
     draw::*,
     enums::{Color, FrameType},
     misc::Tooltip,
+    prelude::WidgetExt,
 };
 
+pub(crate) mod adwaita;
 pub(crate) mod aero;
+pub(crate) mod aqua;
 pub(crate) mod aqua_classic;
 pub(crate) mod blue;
+pub(crate) mod breeze;
 pub(crate) mod classic;
 pub(crate) mod dark;
+pub(crate) mod elementary;
+pub(crate) mod fluent_dark;
 pub(crate) mod greybird;
+pub(crate) mod haiku;
 pub(crate) mod high_contrast;
+pub(crate) mod luna;
 pub(crate) mod metro;
+pub(crate) mod motif;
+pub(crate) mod win11;
+pub(crate) mod yaru;
 
 pub const OS_BUTTON_UP_BOX: FrameType = FrameType::GtkUpBox;
 pub const OS_CHECK_DOWN_BOX: FrameType = FrameType::GtkDownBox;
@@ -45,6 +64,10 @@ pub const OS_TABS_BOX: FrameType = FrameType::EmbossedBox;
 pub const OS_SWATCH_BOX: FrameType = FrameType::EngravedBox;
 pub const OS_SWATCH_FRAME: FrameType = FrameType::EngravedFrame;
 pub const OS_BG_BOX: FrameType = FrameType::FreeBoxType;
+/// Thumb of a scrollbar, set it using `Scrollbar::set_slider_frame`
+pub const OS_SCROLLBAR_THUMB_BOX: FrameType = FrameType::GleamThinUpBox;
+/// Background of a menu bar, set it using `MenuBar::set_frame`
+pub const OS_MENU_BAR_BOX: FrameType = FrameType::GleamThinDownBox;
 
 pub const OS_FONT_SIZE: i32 = if cfg!(target_os = "window") { 12 } else { 13 };
 
@@ -91,6 +114,24 @@ pub(crate) fn horizontal_gradient(x1: i32, y1: i32, x2: i32, y2: i32, c1: Color,
     }
 }
 
+/// Checks whether the frame being drawn belongs to the widget holding the keyboard focus
+pub(crate) fn frame_has_focus(x: i32, y: i32, w: i32, h: i32) -> bool {
+    if let Some(wid) = app::focus() {
+        wid.x() == x && wid.y() == y && wid.w() == w && wid.h() == h
+    } else {
+        false
+    }
+}
+
+/// Checks whether the frame being drawn belongs to the widget below the mouse pointer
+pub(crate) fn frame_is_hovered(x: i32, y: i32, w: i32, h: i32) -> bool {
+    if let Some(wid) = app::belowmouse::<fltk::widget::Widget>() {
+        wid.x() == x && wid.y() == y && wid.w() == w && wid.h() == h
+    } else {
+        false
+    }
+}
+
 pub(crate) fn devalued(c: Color, w: f32) -> Color {
     Color::color_average(Color::Black, c, w)
 }
@@ -0,0 +1,323 @@
+use super::*;
+use fltk::{app, enums::Color, misc::Tooltip};
+
+fn top_shadow(c: Color) -> Color {
+    activated_color(Color::color_average(Color::White, c, 0.55))
+}
+
+fn bottom_shadow(c: Color) -> Color {
+    activated_color(devalued(c, 0.45))
+}
+
+fn motif_bevel(x: i32, y: i32, w: i32, h: i32, t: i32, top: Color, bottom: Color) {
+    for i in 0..t {
+        // top and left highlight
+        set_draw_color(top);
+        draw_xyline(x + i, y + i, x + w - 2 - i);
+        draw_yxline(x + i, y + i, y + h - 2 - i);
+        // bottom and right shadow
+        set_draw_color(bottom);
+        draw_xyline(x + i, y + h - 1 - i, x + w - 1 - i);
+        draw_yxline(x + w - 1 - i, y + i, y + h - 1 - i);
+    }
+}
+
+fn motif_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    motif_bevel(x, y, w, h, 2, top_shadow(c), bottom_shadow(c));
+}
+
+fn motif_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 2, y + 2, w - 4, h - 4);
+    motif_button_up_frame(x, y, w, h, c);
+}
+
+fn motif_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    motif_bevel(x, y, w, h, 2, bottom_shadow(c), top_shadow(c));
+}
+
+fn motif_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 2, y + 2, w - 4, h - 4);
+    motif_check_down_frame(x, y, w, h, c);
+}
+
+fn motif_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    motif_bevel(x, y, w, h, 1, top_shadow(c), bottom_shadow(c));
+}
+
+fn motif_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    motif_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn motif_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    motif_bevel(x, y, w, h, 1, bottom_shadow(c), top_shadow(c));
+}
+
+fn motif_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    motif_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn motif_etched_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // two nested 1px bevels, the outer one sunken and the inner one raised
+    motif_bevel(x, y, w, h, 1, bottom_shadow(c), top_shadow(c));
+    motif_bevel(
+        x + 1,
+        y + 1,
+        w - 2,
+        h - 2,
+        1,
+        top_shadow(c),
+        bottom_shadow(c),
+    );
+}
+
+fn motif_etched_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 2, y + 2, w - 4, h - 4);
+    motif_etched_frame(x, y, w, h, c);
+}
+
+fn motif_diamond(x: i32, y: i32, w: i32, h: i32, top: Color, bottom: Color, fill: Color) {
+    let cx = x + w / 2;
+    let cy = y + h / 2;
+    let r = w.min(h) / 2 - 1;
+    set_draw_color(fill);
+    draw_polygon(cx - r, cy, cx, cy - r, cx + r, cy);
+    draw_polygon(cx - r, cy, cx, cy + r, cx + r, cy);
+    for i in 0..2 {
+        let r = r - i;
+        // upper edges
+        set_draw_color(top);
+        draw_line(cx - r, cy, cx, cy - r);
+        draw_line(cx, cy - r, cx + r, cy);
+        // lower edges
+        set_draw_color(bottom);
+        draw_line(cx - r, cy, cx, cy + r);
+        draw_line(cx, cy + r, cx + r, cy);
+    }
+}
+
+fn motif_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    motif_diamond(
+        x,
+        y,
+        w,
+        h,
+        bottom_shadow(c),
+        top_shadow(c),
+        activated_color(c),
+    );
+}
+
+fn motif_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    motif_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn motif_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // the extra sunken ring Motif draws around the default button
+    motif_bevel(x, y, w, h, 1, bottom_shadow(c), top_shadow(c));
+    motif_bevel(
+        x + 3,
+        y + 3,
+        w - 6,
+        h - 6,
+        2,
+        top_shadow(c),
+        bottom_shadow(c),
+    );
+}
+
+fn motif_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    motif_default_button_up_frame(x, y, w, h, c);
+}
+
+fn motif_default_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    motif_bevel(x, y, w, h, 1, bottom_shadow(c), top_shadow(c));
+    motif_bevel(
+        x + 3,
+        y + 3,
+        w - 6,
+        h - 6,
+        2,
+        bottom_shadow(c),
+        top_shadow(c),
+    );
+}
+
+fn motif_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 2, y + 2, w - 4, h - 4);
+    motif_bevel(
+        x,
+        y,
+        w,
+        h,
+        2,
+        bottom_shadow(Color::Background),
+        top_shadow(Color::Background),
+    );
+}
+
+fn motif_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    motif_bevel(
+        x,
+        y,
+        w,
+        h,
+        2,
+        bottom_shadow(Color::Background),
+        top_shadow(Color::Background),
+    );
+}
+
+fn use_motif_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, motif_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, motif_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, motif_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, motif_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, motif_panel_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        motif_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_PANEL_THIN_UP_FRAME,
+        motif_panel_thin_up_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        motif_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        motif_radio_round_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, motif_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_BOX, motif_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_HOVERED_UP_FRAME, motif_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_FRAME, motif_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_BOX,
+        motif_input_thin_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        motif_input_thin_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        motif_default_button_up_box,
+        5,
+        5,
+        10,
+        10,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_HOVERED_UP_BOX,
+        motif_default_button_up_box,
+        5,
+        5,
+        10,
+        10,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_DEPRESSED_DOWN_BOX,
+        motif_default_depressed_down_box,
+        5,
+        5,
+        10,
+        10,
+    );
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_PANEL_THIN_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, motif_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_SWATCH_BOX, motif_etched_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_SWATCH_FRAME, motif_etched_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_MINI_BUTTON_UP_BOX, motif_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_MINI_DEPRESSED_DOWN_BOX, motif_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_MINI_BUTTON_UP_FRAME, motif_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_MINI_DEPRESSED_DOWN_FRAME,
+        motif_check_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(OS_MENU_BAR_BOX, motif_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_SCROLLBAR_THUMB_BOX, motif_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::UpBox, motif_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::DownBox, motif_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::UpFrame, motif_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::DownFrame, motif_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::ThinUpBox, motif_panel_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        FrameType::ThinDownBox,
+        motif_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(FrameType::EngravedBox, motif_etched_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(FrameType::EngravedFrame, motif_etched_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        FrameType::RoundDownBox,
+        motif_radio_round_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_motif_colors() {
+    app::background(0xAE, 0xB2, 0xC3);
+    app::background2(0xAE, 0xB2, 0xC3);
+    app::foreground(0x00, 0x00, 0x00);
+    app::set_color(Color::Inactive, 0x5C, 0x60, 0x70);
+    app::set_color(Color::Selection, 0xE8, 0x9C, 0x4C);
+    app::set_color(Color::Free, 0xAE, 0xB2, 0xC3);
+    Tooltip::set_color(Color::from_rgb(0xFF, 0xF7, 0xC6));
+    Tooltip::set_text_color(Color::Foreground);
+}
+
+pub(crate) fn use_motif_theme() {
+    use_motif_scheme();
+    use_motif_colors();
+    use_native_settings();
+}
@@ -0,0 +1,383 @@
+use super::*;
+use crate::widget_schemes::configured_accent_color;
+use fltk::{app, enums::Color, misc::Tooltip};
+
+/// The color of default buttons, the configured accent lightened by light if there's one
+fn default_button_color(r: u8, g: u8, b: u8, light: f32) -> Color {
+    activated_color(match configured_accent_color() {
+        Some(accent) => Color::color_average(Color::White, accent, light),
+        None => Color::from_rgb(r, g, b),
+    })
+}
+
+fn win11_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        false,
+        activated_color(Color::from_rgb(0xE5, 0xE5, 0xE5)),
+    );
+    // darker bottom edge
+    set_draw_color(activated_color(Color::from_rgb(0xCC, 0xCC, 0xCC)));
+    draw_xyline(x + 3, y + h - 1, x + w - 4);
+}
+
+fn win11_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        true,
+        activated_color(Color::from_rgb(0xFB, 0xFB, 0xFB)),
+    );
+    win11_button_up_frame(x, y, w, h, c);
+}
+
+fn win11_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        false,
+        activated_color(Color::from_rgb(0x8A, 0x8A, 0x8A)),
+    );
+}
+
+fn win11_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        true,
+        activated_color(Color::from_rgb(0xF7, 0xF7, 0xF7)),
+    );
+    win11_check_down_frame(x, y, w, h, c);
+}
+
+fn win11_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xE5, 0xE5, 0xE5)));
+    draw_rect(x, y, w, h);
+}
+
+fn win11_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    win11_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn win11_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xD6, 0xD6, 0xD6)));
+    draw_rect(x, y, w, h);
+}
+
+fn win11_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    win11_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn win11_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0x8A, 0x8A, 0x8A)));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn win11_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xF7, 0xF7, 0xF7)));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    win11_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn win11_hovered_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    win11_button_up_frame(x, y, w, h, c);
+}
+
+fn win11_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        true,
+        activated_color(Color::from_rgb(0xF6, 0xF6, 0xF6)),
+    );
+    win11_hovered_up_frame(x, y, w, h, c);
+}
+
+fn win11_depressed_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        false,
+        activated_color(Color::from_rgb(0xE5, 0xE5, 0xE5)),
+    );
+}
+
+fn win11_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        true,
+        activated_color(Color::from_rgb(0xF5, 0xF5, 0xF5)),
+    );
+    win11_depressed_down_frame(x, y, w, h, c);
+}
+
+fn win11_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        false,
+        activated_color(Color::from_rgb(0xE5, 0xE5, 0xE5)),
+    );
+    // the bottom stroke of text fields
+    set_draw_color(activated_color(Color::from_rgb(0x86, 0x86, 0x86)));
+    draw_xyline(x + 3, y + h - 1, x + w - 4);
+}
+
+fn win11_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        true,
+        activated_color(Color::from_rgb(0xFF, 0xFF, 0xFF)),
+    );
+    win11_input_thin_down_frame(x, y, w, h, c);
+}
+
+fn win11_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        false,
+        default_button_color(0x00, 0x5F, 0xB8, 0.0),
+    );
+    set_draw_color(match configured_accent_color() {
+        Some(accent) => activated_color(accent.darker()),
+        None => activated_color(Color::from_rgb(0x00, 0x3E, 0x92)),
+    });
+    draw_xyline(x + 3, y + h - 1, x + w - 4);
+}
+
+fn win11_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        true,
+        default_button_color(0x00, 0x5F, 0xB8, 0.0),
+    );
+    win11_default_button_up_frame(x, y, w, h, c);
+}
+
+fn win11_default_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        true,
+        default_button_color(0x19, 0x6E, 0xBF, 0.1),
+    );
+    win11_default_button_up_frame(x, y, w, h, c);
+}
+
+fn win11_default_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        4,
+        true,
+        default_button_color(0x31, 0x7E, 0xC6, 0.2),
+    );
+}
+
+fn win11_tabs_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xE5, 0xE5, 0xE5)));
+    draw_rect(x, y, w, h);
+}
+
+fn win11_tabs_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(Color::from_rgb(0xF9, 0xF9, 0xF9)));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    win11_tabs_frame(x, y, w, h, c);
+}
+
+fn win11_swatch_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    win11_check_down_frame(x, y, w, h, c);
+}
+
+fn win11_swatch_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 4, true, activated_color(c));
+    win11_swatch_frame(x, y, w, h, c);
+}
+
+fn win11_scrollbar_thumb_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    // thin overlay thumb, centered along the short side
+    let col = activated_color(Color::from_rgb(0x8A, 0x8A, 0x8A));
+    if w >= h {
+        let t = (h / 3).max(2);
+        draw_rbox(x + 1, y + (h - t) / 2, w - 2, t, t / 2, true, col);
+    } else {
+        let t = (w / 3).max(2);
+        draw_rbox(x + (w - t) / 2, y + 1, t, h - 2, t / 2, true, col);
+    }
+}
+
+fn use_win11_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, win11_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, win11_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, win11_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, win11_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, win11_panel_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        win11_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_PANEL_THIN_UP_FRAME,
+        win11_panel_thin_up_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        win11_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        win11_radio_round_down_box,
+        3,
+        3,
+        6,
+        6,
+    );
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, win11_hovered_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_BOX, win11_depressed_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_HOVERED_UP_FRAME, win11_hovered_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        win11_depressed_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_BOX,
+        win11_input_thin_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        win11_input_thin_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        win11_default_button_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_HOVERED_UP_BOX,
+        win11_default_hovered_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_DEPRESSED_DOWN_BOX,
+        win11_default_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_HOVERED_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, win11_tabs_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_SWATCH_BOX, win11_swatch_box, 2, 2, 4, 4);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type_cb(
+        OS_SCROLLBAR_THUMB_BOX,
+        win11_scrollbar_thumb_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_CHECK_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundUpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_win11_colors() {
+    app::background(0xF3, 0xF3, 0xF3);
+    app::background2(0xFF, 0xFF, 0xFF);
+    app::foreground(0x1B, 0x1B, 0x1B);
+    app::set_color(Color::Inactive, 0xA0, 0xA0, 0xA0);
+    app::set_color(Color::Selection, 0x00, 0x5F, 0xB8);
+    app::set_color(Color::Free, 0xFB, 0xFB, 0xFB);
+    Tooltip::set_color(Color::from_rgb(0xF9, 0xF9, 0xF9));
+    Tooltip::set_text_color(Color::Foreground);
+}
+
+pub(crate) fn use_win11_theme() {
+    use_win11_scheme();
+    use_win11_colors();
+    use_native_settings();
+}
@@ -0,0 +1,330 @@
+use super::*;
+use fltk::{app, enums::Color, misc::Tooltip};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DARK: AtomicBool = AtomicBool::new(false);
+
+struct Palette {
+    button: Color,
+    border: Color,
+    hovered: Color,
+    pressed: Color,
+    field: Color,
+    field_border: Color,
+}
+
+const LIGHT: Palette = Palette {
+    button: Color::from_rgb(0xF7, 0xF7, 0xF7),
+    border: Color::from_rgb(0xCD, 0xCD, 0xCD),
+    hovered: Color::from_rgb(0xFC, 0xFC, 0xFC),
+    pressed: Color::from_rgb(0xE2, 0xE2, 0xE2),
+    field: Color::from_rgb(0xFF, 0xFF, 0xFF),
+    field_border: Color::from_rgb(0xCD, 0xCD, 0xCD),
+};
+
+const DARK_PALETTE: Palette = Palette {
+    button: Color::from_rgb(0x3D, 0x3D, 0x3D),
+    border: Color::from_rgb(0x1E, 0x1E, 0x1E),
+    hovered: Color::from_rgb(0x46, 0x46, 0x46),
+    pressed: Color::from_rgb(0x30, 0x30, 0x30),
+    field: Color::from_rgb(0x2B, 0x2B, 0x2B),
+    field_border: Color::from_rgb(0x1E, 0x1E, 0x1E),
+};
+
+const ACCENT: Color = Color::from_rgb(0xE9, 0x54, 0x20);
+const ACCENT_BORDER: Color = Color::from_rgb(0xC3, 0x44, 0x13);
+const HEADER: Color = Color::from_rgb(0x2C, 0x2C, 0x2C);
+
+fn palette() -> &'static Palette {
+    if DARK.load(Ordering::Relaxed) {
+        &DARK_PALETTE
+    } else {
+        &LIGHT
+    }
+}
+
+fn yaru_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 6, false, activated_color(palette().border));
+}
+
+fn yaru_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 6, true, activated_color(palette().button));
+    yaru_button_up_frame(x, y, w, h, c);
+}
+
+fn yaru_check_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, false, activated_color(ACCENT_BORDER));
+}
+
+fn yaru_check_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(palette().field));
+    yaru_check_down_frame(x, y, w, h, c);
+}
+
+fn yaru_panel_thin_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().border));
+    draw_rect(x, y, w, h);
+}
+
+fn yaru_panel_thin_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    yaru_panel_thin_up_frame(x, y, w, h, c);
+}
+
+fn yaru_spacer_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().border));
+    draw_rect(x, y, w, h);
+}
+
+fn yaru_spacer_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    yaru_spacer_thin_down_frame(x, y, w, h, c);
+}
+
+fn yaru_radio_round_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(ACCENT_BORDER));
+    draw_arc(x, y, w, h, 0.0, 360.0);
+}
+
+fn yaru_radio_round_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().field));
+    draw_pie(x, y, w, h, 0.0, 360.0);
+    yaru_radio_round_down_frame(x, y, w, h, c);
+}
+
+fn yaru_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 6, true, activated_color(palette().hovered));
+    yaru_button_up_frame(x, y, w, h, c);
+}
+
+fn yaru_depressed_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        6,
+        false,
+        activated_color(devalued(palette().border, 0.1)),
+    );
+}
+
+fn yaru_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 6, true, activated_color(palette().pressed));
+    yaru_depressed_down_frame(x, y, w, h, c);
+}
+
+fn yaru_input_thin_down_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    if frame_has_focus(x, y, w, h) {
+        draw_rbox(x, y, w, h, 6, false, activated_color(ACCENT));
+    } else {
+        draw_rbox(
+            x,
+            y,
+            w,
+            h,
+            6,
+            false,
+            activated_color(palette().field_border),
+        );
+    }
+}
+
+fn yaru_input_thin_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 6, true, activated_color(palette().field));
+    yaru_input_thin_down_frame(x, y, w, h, c);
+}
+
+fn yaru_default_button_up_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 6, false, activated_color(ACCENT_BORDER));
+}
+
+fn yaru_default_button_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 6, true, activated_color(ACCENT));
+    yaru_default_button_up_frame(x, y, w, h, c);
+}
+
+fn yaru_default_hovered_up_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(
+        x,
+        y,
+        w,
+        h,
+        6,
+        true,
+        activated_color(Color::from_rgb(0xEE, 0x67, 0x37)),
+    );
+    yaru_default_button_up_frame(x, y, w, h, c);
+}
+
+fn yaru_default_depressed_down_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 6, true, activated_color(ACCENT_BORDER));
+    yaru_default_button_up_frame(x, y, w, h, c);
+}
+
+fn yaru_tabs_frame(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(palette().border));
+    draw_rect(x, y, w, h);
+}
+
+fn yaru_tabs_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(c));
+    draw_rectf(x + 1, y + 1, w - 2, h - 2);
+    yaru_tabs_frame(x, y, w, h, c);
+}
+
+fn yaru_swatch_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    draw_rbox(x, y, w, h, 3, true, activated_color(c));
+    draw_rbox(x, y, w, h, 3, false, activated_color(palette().border));
+}
+
+fn yaru_menu_bar_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    set_draw_color(activated_color(HEADER));
+    draw_rectf(x, y, w, h);
+    set_draw_color(activated_color(devalued(HEADER, 0.3)));
+    draw_xyline(x, y + h - 1, x + w - 1);
+}
+
+fn yaru_scrollbar_thumb_box(x: i32, y: i32, w: i32, h: i32, c: Color) {
+    let col = activated_color(Color::color_average(
+        palette().border,
+        Color::Foreground,
+        0.5,
+    ));
+    if w >= h {
+        let t = (h / 2).max(3);
+        draw_rbox(x + 1, y + (h - t) / 2, w - 2, t, t / 2, true, col);
+    } else {
+        let t = (w / 2).max(3);
+        draw_rbox(x + (w - t) / 2, y + 1, t, h - 2, t / 2, true, col);
+    }
+}
+
+fn use_yaru_scheme() {
+    app::set_scheme(app::Scheme::Base);
+    app::set_frame_type_cb(OS_BUTTON_UP_BOX, yaru_button_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_BOX, yaru_check_down_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_BUTTON_UP_FRAME, yaru_button_up_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_CHECK_DOWN_FRAME, yaru_check_down_frame, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_BOX, yaru_panel_thin_up_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_BOX,
+        yaru_spacer_thin_down_box,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(OS_PANEL_THIN_UP_FRAME, yaru_panel_thin_up_frame, 1, 1, 2, 2);
+    app::set_frame_type_cb(
+        OS_SPACER_THIN_DOWN_FRAME,
+        yaru_spacer_thin_down_frame,
+        1,
+        1,
+        2,
+        2,
+    );
+    app::set_frame_type_cb(
+        OS_RADIO_ROUND_DOWN_BOX,
+        yaru_radio_round_down_box,
+        3,
+        3,
+        6,
+        6,
+    );
+    app::set_frame_type_cb(OS_HOVERED_UP_BOX, yaru_hovered_up_box, 2, 2, 4, 4);
+    app::set_frame_type_cb(OS_DEPRESSED_DOWN_BOX, yaru_depressed_down_box, 2, 2, 4, 4);
+    app::set_frame_type2(OS_HOVERED_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type_cb(
+        OS_DEPRESSED_DOWN_FRAME,
+        yaru_depressed_down_frame,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(OS_INPUT_THIN_DOWN_BOX, yaru_input_thin_down_box, 3, 3, 6, 6);
+    app::set_frame_type_cb(
+        OS_INPUT_THIN_DOWN_FRAME,
+        yaru_input_thin_down_frame,
+        3,
+        3,
+        6,
+        6,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_BUTTON_UP_BOX,
+        yaru_default_button_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_HOVERED_UP_BOX,
+        yaru_default_hovered_up_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type_cb(
+        OS_DEFAULT_DEPRESSED_DOWN_BOX,
+        yaru_default_depressed_down_box,
+        2,
+        2,
+        4,
+        4,
+    );
+    app::set_frame_type2(OS_TOOLBAR_BUTTON_HOVER_BOX, OS_HOVERED_UP_BOX);
+    app::set_frame_type_cb(OS_TABS_BOX, yaru_tabs_box, 1, 1, 2, 2);
+    app::set_frame_type_cb(OS_SWATCH_BOX, yaru_swatch_box, 2, 2, 4, 4);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_BOX, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_BOX, OS_DEPRESSED_DOWN_BOX);
+    app::set_frame_type2(OS_MINI_BUTTON_UP_FRAME, OS_BUTTON_UP_FRAME);
+    app::set_frame_type2(OS_MINI_DEPRESSED_DOWN_FRAME, OS_DEPRESSED_DOWN_FRAME);
+    app::set_frame_type_cb(OS_MENU_BAR_BOX, yaru_menu_bar_box, 0, 0, 0, 1);
+    app::set_frame_type_cb(OS_SCROLLBAR_THUMB_BOX, yaru_scrollbar_thumb_box, 1, 1, 2, 2);
+    app::set_frame_type2(FrameType::UpBox, OS_BUTTON_UP_BOX);
+    app::set_frame_type2(FrameType::DownBox, OS_CHECK_DOWN_BOX);
+    app::set_frame_type2(FrameType::RoundDownBox, OS_RADIO_ROUND_DOWN_BOX);
+    app::set_frame_type2(OS_BG_BOX, FrameType::FlatBox);
+}
+
+fn use_yaru_colors() {
+    app::background(0xFA, 0xFA, 0xFA);
+    app::background2(0xFF, 0xFF, 0xFF);
+    app::foreground(0x3D, 0x3D, 0x3D);
+    app::set_color(Color::Inactive, 0x8C, 0x8C, 0x8C);
+    app::set_color(Color::Selection, 0xE9, 0x54, 0x20);
+    app::set_color(Color::Free, 0xF7, 0xF7, 0xF7);
+    Tooltip::set_color(Color::from_rgb(0x2C, 0x2C, 0x2C));
+    Tooltip::set_text_color(Color::from_rgb(0xFF, 0xFF, 0xFF));
+}
+
+fn use_yaru_dark_colors() {
+    app::background(0x2C, 0x2C, 0x2C);
+    app::background2(0x2B, 0x2B, 0x2B);
+    app::foreground(0xF7, 0xF7, 0xF7);
+    app::set_color(Color::Inactive, 0x91, 0x91, 0x91);
+    app::set_color(Color::Selection, 0xE9, 0x54, 0x20);
+    app::set_color(Color::Free, 0x3D, 0x3D, 0x3D);
+    Tooltip::set_color(Color::from_rgb(0x1D, 0x1D, 0x1D));
+    Tooltip::set_text_color(Color::from_rgb(0xF7, 0xF7, 0xF7));
+}
+
+pub(crate) fn use_yaru_theme() {
+    DARK.store(false, Ordering::Relaxed);
+    use_yaru_scheme();
+    use_yaru_colors();
+    use_native_settings();
+}
+
+pub(crate) fn use_yaru_dark_theme() {
+    DARK.store(true, Ordering::Relaxed);
+    use_yaru_scheme();
+    use_yaru_dark_colors();
+    use_native_settings();
+}
@@ -0,0 +1,76 @@
+use crate::subtree::recolor;
+use crate::{on_theme_applied, ColorTheme};
+use fltk::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+/**
+Binds a color theme to a window, so that it keeps its own colors whatever the global theme:
+the theme is applied to the window's widgets like `ColorTheme::apply_to` does, then again to the
+widgets added later (checked whenever the window draws) and after every theme applied globally:
+```rust,no_run
+use fltk::{prelude::*, *};
+use fltk_theme::{color_themes, ColorTheme, WindowTheme};
+ColorTheme::new(color_themes::DARK_THEME).apply();
+let mut settings = window::Window::default().with_size(400, 300);
+settings.end();
+let _light = WindowTheme::bind(&mut settings, ColorTheme::new(color_themes::GRAY_THEME));
+settings.show();
+```
+The binding installs the window's draw callback, replacing one set by the app. It lasts until
+`unbind()` is called or the window is deleted, the handle can be dropped.
+*/
+#[derive(Clone)]
+pub struct WindowTheme {
+    theme: Rc<RefCell<Option<ColorTheme>>>,
+    window: fltk::widget::Widget,
+}
+
+impl WindowTheme {
+    /// Applies the theme to the window and keeps it applied
+    pub fn bind<W: WindowExt + WidgetBase + 'static>(
+        win: &mut W,
+        theme: ColorTheme,
+    ) -> WindowTheme {
+        let mut window = win.as_base_widget();
+        recolor(&theme, None, &mut window);
+        let shared = Rc::new(RefCell::new(Some(theme)));
+        let state = shared.clone();
+        win.draw(move |w| {
+            if let Some(theme) = state.borrow().as_ref() {
+                recolor(theme, None, &mut w.as_base_widget());
+            }
+        });
+        let state = shared.clone();
+        let mut target = window.clone();
+        on_theme_applied(move |_| {
+            if target.was_deleted() {
+                state.borrow_mut().take();
+            }
+            if let Some(theme) = state.borrow().as_ref() {
+                recolor(theme, None, &mut target);
+                target.redraw();
+            }
+        });
+        WindowTheme {
+            theme: shared,
+            window,
+        }
+    }
+
+    /// Switches the window to another theme
+    pub fn set_theme(&self, theme: ColorTheme) {
+        let previous = self.theme.borrow_mut().replace(theme);
+        let mut window = self.window.clone();
+        if let Some(theme) = self.theme.borrow().as_ref() {
+            if !window.was_deleted() {
+                recolor(theme, previous.as_ref(), &mut window);
+                window.redraw();
+            }
+        }
+    }
+
+    /// Stops keeping the theme applied. The window keeps its current colors
+    pub fn unbind(&self) {
+        self.theme.borrow_mut().take();
+    }
+}
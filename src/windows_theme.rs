@@ -0,0 +1,228 @@
+use crate::colors::relative_luminance;
+use crate::ini::Ini;
+use crate::ramp::{dark_end_through, gray_ramp_through};
+use crate::theme_file::mix;
+use crate::{ColorMap, ColorTheme, ThemeError};
+use std::{fs, path::Path};
+
+const SECTION: &str = "Control Panel\\Colors";
+
+/// Windows writes .theme files as UTF-16 as often as not
+fn decode(bytes: &[u8]) -> String {
+    let utf16 = |le: bool| {
+        let units: Vec<u16> = bytes[2..]
+            .chunks_exact(2)
+            .map(|c| {
+                if le {
+                    u16::from_le_bytes([c[0], c[1]])
+                } else {
+                    u16::from_be_bytes([c[0], c[1]])
+                }
+            })
+            .collect();
+        String::from_utf16_lossy(&units)
+    };
+    match bytes {
+        [0xFF, 0xFE, ..] => utf16(true),
+        [0xFE, 0xFF, ..] => utf16(false),
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+/// Parses the `212 208 200` triples of the colors section
+fn parse_triple(s: &str) -> Option<(u8, u8, u8)> {
+    let parts: Vec<_> = s.split_whitespace().map(|p| p.parse::<u8>()).collect();
+    match parts.as_slice() {
+        [Ok(r), Ok(g), Ok(b)] => Some((*r, *g, *b)),
+        _ => None,
+    }
+}
+
+fn color(ini: &Ini, key: &str) -> Result<Option<(u8, u8, u8)>, ThemeError> {
+    match ini.get(SECTION, key) {
+        Some((line, value)) => match parse_triple(value) {
+            Some(col) => Ok(Some(col)),
+            None => Err(ThemeError::InvalidColor {
+                line,
+                key: key.to_string(),
+                value: value.to_string(),
+            }),
+        },
+        None => Ok(None),
+    }
+}
+
+/// The classic colors of Windows, from a .theme file or from the system
+struct ClassicColors {
+    face: (u8, u8, u8),
+    text: (u8, u8, u8),
+    shadow: (u8, u8, u8),
+    hilight: (u8, u8, u8),
+    window: Option<(u8, u8, u8)>,
+    gray_text: Option<(u8, u8, u8)>,
+    highlight: Option<(u8, u8, u8)>,
+}
+
+impl ClassicColors {
+    /// Maps the colors onto FLTK's indices. With `pinned`, each gray of the ramp is replaced by the
+    /// closest of WindowText, ButtonFace and ButtonHilight, as high contrast schemes expect
+    fn to_theme(&self, pinned: bool) -> ColorTheme {
+        let mut map = vec![];
+        let mut set = |index: u8, col: Option<(u8, u8, u8)>| {
+            if let Some((r, g, b)) = col {
+                map.push(ColorMap {
+                    index,
+                    r,
+                    g,
+                    b,
+                    a: 255,
+                });
+            }
+        };
+        set(0, Some(self.text));
+        set(7, self.window);
+        set(8, self.gray_text);
+        set(15, self.highlight);
+        let ramp = gray_ramp_through(
+            dark_end_through(self.face, self.shadow),
+            self.face,
+            self.hilight,
+        );
+        if pinned {
+            let levels = [self.text, self.face, self.hilight];
+            map.extend(ramp.into_iter().map(|c| {
+                let l = relative_luminance((c.r, c.g, c.b));
+                let off = |level: &(u8, u8, u8)| (relative_luminance(*level) - l).abs();
+                let (r, g, b) = *levels
+                    .iter()
+                    .min_by(|x, y| off(x).total_cmp(&off(y)))
+                    .unwrap();
+                ColorMap {
+                    index: c.index,
+                    r,
+                    g,
+                    b,
+                    a: c.a,
+                }
+            }));
+        } else {
+            map.extend(ramp);
+        }
+        ColorTheme::from(map)
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod user32 {
+    use std::os::raw::c_void;
+
+    pub const COLOR_WINDOW: i32 = 5;
+    pub const COLOR_WINDOWTEXT: i32 = 8;
+    pub const COLOR_HIGHLIGHT: i32 = 13;
+    pub const COLOR_BTNFACE: i32 = 15;
+    pub const COLOR_BTNSHADOW: i32 = 16;
+    pub const COLOR_GRAYTEXT: i32 = 17;
+    pub const COLOR_BTNHIGHLIGHT: i32 = 20;
+    pub const SPI_GETHIGHCONTRAST: u32 = 0x0042;
+    pub const HCF_HIGHCONTRASTON: u32 = 0x0001;
+
+    #[repr(C)]
+    pub struct HighContrast {
+        pub size: u32,
+        pub flags: u32,
+        pub default_scheme: *mut u16,
+    }
+
+    #[link(name = "user32")]
+    extern "system" {
+        pub fn GetSysColor(index: i32) -> u32;
+        pub fn SystemParametersInfoW(action: u32, param: u32, value: *mut c_void, ini: u32) -> i32;
+    }
+}
+
+/// Whether a Windows high contrast scheme is active
+#[cfg(target_os = "windows")]
+fn high_contrast() -> bool {
+    let mut hc = user32::HighContrast {
+        size: std::mem::size_of::<user32::HighContrast>() as u32,
+        flags: 0,
+        default_scheme: std::ptr::null_mut(),
+    };
+    let ok = unsafe {
+        user32::SystemParametersInfoW(
+            user32::SPI_GETHIGHCONTRAST,
+            hc.size,
+            &mut hc as *mut user32::HighContrast as *mut std::os::raw::c_void,
+            0,
+        )
+    };
+    ok != 0 && hc.flags & user32::HCF_HIGHCONTRASTON != 0
+}
+
+/// Applies the system colors on Windows, used by `WidgetTheme::with_system_colors`
+pub(crate) fn apply_system_colors() {
+    #[cfg(target_os = "windows")]
+    ColorTheme::from_windows_system_colors().apply();
+}
+
+impl ColorTheme {
+    /**
+    Builds a theme from the `[Control Panel\Colors]` section of a legacy Windows .theme file
+    (.themepack files are cabinet archives and need to be extracted first).
+    ButtonFace gives the background, with the gray ramp running through ButtonShadow (on FL_DARK3)
+    and ButtonHilight, Window the background of inputs, WindowText the foreground, GrayText the
+    inactive color and Highlight the selection color. HighlightText has no counterpart since FLTK
+    picks the text color of selections by contrast.
+    */
+    pub fn from_windows_theme(path: &Path) -> Result<ColorTheme, ThemeError> {
+        ColorTheme::from_windows_theme_str(&decode(&fs::read(path)?))
+    }
+
+    /// Builds a theme from the content of a Windows .theme file, see `from_windows_theme`
+    pub fn from_windows_theme_str(s: &str) -> Result<ColorTheme, ThemeError> {
+        let ini = Ini::parse(s);
+        let face = color(&ini, "ButtonFace")?.ok_or_else(|| ThemeError::MissingColor {
+            key: format!("[{}] ButtonFace", SECTION),
+        })?;
+        let text = color(&ini, "WindowText")?.unwrap_or((0, 0, 0));
+        let shadow = color(&ini, "ButtonShadow")?.unwrap_or_else(|| mix(face, (0, 0, 0), 0.4));
+        let hilight = color(&ini, "ButtonHilight")?.unwrap_or((255, 255, 255));
+        Ok(ClassicColors {
+            face,
+            text,
+            shadow,
+            hilight,
+            window: color(&ini, "Window")?,
+            gray_text: color(&ini, "GrayText")?,
+            highlight: color(&ini, "Highlight")?,
+        }
+        .to_theme(false))
+    }
+
+    /**
+    Builds a theme from the live system colors of Windows (`GetSysColor`), mapped like
+    `from_windows_theme` does, so that custom classic and high contrast color schemes are followed.
+    When a high contrast scheme is active, the gray ramp is pinned to the text, face and highlight
+    colors so that no frame is drawn in an in-between gray. `WidgetTheme::with_system_colors` applies
+    it along with a widget theme, usually `ThemeType::Classic`
+    */
+    #[cfg(target_os = "windows")]
+    pub fn from_windows_system_colors() -> ColorTheme {
+        use user32::*;
+        let sys = |index| {
+            // COLORREF is 0x00BBGGRR
+            let [r, g, b, _] = unsafe { GetSysColor(index) }.to_le_bytes();
+            (r, g, b)
+        };
+        ClassicColors {
+            face: sys(COLOR_BTNFACE),
+            text: sys(COLOR_WINDOWTEXT),
+            shadow: sys(COLOR_BTNSHADOW),
+            hilight: sys(COLOR_BTNHIGHLIGHT),
+            window: Some(sys(COLOR_WINDOW)),
+            gray_text: Some(sys(COLOR_GRAYTEXT)),
+            highlight: Some(sys(COLOR_HIGHLIGHT)),
+        }
+        .to_theme(high_contrast())
+    }
+}
@@ -0,0 +1,134 @@
+use crate::ansi::ansi_map;
+use crate::theme_file::parse_hex;
+use crate::ColorTheme;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Guards against include cycles
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// Parses `#rrggbb` and `rgb:r/g/b` with 1 to 4 hex digits per channel
+fn parse_x_color(s: &str) -> Option<(u8, u8, u8)> {
+    if let Some(rgb) = s.strip_prefix("rgb:") {
+        let mut channels = rgb.split('/').map(|c| {
+            if c.is_empty() || c.len() > 4 {
+                return None;
+            }
+            let v = u32::from_str_radix(c, 16).ok()?;
+            let max = (1u32 << (4 * c.len())) - 1;
+            Some((v * 255 / max) as u8)
+        });
+        let r = channels.next()??;
+        let g = channels.next()??;
+        let b = channels.next()??;
+        if channels.next().is_some() {
+            return None;
+        }
+        return Some((r, g, b));
+    }
+    parse_hex(s).filter(|_| s.starts_with('#'))
+}
+
+/// Collects the resources of s, later definitions overriding earlier ones.
+/// Includes are resolved relative to dir, and skipped if there's none.
+fn collect(
+    s: &str,
+    dir: Option<&Path>,
+    depth: usize,
+    defines: &mut HashMap<String, String>,
+    resources: &mut HashMap<String, String>,
+) {
+    for line in s.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('!') {
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("#define") {
+            let mut parts = rest.split_whitespace();
+            if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+                defines.insert(name.to_string(), value.to_string());
+            }
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("#include") {
+            let file = rest.trim().trim_matches(['"', '<', '>']);
+            if let Some(dir) = dir {
+                if depth < MAX_INCLUDE_DEPTH {
+                    let path = dir.join(file);
+                    if let Ok(content) = fs::read_to_string(&path) {
+                        let sub_dir = path.parent().map(Path::to_path_buf);
+                        collect(&content, sub_dir.as_deref(), depth + 1, defines, resources);
+                    }
+                }
+            }
+            continue;
+        }
+        if line.starts_with('#') {
+            // other preprocessor directives
+            continue;
+        }
+        let (name, value) = match line.split_once(':') {
+            Some(nv) => nv,
+            None => continue,
+        };
+        // `*.color0`, `*color0` and `URxvt.color0` all define color0
+        let name = name.trim().rsplit(['.', '*']).next().unwrap_or_default();
+        let value = value.trim();
+        let value = defines.get(value).map(String::as_str).unwrap_or(value);
+        resources.insert(name.to_string(), value.to_string());
+    }
+}
+
+fn theme_from_resources(resources: &HashMap<String, String>) -> Option<ColorTheme> {
+    let color = |name: &str| resources.get(name).and_then(|v| parse_x_color(v));
+    let bg = color("background")?;
+    let fg = color("foreground")?;
+    let ansi: Vec<_> = (0..16).map(|n| color(&format!("color{}", n))).collect();
+    let selection = ansi[4].or_else(|| color("cursorColor"));
+    Some(ColorTheme::from(ansi_map(bg, fg, &ansi, selection)))
+}
+
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(PathBuf::from)
+}
+
+impl ColorTheme {
+    /**
+    Builds a theme from the user's `~/.Xresources`, falling back to `~/.Xdefaults`.
+    `*.background` and `*.foreground` are required, `*.color0` to `*.color15` are mapped
+    as in `from_ansi16`, and the gray ramp is interpolated
+    between the background and the foreground. `*.cursorColor` is used as the selection
+    color when `*.color4` isn't defined.
+    */
+    pub fn from_xresources() -> Option<ColorTheme> {
+        let home = home_dir()?;
+        [".Xresources", ".Xdefaults"]
+            .iter()
+            .find_map(|name| ColorTheme::from_xresources_file(&home.join(name)))
+    }
+
+    /// Builds a theme from an Xresources file, see `from_xresources`.
+    /// `#include` lines are resolved relative to the file
+    pub fn from_xresources_file(path: &Path) -> Option<ColorTheme> {
+        let content = fs::read_to_string(path).ok()?;
+        let mut resources = HashMap::new();
+        collect(
+            &content,
+            path.parent(),
+            0,
+            &mut HashMap::new(),
+            &mut resources,
+        );
+        theme_from_resources(&resources)
+    }
+
+    /// Builds a theme from Xresources content, see `from_xresources`. `#include` lines are ignored
+    pub fn from_xresources_str(s: &str) -> Option<ColorTheme> {
+        let mut resources = HashMap::new();
+        collect(s, None, 0, &mut HashMap::new(), &mut resources);
+        theme_from_resources(&resources)
+    }
+}